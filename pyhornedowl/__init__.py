@@ -1,4 +1,4 @@
 from __future__ import annotations
-from .pyhornedowl import PyIndexedOntology, open_ontology, get_descendants, get_ancestors
+from .pyhornedowl import PyIndexedOntology, OntologyManager, open_ontology, open_ontology_from_file, open_ontology_from_string, open_ontology_from_bytes, open_ontology_from_reader, get_descendants, get_ancestors, merge_ontologies
 
-__all__ = ["PyIndexedOntology", "open_ontology", "get_descendants", "get_ancestors"]
+__all__ = ["PyIndexedOntology", "OntologyManager", "open_ontology", "open_ontology_from_file", "open_ontology_from_string", "open_ontology_from_bytes", "open_ontology_from_reader", "get_descendants", "get_ancestors", "merge_ontologies"]
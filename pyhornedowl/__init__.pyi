@@ -34,6 +34,37 @@ class PyIndexedOntology:
         """
         ...
 
+    def set_annotation(self, iri: str, property_iri: str, value: typing.Union[str, model.AnnotationValue], lang: Optional[str], datatype: Optional[str], replace: Optional[bool]) -> None:
+        """
+        Sets an annotation of a term by iri.
+        
+        If `value` is a string, it is annotated as a literal with the language tag `lang` or the
+        datatype `datatype`, or as a simple literal if neither is given. Otherwise `value` is used as is,
+        e.g. a `model.IRI` for IRI-valued annotations. Unless `replace` is false, all existing
+        `AnnotationAssertion`s of `property_iri` on the term are replaced; the axiom annotations of the
+        replaced assertions are kept on the new one.
+        """
+        ...
+
+    def add_annotation(self, iri: str, property_iri: str, value: typing.Union[str, model.AnnotationValue], lang: Optional[str], datatype: Optional[str]) -> None:
+        """
+        Adds an annotation to a term by iri, keeping existing annotations of the same property.
+        
+        `value`, `lang` and `datatype` are interpreted as in `PyIndexedOntology.set_annotation`.
+        """
+        ...
+
+    def remove_annotations(self, iri: str, property_iri: str, value: Optional[typing.Union[str, model.AnnotationValue]]) -> int:
+        """
+        Removes the annotations of a term by iri for an annotation property.
+        
+        If `value` is given, only annotations with this value are removed. A string matches literals
+        with the same lexical value regardless of their language or datatype.
+        
+        Returns the number of removed annotations.
+        """
+        ...
+
     def get_iri_for_label(self, label: str) -> Optional[str]:
         """
         Returns the IRI of a term by its label if it exists.
@@ -54,6 +85,62 @@ class PyIndexedOntology:
         """
         ...
 
+    def set_iri(self, iri: Optional[str]) -> None:
+        """
+        Sets the ontology iri, keeping the version iri.
+        
+        If `iri` is `None`, the ontology iri is removed.
+        """
+        ...
+
+    def set_version_iri(self, iri: Optional[str]) -> None:
+        """
+        Sets the ontologys version iri, keeping the ontology iri.
+        
+        If `iri` is `None`, the version iri is removed.
+        """
+        ...
+
+    def get_ontology_annotations(self) -> List[model.Annotation]:
+        """
+        Returns the annotations of the ontology itself.
+        """
+        ...
+
+    def add_ontology_annotation(self, ann: model.Annotation) -> None:
+        """
+        Adds an annotation to the ontology itself.
+        """
+        ...
+
+    def remove_ontology_annotation(self, ann: model.Annotation) -> None:
+        """
+        Removes an annotation of the ontology itself.
+        
+        Raises a `ValueError` if the ontology does not have this annotation.
+        """
+        ...
+
+    def get_imports(self) -> List[str]:
+        """
+        Returns the IRIs of all ontologies imported by this ontology.
+        """
+        ...
+
+    def add_import(self, iri: str) -> None:
+        """
+        Adds an import of the ontology with the given iri.
+        """
+        ...
+
+    def remove_import(self, iri: str) -> None:
+        """
+        Removes the import of the ontology with the given iri.
+        
+        Raises a `ValueError` if the ontology does not import `iri`.
+        """
+        ...
+
     def get_subclasses(self, iri: str) -> Set[str]:
         """
         Gets all subclasses of an entity.
@@ -97,9 +184,80 @@ class PyIndexedOntology:
         """
         ...
 
-    def save_to_file(self, file_name: str) -> None:
+    def get_annotation_values(self, class_iri: str, ann_iri: str, native: Optional[bool]) -> List[typing.Any]:
+        """
+        Gets all annotated values for an entity and annotation property with their types.
+        
+        By default the values are returned as `model.AnnotationValue`, keeping language tags, datatypes
+        and IRI- or anonymous-individual-valued annotations. With `native=True` literals are converted to
+        Python values instead: `xsd:integer` and its subtypes to `int`, `xsd:boolean` to `bool`,
+        `xsd:decimal` to `decimal.Decimal`, `xsd:double` and `xsd:float` to `float`, `xsd:dateTime` to
+        `datetime.datetime` and `xsd:date` to `datetime.date`, dropping its timezone. Other literals,
+        and literals whose lexical form is not valid for their datatype, become `str` unchanged.
+        
+        Note: The order is neither necessarily the same as in the ontology neither is it stable.
+        """
+        ...
+
+    def get_axiom_annotations(self, ax: model.Component) -> List[model.Annotation]:
+        """
+        Gets the annotations on an axiom of the ontology.
+        
+        Raises a `ValueError` if the ontology does not contain the axiom.
+        """
+        ...
+
+    def add_axiom_annotation(self, ax: model.Component, ann: model.Annotation) -> None:
+        """
+        Adds an annotation to an axiom of the ontology, keeping its other annotations.
+        
+        Raises a `ValueError` if the ontology does not contain the axiom.
+        """
+        ...
+
+    def remove_axiom_annotation(self, ax: model.Component, ann: model.Annotation) -> None:
+        """
+        Removes an annotation from an axiom of the ontology, keeping the axiom itself.
+        
+        Raises a `ValueError` if the ontology does not contain the axiom.
+        """
+        ...
+
+    def get_axioms_with_annotation(self, ann_iri: str, value: Optional[typing.Union[str, model.AnnotationValue]]) -> List[model.AnnotatedComponent]:
+        """
+        Returns all axioms annotated with the annotation property `ann_iri`.
+        
+        If `value` is given, only axioms with an annotation of this value are returned. A string matches
+        literals with the same lexical value regardless of their language or datatype.
+        """
+        ...
+
+    def save_to_file(self, file_name: str, serialization: typing.Optional[str] = None, progress: typing.Optional[typing.Callable[[int], None]] = None) -> None:
         """
-        Saves the ontology to disk in owx format.
+        Saves the ontology to disk.
+        
+        `serialization` is either "owx", "rdfxml", "turtle", "ntriples", "jsonld", "obo" (OBO 1.4
+        flat file) or "obographs" (OBO Graphs JSON). If it is not given, it is guessed from the
+        extension of `file_name` as for `open_ontology_from_file`, with `.json` written as OBO Graphs,
+        defaulting to owx.
+        
+        OWX, RDF/XML and OBO are written to the file as they are produced, OWX and RDF/XML without
+        copying the ontology. The other serializations, and RDF/XML with canonical ordering, are
+        first built in memory: Turtle, N-Triples and JSON-LD from all triples of the ontology, which
+        are obtained by writing RDF/XML and parsing it again. If `progress` is given, it is called
+        with the number of bytes written so far after every MiB and when the file is complete.
+        """
+        ...
+
+    def set_canonical_ordering(self, canonical: bool) -> None:
+        """
+        Sets whether the ontology is written in a canonical order, so that writing the same
+        components always produces the same output.
+        
+        Components are sorted by kind, then by the IRI of the entity they are about, then
+        structurally. RDF output is sorted by subject, predicate and object, and blank nodes are
+        labelled from the triples around them instead of the order in which they were written.
+        OWX output is always grouped by kind and sorted structurally by horned-owl.
         """
         ...
 
@@ -109,6 +267,73 @@ class PyIndexedOntology:
         """
         ...
 
+    def write_to_turtle_string(self) -> str:
+        """
+        Writes the ontology to a string in Turtle format.
+        
+        The prefixes of the ontology are declared and used to abbreviate IRIs. Triples are sorted by
+        subject, predicate and object, so the same ontology is always written the same way.
+        """
+        ...
+
+    def write_to_ntriples_string(self) -> str:
+        """
+        Writes the ontology to a string in N-Triples format, one sorted triple per line.
+        """
+        ...
+
+    def write_to_jsonld_string(self) -> str:
+        """
+        Writes the RDF mapping of the ontology as a flattened JSON-LD document.
+        
+        The `@context` declares the prefixes of the ontology, which are used to compact IRIs. Each
+        subject becomes a node object in `@graph`, with nodes and their values sorted.
+        """
+        ...
+
+    def write_to_obographs_string(self) -> str:
+        """
+        Writes the ontology as OBO Graphs JSON.
+        
+        Declared entities become nodes carrying their label, definition, synonyms, xrefs, comments,
+        subsets, deprecation and other annotations as metadata. `SubClassOf` axioms between named
+        classes become `is_a` edges and existential restrictions on named classes edges labelled
+        with the property. Equivalences to intersections of named classes and existential
+        restrictions become logical definitions. Other axioms are not represented.
+        """
+        ...
+
+    def write_to_obo_string(self) -> str:
+        """
+        Writes the ontology as an OBO 1.4 flat file.
+        
+        Classes, object properties and named individuals become `[Term]`, `[Typedef]` and
+        `[Instance]` stanzas following the OBO to OWL mapping. Labels, definitions, synonyms, xrefs,
+        subsets and obsolescence are written as their tags, `SubClassOf` axioms as `is_a` or
+        `relationship` and equivalences to intersections as `intersection_of`. Axioms without an
+        OBO representation, such as nested class expressions, are not written.
+        """
+        ...
+
+    def sparql_query(self, query: str) -> typing.Union[List[Dict[str, typing.Any]], bool, List[Tuple[str, str, typing.Any]]]:
+        """
+        Evaluates a SPARQL 1.1 query over the RDF triples of the ontology.
+        
+        The triples are those written by `PyIndexedOntology.write_to_rdf_string`. SELECT queries
+        return one dict per solution mapping variable names to values, with `None` for unbound
+        variables. ASK queries return a bool, CONSTRUCT and DESCRIBE queries a list of
+        `(subject, predicate, object)` tuples. IRIs are returned as strings, blank nodes as `_:id`
+        and literals as native Python values where the datatype is known. The triples are kept
+        between queries until the ontology changes.
+        """
+        ...
+
+    def clone(self) -> PyIndexedOntology:
+        """
+        Returns an independent copy of the ontology including its prefix mapping.
+        """
+        ...
+
     def write_to_owx_string(self) -> None:
         """
         Writes the ontology to a string in rdf format.
@@ -127,6 +352,86 @@ class PyIndexedOntology:
         """
         ...
 
+    def get_components(self, kind: typing.Union[model.ComponentKind, List[model.ComponentKind]], iri: Optional[str]) -> List[model.AnnotatedComponent]:
+        """
+        Returns all components of one or several kinds.
+        
+        If `iri` is given, only components that reference this IRI are returned. This is much faster
+        than filtering the result of `PyIndexedOntology.get_axioms`, as the components are looked up
+        in the index of the ontology.
+        """
+        ...
+
+    def parse_class_expression(self, text: str) -> model.ClassExpression:
+        """
+        Parses a class expression in Manchester syntax.
+        
+        Names are resolved as labels (quoted with `'` if they contain spaces), CURIEs using the
+        prefix mapping of the ontology or full IRIs in angle brackets.
+        """
+        ...
+
+    def parse_component(self, text: str) -> model.Component:
+        """
+        Parses an axiom or declaration in Manchester syntax.
+        
+        Axioms are written like a single line of a Manchester syntax frame, e.g.
+        `'carbon atom' SubClassOf: atom`, `part_of Characteristics: Transitive`, `i Facts: p j` or
+        `A Annotations: rdfs:label "a"`, and declarations like `Class: ex:A`. Whether the subject
+        is an object, data or annotation property is taken from the declarations in the ontology.
+        """
+        ...
+
+    def render_manchester(self, obj: typing.Union[model.Component, model.AnnotatedComponent, model.ClassExpression, model.ObjectPropertyExpression, model.DataRange, model.Individual, model.Literal, model.IRI]) -> str:
+        """
+        Renders a model object in Manchester syntax.
+        
+        Entities are written by their label where they have one, the smallest if they have several,
+        otherwise as CURIE or full IRI.
+        Components are rendered in the form accepted by `PyIndexedOntology.parse_component`.
+        """
+        ...
+
+    def render_functional(self, obj: typing.Any) -> str:
+        """
+        Renders a model object in OWL Functional Syntax, abbreviating IRIs with the prefix mapping
+        of the ontology.
+        """
+        ...
+
+    def dl_query(self, expression: str, kind: Optional[str]) -> Set[str]:
+        """
+        Evaluates a DL query given as a Manchester syntax class expression.
+        
+        Names in the expression are resolved as labels (quoted with `'` if they contain spaces),
+        CURIEs using the prefix mapping of the ontology or full IRIs in angle brackets. `kind` is one
+        of "subclasses" (the default), "superclasses", "equivalents" or "instances" and the IRIs of
+        the matching named classes or individuals are returned.
+        
+        The query is answered from the asserted subclass, equivalence, class assertion and object
+        property assertion axioms without a reasoner, following named superclasses, intersections,
+        unions and existential or universal restrictions.
+        """
+        ...
+
+    def match_pattern(self, pattern: model.Component) -> List[Tuple[model.AnnotatedComponent, Dict[str, typing.Any]]]:
+        """
+        Returns all components matching a structural pattern together with the bindings of its variables.
+        
+        The pattern is a component in which IRIs starting with `?` are variables, e.g.
+        `SubClassOf(ObjectSomeValuesFrom(part_of, Class(IRI.parse("?y"))), Class(IRI.parse("?x")))`.
+        A variable used as a class, object property, datatype, individual or IRI annotation value
+        matches any expression in that position, and a simple literal `"?name"` matches any literal.
+        Variables occurring several times must bind to the same value, and the anonymous variable `?`
+        matches anything without being bound. The operands of n-ary constructs such as
+        `EquivalentClasses` or `ObjectIntersectionOf` are matched in any order, property chains element
+        by element and SWRL rules only literally.
+        
+        The bindings map each variable name without `?` to the matched model object. Candidates are
+        looked up in the index of the ontology using the IRIs of the pattern that are not variables.
+        """
+        ...
+
     def add_axiom(self, ax: model.Component, annotations: Optional[List[model.Annotation]]) -> None:
         """
         Adds an axiom to the ontology with optional annotations.
@@ -139,6 +444,56 @@ class PyIndexedOntology:
         """
         ...
 
+    def remove_entity(self, iri: str, mode: Optional[str]) -> int:
+        """
+        Removes an entity together with the axioms that reference it.
+        
+        With `mode="all"` (the default) every component mentioning `iri` is removed, including
+        its declarations and annotation assertions. With `mode="subject"` only the declarations,
+        annotation assertions and axioms about the entity are removed, while axioms that merely
+        refer to it (e.g. as a superclass or filler) are kept. `mode="rewire"` removes the same
+        components as `"all"`, but first asserts every direct subclass of the removed class as a
+        subclass of each of its direct superclasses.
+        
+        Returns the number of removed components.
+        """
+        ...
+
+    def obsolete_entity(self, iri: str, replaced_by: Optional[str], consider: Optional[List[str]], reason: Optional[str]) -> None:
+        """
+        Marks an entity as obsolete following the OBO deprecation pattern.
+        
+        Asserts `owl:deprecated true`, prefixes the `rdfs:label` with "obsolete " and removes the
+        logical axioms about the entity. Declarations, annotation assertions and axioms of other
+        entities referring to it are kept. `replaced_by` adds an `IAO:0100001` (term replaced by)
+        annotation, every IRI in `consider` an `oboInOwl:consider` annotation and `reason` an
+        `IAO:0000231` (has obsolescence reason) annotation.
+        
+        As in OBO ontologies, `reason` must be the absolute IRI of a reason term (such as
+        `http://purl.obolibrary.org/obo/OMO_0001000`, out of scope) rather than free text;
+        otherwise a ValueError is raised and the ontology is left unchanged.
+        """
+        ...
+
+    def get_obsolete_entities(self) -> Set[str]:
+        """
+        Returns the IRIs of all entities annotated with `owl:deprecated true`.
+        """
+        ...
+
+    def merge(self, other: PyIndexedOntology, ontology_id: Optional[model.OntologyID], drop_merged_imports: Optional[bool]) -> List[str]:
+        """
+        Adds all components and prefixes of `other` to this ontology.
+        
+        The `OntologyID` of this ontology is kept, or taken from `other` if this ontology has none,
+        unless `ontology_id` is given. If `drop_merged_imports` is true, `Import`s of either of the
+        two ontologies are removed.
+        
+        Returns the prefixes that `other` binds to a different IRI than this ontology. These keep
+        their current binding.
+        """
+        ...
+
     def iri(self, iri: str) -> model.IRI:
         """
         Creates an new IRI from string.
@@ -147,13 +502,182 @@ class PyIndexedOntology:
         """
         ...
 
+class OntologyManager:
+    def load(self, ontology: str, resolve_imports: Optional[bool], catalog: Optional[str]) -> str:
+        """
+        Loads an ontology from a path or plain text and adds it to the manager.
+        
+        If `resolve_imports` is true, the imports closure is loaded as separate members, resolving
+        import IRIs through `catalog` as in `open_ontology`. Imports that are already members are not
+        loaded again.
+        
+        Returns the key of the loaded ontology, which is its ontology IRI or, if it has none, `ontology`.
+        """
+        ...
+
+    def add(self, ontology: PyIndexedOntology, iri: Optional[str]) -> str:
+        """
+        Adds an already loaded ontology to the manager.
+        
+        The ontology is stored under `iri` or, if not given, its ontology IRI. The ontology is shared
+        with the caller, so later changes to it are visible to the manager. Like ontologies loaded
+        through the manager, it then shares the manager's IRI cache. Returns the key.
+        """
+        ...
+
+    def get(self, iri: str) -> PyIndexedOntology:
+        """
+        Returns the member with the given ontology IRI, version IRI or import IRI.
+        """
+        ...
+
+    def remove(self, iri: str) -> None:
+        """
+        Removes a member from the manager.
+        """
+        ...
+
+    def get_ontology_iris(self) -> Set[str]:
+        """
+        Returns the keys of all members.
+        """
+        ...
+
+    def get_imports(self, iri: str) -> Set[str]:
+        """
+        Returns the IRIs directly imported by a member, whether or not they are members themselves.
+        """
+        ...
+
+    def get_imports_closure(self, iri: str) -> List[str]:
+        """
+        Returns the keys of the members in the imports closure of a member, starting with the member itself.
+        """
+        ...
+
+    def get_classes(self, iri: str) -> Set[str]:
+        """
+        Returns the IRIs of all classes declared in the imports closure of a member.
+        """
+        ...
+
+    def get_object_properties(self, iri: str) -> Set[str]:
+        """
+        Returns the IRIs of all object properties declared in the imports closure of a member.
+        """
+        ...
+
+    def get_data_properties(self, iri: str) -> Set[str]:
+        """
+        Returns the IRIs of all data properties declared in the imports closure of a member.
+        """
+        ...
+
+    def get_axioms(self, iri: str) -> List[model.AnnotatedComponent]:
+        """
+        Returns all axioms in the imports closure of a member.
+        """
+        ...
+
+    def get_axioms_for_iri(self, ontology: str, iri: str) -> List[model.AnnotatedComponent]:
+        """
+        Gets all axioms for an entity in the imports closure of a member.
+        """
+        ...
+
+    def get_iri_for_label(self, ontology: str, label: str) -> Optional[str]:
+        """
+        Returns the IRI of a term by its label in the imports closure of a member.
+        
+        If no member in the closure has a term with this label, `None` is returned.
+        """
+        ...
+
+    def get_subclasses(self, ontology: str, iri: str) -> Set[str]:
+        """
+        Gets all direct subclasses of a class asserted in the imports closure of a member.
+        """
+        ...
+
+    def get_superclasses(self, ontology: str, iri: str) -> Set[str]:
+        """
+        Gets all direct superclasses of a class asserted in the imports closure of a member.
+        """
+        ...
+
+    def get_descendants(self, ontology: str, iri: str) -> Set[str]:
+        """
+        Gets all direct and indirect subclasses of a class in the imports closure of a member.
+        """
+        ...
+
+    def get_ancestors(self, ontology: str, iri: str) -> Set[str]:
+        """
+        Gets all direct and indirect superclasses of a class in the imports closure of a member.
+        """
+        ...
 
-def open_ontology(ontology: str) -> PyIndexedOntology:
+
+def open_ontology(ontology: str, resolve_imports: Optional[bool], catalog: Optional[str]) -> PyIndexedOntology:
     """
     Opens an ontology from a path or plain text.
     
-    If `ontology` is a path, the file is loaded. Otherwise, `ontology` is interepreted as an ontology in either owx or owl format.
-    Note: Only .owl and .owx files are currently supported.
+    If `ontology` is the path of an existing file, it is loaded as by `open_ontology_from_file`.
+    Otherwise `ontology` is read as the text of an ontology as by `open_ontology_from_string`. In
+    both cases the format is guessed from the file extension or the content; as OBO Graphs and
+    JSON-LD documents both use `.json`, the content decides between them. Use
+    `open_ontology_from_file` or `open_ontology_from_string` to avoid guessing whether `ontology`
+    is a path.
+    
+    If `resolve_imports` is true, the imports closure is loaded from local files and merged into the
+    returned ontology. Import IRIs are resolved through `catalog`, which is either a Protégé-style XML
+    catalog or a directory containing the imported files named like the last segment of their IRI.
+    It defaults to the `catalog-v001.xml` next to the ontology file. Imports are never fetched from
+    the network; an import that cannot be resolved raises a `ValueError`.
+    """
+    ...
+
+
+def open_ontology_from_file(path: str, format: Optional[str], resolve_imports: Optional[bool], catalog: Optional[str]) -> PyIndexedOntology:
+    """
+    Opens an ontology from a file.
+    
+    `format` is one of "owx", "rdfxml", "turtle", "ntriples", "jsonld", "obo" or "obographs", or
+    a file extension such as "owl" or "ttl". If it is not given, it is guessed from the extension
+    of `path` and otherwise from the content of the file, and a `ValueError` is raised if it cannot
+    be. `.json` files are read as JSON-LD or OBO Graphs depending on their content. Files compressed with gzip, bzip2 or zstd and zip archives holding a single file are
+    decompressed transparently. `resolve_imports` and `catalog` are as for `open_ontology`.
+    """
+    ...
+
+
+def open_ontology_from_string(text: str, format: Optional[str]) -> PyIndexedOntology:
+    """
+    Opens an ontology from its text.
+    
+    `format` is as for `open_ontology_from_file`. If it is not given, it is guessed from the
+    text. Unlike `open_ontology`, `text` is never interpreted as a path.
+    """
+    ...
+
+
+def open_ontology_from_bytes(data: bytes, format: Optional[str]) -> PyIndexedOntology:
+    """
+    Opens an ontology from the bytes of a document, which may be compressed with gzip, bzip2 or
+    zstd or be a zip archive holding a single file.
+    
+    `format` is as for `open_ontology_from_file`. If it is not given, it is guessed from the
+    decompressed content.
+    """
+    ...
+
+
+def open_ontology_from_reader(reader: typing.Union[typing.BinaryIO, typing.TextIO], format: Optional[str]) -> PyIndexedOntology:
+    """
+    Opens an ontology from a file-like object, reading it to the end.
+    
+    Binary readers may return compressed data as for `open_ontology_from_bytes`. `format` is as
+    for `open_ontology_from_file`. If it is not given, it is guessed from the content.
     """
     ...
 
@@ -172,3 +696,15 @@ def get_ancestors(onto: PyIndexedOntology, child: str) -> Set[str]:
     ...
 
 
+def merge_ontologies(ontologies: List[PyIndexedOntology], ontology_id: Optional[model.OntologyID], drop_merged_imports: Optional[bool]) -> PyIndexedOntology:
+    """
+    Merges several ontologies into a new ontology.
+    
+    Components and prefixes are combined as in `PyIndexedOntology.merge`. The `OntologyID` of the
+    first ontology that has one is used unless `ontology_id` is given. If `drop_merged_imports` is
+    true, `Import`s of any of the merged ontologies are removed. A prefix bound to different IRIs
+    keeps its first binding and a warning is emitted.
+    """
+    ...
+
+
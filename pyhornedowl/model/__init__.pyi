@@ -5,24 +5,72 @@ class ObjectIntersectionOf:
     first: typing.List[ClassExpression]
     def __init__(self, first: typing.List[ClassExpression]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectIntersectionOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectIntersectionOf:
+        ...
     ...
 
 class ObjectUnionOf:
     first: typing.List[ClassExpression]
     def __init__(self, first: typing.List[ClassExpression]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectUnionOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectUnionOf:
+        ...
     ...
 
 class ObjectComplementOf:
     first: ClassExpression
     def __init__(self, first: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectComplementOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectComplementOf:
+        ...
     ...
 
 class ObjectOneOf:
     first: typing.List[Individual]
     def __init__(self, first: typing.List[Individual]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectOneOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectOneOf:
+        ...
     ...
 
 class ObjectSomeValuesFrom:
@@ -30,6 +78,18 @@ class ObjectSomeValuesFrom:
     bce: ClassExpression
     def __init__(self, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectSomeValuesFrom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectSomeValuesFrom:
+        ...
     ...
 
 class ObjectAllValuesFrom:
@@ -37,6 +97,18 @@ class ObjectAllValuesFrom:
     bce: ClassExpression
     def __init__(self, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectAllValuesFrom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectAllValuesFrom:
+        ...
     ...
 
 class ObjectHasValue:
@@ -44,12 +116,36 @@ class ObjectHasValue:
     i: Individual
     def __init__(self, ope: ObjectPropertyExpression, i: Individual):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectHasValue:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectHasValue:
+        ...
     ...
 
 class ObjectHasSelf:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectHasSelf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectHasSelf:
+        ...
     ...
 
 class ObjectMinCardinality:
@@ -58,6 +154,18 @@ class ObjectMinCardinality:
     bce: ClassExpression
     def __init__(self, n: int, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectMinCardinality:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectMinCardinality:
+        ...
     ...
 
 class ObjectMaxCardinality:
@@ -66,6 +174,18 @@ class ObjectMaxCardinality:
     bce: ClassExpression
     def __init__(self, n: int, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectMaxCardinality:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectMaxCardinality:
+        ...
     ...
 
 class ObjectExactCardinality:
@@ -74,6 +194,18 @@ class ObjectExactCardinality:
     bce: ClassExpression
     def __init__(self, n: int, ope: ObjectPropertyExpression, bce: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectExactCardinality:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectExactCardinality:
+        ...
     ...
 
 class DataSomeValuesFrom:
@@ -81,6 +213,18 @@ class DataSomeValuesFrom:
     dr: DataRange
     def __init__(self, dp: DataProperty, dr: DataRange):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataSomeValuesFrom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataSomeValuesFrom:
+        ...
     ...
 
 class DataAllValuesFrom:
@@ -88,6 +232,18 @@ class DataAllValuesFrom:
     dr: DataRange
     def __init__(self, dp: DataProperty, dr: DataRange):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataAllValuesFrom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataAllValuesFrom:
+        ...
     ...
 
 class DataHasValue:
@@ -95,6 +251,18 @@ class DataHasValue:
     l: Literal
     def __init__(self, dp: DataProperty, l: Literal):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataHasValue:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataHasValue:
+        ...
     ...
 
 class DataMinCardinality:
@@ -103,6 +271,18 @@ class DataMinCardinality:
     dr: DataRange
     def __init__(self, n: int, dp: DataProperty, dr: DataRange):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataMinCardinality:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataMinCardinality:
+        ...
     ...
 
 class DataMaxCardinality:
@@ -111,6 +291,18 @@ class DataMaxCardinality:
     dr: DataRange
     def __init__(self, n: int, dp: DataProperty, dr: DataRange):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataMaxCardinality:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataMaxCardinality:
+        ...
     ...
 
 class DataExactCardinality:
@@ -119,36 +311,108 @@ class DataExactCardinality:
     dr: DataRange
     def __init__(self, n: int, dp: DataProperty, dr: DataRange):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataExactCardinality:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataExactCardinality:
+        ...
     ...
 
 class Datatype:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> Datatype:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> Datatype:
+        ...
     ...
 
 class DataIntersectionOf:
     first: typing.List[DataRange]
     def __init__(self, first: typing.List[DataRange]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataIntersectionOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataIntersectionOf:
+        ...
     ...
 
 class DataUnionOf:
     first: typing.List[DataRange]
     def __init__(self, first: typing.List[DataRange]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataUnionOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataUnionOf:
+        ...
     ...
 
 class DataComplementOf:
     first: DataRange
     def __init__(self, first: DataRange):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataComplementOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataComplementOf:
+        ...
     ...
 
 class DataOneOf:
     first: typing.List[Literal]
     def __init__(self, first: typing.List[Literal]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataOneOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataOneOf:
+        ...
     ...
 
 class DatatypeRestriction:
@@ -156,12 +420,36 @@ class DatatypeRestriction:
     second: typing.List[FacetRestriction]
     def __init__(self, first: Datatype, second: typing.List[FacetRestriction]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DatatypeRestriction:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DatatypeRestriction:
+        ...
     ...
 
 class SimpleLiteral:
     literal: str
     def __init__(self, literal: str):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> SimpleLiteral:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> SimpleLiteral:
+        ...
     ...
 
 class LanguageLiteral:
@@ -169,6 +457,18 @@ class LanguageLiteral:
     lang: str
     def __init__(self, literal: str, lang: str):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> LanguageLiteral:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> LanguageLiteral:
+        ...
     ...
 
 class DatatypeLiteral:
@@ -176,18 +476,54 @@ class DatatypeLiteral:
     datatype_iri: IRI
     def __init__(self, literal: str, datatype_iri: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DatatypeLiteral:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DatatypeLiteral:
+        ...
     ...
 
 class ObjectProperty:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectProperty:
+        ...
     ...
 
 class InverseObjectProperty:
     first: ObjectProperty
     def __init__(self, first: ObjectProperty):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> InverseObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> InverseObjectProperty:
+        ...
     ...
 
 class AnnotatedComponent:
@@ -195,6 +531,18 @@ class AnnotatedComponent:
     ann: typing.Set[Annotation]
     def __init__(self, component: Component, ann: typing.Set[Annotation]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> AnnotatedComponent:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> AnnotatedComponent:
+        ...
     ...
 
 class Annotation:
@@ -202,6 +550,18 @@ class Annotation:
     av: AnnotationValue
     def __init__(self, ap: AnnotationProperty, av: AnnotationValue):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> Annotation:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> Annotation:
+        ...
     ...
 
 class AnnotationAssertion:
@@ -209,12 +569,36 @@ class AnnotationAssertion:
     ann: Annotation
     def __init__(self, subject: AnnotationSubject, ann: Annotation):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> AnnotationAssertion:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> AnnotationAssertion:
+        ...
     ...
 
 class AnnotationProperty:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> AnnotationProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> AnnotationProperty:
+        ...
     ...
 
 class AnnotationPropertyDomain:
@@ -222,6 +606,18 @@ class AnnotationPropertyDomain:
     iri: IRI
     def __init__(self, ap: AnnotationProperty, iri: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> AnnotationPropertyDomain:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> AnnotationPropertyDomain:
+        ...
     ...
 
 class AnnotationPropertyRange:
@@ -229,24 +625,72 @@ class AnnotationPropertyRange:
     iri: IRI
     def __init__(self, ap: AnnotationProperty, iri: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> AnnotationPropertyRange:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> AnnotationPropertyRange:
+        ...
     ...
 
 class AnonymousIndividual:
     first: str
     def __init__(self, first: str):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> AnonymousIndividual:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> AnonymousIndividual:
+        ...
     ...
 
 class AsymmetricObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> AsymmetricObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> AsymmetricObjectProperty:
+        ...
     ...
 
 class Class:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> Class:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> Class:
+        ...
     ...
 
 class ClassAssertion:
@@ -254,12 +698,36 @@ class ClassAssertion:
     i: Individual
     def __init__(self, ce: ClassExpression, i: Individual):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ClassAssertion:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ClassAssertion:
+        ...
     ...
 
 class DataProperty:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataProperty:
+        ...
     ...
 
 class DataPropertyAssertion:
@@ -268,6 +736,18 @@ class DataPropertyAssertion:
     to: Literal
     def __init__(self, dp: DataProperty,  to: Literal):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataPropertyAssertion:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataPropertyAssertion:
+        ...
     ...
 
 class DataPropertyDomain:
@@ -275,6 +755,18 @@ class DataPropertyDomain:
     ce: ClassExpression
     def __init__(self, dp: DataProperty, ce: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataPropertyDomain:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataPropertyDomain:
+        ...
     ...
 
 class DataPropertyRange:
@@ -282,6 +774,18 @@ class DataPropertyRange:
     dr: DataRange
     def __init__(self, dp: DataProperty, dr: DataRange):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataPropertyRange:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataPropertyRange:
+        ...
     ...
 
 class DatatypeDefinition:
@@ -289,66 +793,198 @@ class DatatypeDefinition:
     range: DataRange
     def __init__(self, kind: Datatype, range: DataRange):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DatatypeDefinition:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DatatypeDefinition:
+        ...
     ...
 
 class DeclareAnnotationProperty:
     first: AnnotationProperty
     def __init__(self, first: AnnotationProperty):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DeclareAnnotationProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DeclareAnnotationProperty:
+        ...
     ...
 
 class DeclareClass:
     first: Class
     def __init__(self, first: Class):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DeclareClass:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DeclareClass:
+        ...
     ...
 
 class DeclareDataProperty:
     first: DataProperty
     def __init__(self, first: DataProperty):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DeclareDataProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DeclareDataProperty:
+        ...
     ...
 
 class DeclareDatatype:
     first: Datatype
     def __init__(self, first: Datatype):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DeclareDatatype:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DeclareDatatype:
+        ...
     ...
 
 class DeclareNamedIndividual:
     first: NamedIndividual
     def __init__(self, first: NamedIndividual):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DeclareNamedIndividual:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DeclareNamedIndividual:
+        ...
     ...
 
 class DeclareObjectProperty:
     first: ObjectProperty
     def __init__(self, first: ObjectProperty):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DeclareObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DeclareObjectProperty:
+        ...
     ...
 
 class DifferentIndividuals:
     first: typing.List[Individual]
     def __init__(self, first: typing.List[Individual]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DifferentIndividuals:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DifferentIndividuals:
+        ...
     ...
 
 class DisjointClasses:
     first: typing.List[ClassExpression]
     def __init__(self, first: typing.List[ClassExpression]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DisjointClasses:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DisjointClasses:
+        ...
     ...
 
 class DisjointDataProperties:
     first: typing.List[DataProperty]
     def __init__(self, first: typing.List[DataProperty]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DisjointDataProperties:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DisjointDataProperties:
+        ...
     ...
 
 class DisjointObjectProperties:
     first: typing.List[ObjectPropertyExpression]
     def __init__(self, first: typing.List[ObjectPropertyExpression]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DisjointObjectProperties:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DisjointObjectProperties:
+        ...
     ...
 
 class DisjointUnion:
@@ -356,24 +992,72 @@ class DisjointUnion:
     second: typing.List[ClassExpression]
     def __init__(self, first: Class, second: typing.List[ClassExpression]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DisjointUnion:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DisjointUnion:
+        ...
     ...
 
 class EquivalentClasses:
     first: typing.List[ClassExpression]
     def __init__(self, first: typing.List[ClassExpression]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> EquivalentClasses:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> EquivalentClasses:
+        ...
     ...
 
 class EquivalentDataProperties:
     first: typing.List[DataProperty]
     def __init__(self, first: typing.List[DataProperty]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> EquivalentDataProperties:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> EquivalentDataProperties:
+        ...
     ...
 
 class EquivalentObjectProperties:
     first: typing.List[ObjectPropertyExpression]
     def __init__(self, first: typing.List[ObjectPropertyExpression]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> EquivalentObjectProperties:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> EquivalentObjectProperties:
+        ...
     ...
 
 class FacetRestriction:
@@ -381,18 +1065,54 @@ class FacetRestriction:
     l: Literal
     def __init__(self, f: Facet, l: Literal):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> FacetRestriction:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> FacetRestriction:
+        ...
     ...
 
 class FunctionalDataProperty:
     first: DataProperty
     def __init__(self, first: DataProperty):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> FunctionalDataProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> FunctionalDataProperty:
+        ...
     ...
 
 class FunctionalObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> FunctionalObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> FunctionalObjectProperty:
+        ...
     ...
 
 class HasKey:
@@ -400,6 +1120,18 @@ class HasKey:
     vpe: typing.List[PropertyExpression]
     def __init__(self, ce: ClassExpression, vpe: typing.List[PropertyExpression]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> HasKey:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> HasKey:
+        ...
     ...
 
 class IRI:
@@ -410,12 +1142,36 @@ class Import:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> Import:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> Import:
+        ...
     ...
 
 class InverseFunctionalObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> InverseFunctionalObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> InverseFunctionalObjectProperty:
+        ...
     ...
 
 class InverseObjectProperties:
@@ -423,18 +1179,54 @@ class InverseObjectProperties:
     second: ObjectProperty
     def __init__(self, first: ObjectProperty, second: ObjectProperty):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> InverseObjectProperties:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> InverseObjectProperties:
+        ...
     ...
 
 class IrreflexiveObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> IrreflexiveObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> IrreflexiveObjectProperty:
+        ...
     ...
 
 class NamedIndividual:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> NamedIndividual:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> NamedIndividual:
+        ...
     ...
 
 class NegativeDataPropertyAssertion:
@@ -443,6 +1235,18 @@ class NegativeDataPropertyAssertion:
     to: Literal
     def __init__(self, dp: DataProperty,  to: Literal):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> NegativeDataPropertyAssertion:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> NegativeDataPropertyAssertion:
+        ...
     ...
 
 class NegativeObjectPropertyAssertion:
@@ -451,6 +1255,18 @@ class NegativeObjectPropertyAssertion:
     to: Individual
     def __init__(self, ope: ObjectPropertyExpression,  to: Individual):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> NegativeObjectPropertyAssertion:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> NegativeObjectPropertyAssertion:
+        ...
     ...
 
 class ObjectPropertyAssertion:
@@ -459,6 +1275,18 @@ class ObjectPropertyAssertion:
     to: Individual
     def __init__(self, ope: ObjectPropertyExpression,  to: Individual):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectPropertyAssertion:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectPropertyAssertion:
+        ...
     ...
 
 class ObjectPropertyDomain:
@@ -466,6 +1294,18 @@ class ObjectPropertyDomain:
     ce: ClassExpression
     def __init__(self, ope: ObjectPropertyExpression, ce: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectPropertyDomain:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectPropertyDomain:
+        ...
     ...
 
 class ObjectPropertyRange:
@@ -473,12 +1313,36 @@ class ObjectPropertyRange:
     ce: ClassExpression
     def __init__(self, ope: ObjectPropertyExpression, ce: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectPropertyRange:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectPropertyRange:
+        ...
     ...
 
 class OntologyAnnotation:
     first: Annotation
     def __init__(self, first: Annotation):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> OntologyAnnotation:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> OntologyAnnotation:
+        ...
     ...
 
 class OntologyID:
@@ -486,24 +1350,72 @@ class OntologyID:
     viri: Option<IRI>
     def __init__(self, iri: Option<IRI>, viri: Option<IRI>):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> OntologyID:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> OntologyID:
+        ...
     ...
 
 class DocIRI:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DocIRI:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DocIRI:
+        ...
     ...
 
 class ReflexiveObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ReflexiveObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ReflexiveObjectProperty:
+        ...
     ...
 
 class SameIndividual:
     first: typing.List[Individual]
     def __init__(self, first: typing.List[Individual]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> SameIndividual:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> SameIndividual:
+        ...
     ...
 
 class SubAnnotationPropertyOf:
@@ -511,6 +1423,18 @@ class SubAnnotationPropertyOf:
     sub: AnnotationProperty
     def __init__(self, sup: AnnotationProperty, sub: AnnotationProperty):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> SubAnnotationPropertyOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> SubAnnotationPropertyOf:
+        ...
     ...
 
 class SubClassOf:
@@ -518,6 +1442,18 @@ class SubClassOf:
     sub: ClassExpression
     def __init__(self, sup: ClassExpression, sub: ClassExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> SubClassOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> SubClassOf:
+        ...
     ...
 
 class SubDataPropertyOf:
@@ -525,6 +1461,18 @@ class SubDataPropertyOf:
     sub: DataProperty
     def __init__(self, sup: DataProperty, sub: DataProperty):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> SubDataPropertyOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> SubDataPropertyOf:
+        ...
     ...
 
 class SubObjectPropertyOf:
@@ -532,18 +1480,54 @@ class SubObjectPropertyOf:
     sub: SubObjectPropertyExpression
     def __init__(self, sup: ObjectPropertyExpression, sub: SubObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> SubObjectPropertyOf:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> SubObjectPropertyOf:
+        ...
     ...
 
 class SymmetricObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> SymmetricObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> SymmetricObjectProperty:
+        ...
     ...
 
 class TransitiveObjectProperty:
     first: ObjectPropertyExpression
     def __init__(self, first: ObjectPropertyExpression):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> TransitiveObjectProperty:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> TransitiveObjectProperty:
+        ...
     ...
 
 class Facet:
@@ -559,17 +1543,90 @@ class Facet:
     FractionDigits: Facet
     LangRange: Facet
 
+class ComponentKind:
+    Import: ComponentKind
+    OntologyAnnotation: ComponentKind
+    DocIRI: ComponentKind
+    OntologyID: ComponentKind
+    DeclareClass: ComponentKind
+    DeclareObjectProperty: ComponentKind
+    DeclareAnnotationProperty: ComponentKind
+    DeclareDataProperty: ComponentKind
+    DeclareNamedIndividual: ComponentKind
+    DeclareDatatype: ComponentKind
+    SubClassOf: ComponentKind
+    EquivalentClasses: ComponentKind
+    DisjointClasses: ComponentKind
+    DisjointUnion: ComponentKind
+    SubObjectPropertyOf: ComponentKind
+    EquivalentObjectProperties: ComponentKind
+    DisjointObjectProperties: ComponentKind
+    InverseObjectProperties: ComponentKind
+    ObjectPropertyDomain: ComponentKind
+    ObjectPropertyRange: ComponentKind
+    FunctionalObjectProperty: ComponentKind
+    InverseFunctionalObjectProperty: ComponentKind
+    ReflexiveObjectProperty: ComponentKind
+    IrreflexiveObjectProperty: ComponentKind
+    SymmetricObjectProperty: ComponentKind
+    AsymmetricObjectProperty: ComponentKind
+    TransitiveObjectProperty: ComponentKind
+    SubDataPropertyOf: ComponentKind
+    EquivalentDataProperties: ComponentKind
+    DisjointDataProperties: ComponentKind
+    DataPropertyDomain: ComponentKind
+    DataPropertyRange: ComponentKind
+    FunctionalDataProperty: ComponentKind
+    DatatypeDefinition: ComponentKind
+    HasKey: ComponentKind
+    SameIndividual: ComponentKind
+    DifferentIndividuals: ComponentKind
+    ClassAssertion: ComponentKind
+    ObjectPropertyAssertion: ComponentKind
+    NegativeObjectPropertyAssertion: ComponentKind
+    DataPropertyAssertion: ComponentKind
+    NegativeDataPropertyAssertion: ComponentKind
+    AnnotationAssertion: ComponentKind
+    SubAnnotationPropertyOf: ComponentKind
+    AnnotationPropertyDomain: ComponentKind
+    AnnotationPropertyRange: ComponentKind
+    Rule: ComponentKind
+
 class Rule:
     head: typing.List[Atom]
     body: typing.List[Atom]
     def __init__(self, head: typing.List[Atom], body: typing.List[Atom]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> Rule:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> Rule:
+        ...
     ...
 
 class Variable:
     first: IRI
     def __init__(self, first: IRI):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> Variable:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> Variable:
+        ...
     ...
 
 class BuiltInAtom:
@@ -577,6 +1634,18 @@ class BuiltInAtom:
     args: typing.List[DArgument]
     def __init__(self, pred: IRI, args: typing.List[DArgument]):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> BuiltInAtom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> BuiltInAtom:
+        ...
     ...
 
 class ClassAtom:
@@ -584,6 +1653,18 @@ class ClassAtom:
     arg: IArgument
     def __init__(self, pred: ClassExpression, arg: IArgument):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ClassAtom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ClassAtom:
+        ...
     ...
 
 class DataPropertyAtom:
@@ -591,6 +1672,18 @@ class DataPropertyAtom:
     args: (DArgument, DArgument)
     def __init__(self, pred: DataProperty, args: (DArgument, DArgument)):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataPropertyAtom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataPropertyAtom:
+        ...
     ...
 
 class DataRangeAtom:
@@ -598,6 +1691,18 @@ class DataRangeAtom:
     arg: DArgument
     def __init__(self, pred: DataRange, arg: DArgument):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DataRangeAtom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DataRangeAtom:
+        ...
     ...
 
 class DifferentIndividualsAtom:
@@ -605,6 +1710,18 @@ class DifferentIndividualsAtom:
     second: IArgument
     def __init__(self, first: IArgument, second: IArgument):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> DifferentIndividualsAtom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> DifferentIndividualsAtom:
+        ...
     ...
 
 class ObjectPropertyAtom:
@@ -612,6 +1729,18 @@ class ObjectPropertyAtom:
     args: (IArgument, IArgument)
     def __init__(self, pred: ObjectPropertyExpression, args: (IArgument, IArgument)):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> ObjectPropertyAtom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> ObjectPropertyAtom:
+        ...
     ...
 
 class SameIndividualAtom:
@@ -619,6 +1748,18 @@ class SameIndividualAtom:
     second: IArgument
     def __init__(self, first: IArgument, second: IArgument):
         ...
+    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:
+        ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        ...
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> SameIndividualAtom:
+        ...
+    def to_json(self, indent: typing.Optional[int] = None) -> str:
+        ...
+    @classmethod
+    def from_json(cls, text: str) -> SameIndividualAtom:
+        ...
     ...
 
 ClassExpression = typing.Union[ObjectIntersectionOf, ObjectUnionOf, ObjectComplementOf, ObjectOneOf, ObjectSomeValuesFrom, ObjectAllValuesFrom, ObjectHasValue, ObjectHasSelf, ObjectMinCardinality, ObjectMaxCardinality, ObjectExactCardinality, DataSomeValuesFrom, DataAllValuesFrom, DataHasValue, DataMinCardinality, DataMaxCardinality, DataExactCardinality, Class]
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use horned_owl::model::*;
+use horned_owl::visitor::immutable::{Visit, Walk};
+use oxigraph::model::{BlankNode, Subject, Term, Triple};
+
+struct FirstIRI(Option<String>);
+
+impl Visit<ArcStr> for FirstIRI {
+    fn visit_iri(&mut self, iri: &IRI<ArcStr>) {
+        if self.0.is_none() {
+            self.0 = Some(iri.to_string());
+        }
+    }
+}
+
+/// The IRI of the entity a component is about.
+///
+/// As in `is_subject_of`, this is the subclass or subproperty of a subsumption and the individual
+/// of an assertion, otherwise the first IRI the component mentions. Components whose subject is
+/// anonymous also fall back to the first IRI.
+fn entity_iri(component: &Component<ArcStr>) -> String {
+    let mut walk = Walk::new(FirstIRI(None));
+    match component {
+        Component::SubClassOf(SubClassOf { sub, .. }) => walk.class_expression(sub),
+        Component::SubObjectPropertyOf(SubObjectPropertyOf { sub, .. }) => {
+            walk.sub_object_property_expression(sub)
+        }
+        Component::SubDataPropertyOf(SubDataPropertyOf { sub, .. }) => walk.data_property(sub),
+        Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf { sub, .. }) => {
+            walk.annotation_property(sub)
+        }
+        Component::ClassAssertion(ClassAssertion { i, .. }) => walk.individual(i),
+        Component::ObjectPropertyAssertion(ObjectPropertyAssertion { from, .. })
+        | Component::NegativeObjectPropertyAssertion(NegativeObjectPropertyAssertion { from, .. })
+        | Component::DataPropertyAssertion(DataPropertyAssertion { from, .. })
+        | Component::NegativeDataPropertyAssertion(NegativeDataPropertyAssertion { from, .. }) => {
+            walk.individual(from)
+        }
+        _ => {}
+    }
+    //Only sets the IRI if the subject did not have one
+    walk.component(component);
+    walk.into_visit().0.unwrap_or_default()
+}
+
+/// Sorts components by kind, then by the IRI of the entity they are about, then structurally.
+pub fn order<'a>(
+    components: impl Iterator<Item = &'a AnnotatedComponent<ArcStr>>,
+) -> Vec<&'a AnnotatedComponent<ArcStr>> {
+    let mut components: Vec<_> = components
+        .map(|ac| ((ac.kind(), entity_iri(&ac.component)), ac))
+        .collect();
+    components.sort();
+    components.into_iter().map(|(_, ac)| ac).collect()
+}
+
+/// 64-bit FNV-1a, used rather than the standard hasher so that labels do not depend on the Rust
+/// version.
+fn fnv(parts: &[String]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// Relabels blank nodes `c0`, `c1`, ... based on the triples around them rather than the order in
+/// which they were written.
+///
+/// Each blank node starts with the same hash, which is then repeatedly refined with the sorted
+/// triples it occurs in, using the hashes of neighbouring blank nodes in place of their labels,
+/// until the number of distinct hashes stops growing. Nodes are labelled in order of their final
+/// hashes. As the RDF mapping of OWL only produces trees of blank nodes, this distinguishes all
+/// nodes that are not structurally identical; ties are broken by their original order.
+pub fn label_blank_nodes(triples: Vec<Triple>) -> Vec<Triple> {
+    let mut nodes: Vec<BlankNode> = Vec::new();
+    let mut index: HashMap<BlankNode, usize> = HashMap::new();
+    let mut add = |node: &BlankNode| {
+        if !index.contains_key(node) {
+            index.insert(node.clone(), nodes.len());
+            nodes.push(node.clone());
+        }
+    };
+    for triple in &triples {
+        if let Subject::BlankNode(node) = &triple.subject {
+            add(node);
+        }
+        if let Term::BlankNode(node) = &triple.object {
+            add(node);
+        }
+    }
+    if nodes.is_empty() {
+        return triples;
+    }
+
+    let mut hashes = vec![0u64; nodes.len()];
+    let mut distinct = 1;
+    loop {
+        let term = |term: &Term, hashes: &[u64]| match term {
+            Term::BlankNode(node) => format!("_:{:016x}", hashes[index[node]]),
+            term => term.to_string(),
+        };
+        let mut signatures: Vec<Vec<String>> = hashes.iter().map(|h| vec![format!("{:016x}", h)]).collect();
+        for triple in &triples {
+            if let Subject::BlankNode(node) = &triple.subject {
+                let object = term(&triple.object, &hashes);
+                signatures[index[node]].push(format!("> {} {}", triple.predicate, object));
+            }
+            if let Term::BlankNode(node) = &triple.object {
+                let subject = term(&triple.subject.clone().into(), &hashes);
+                signatures[index[node]].push(format!("< {} {}", triple.predicate, subject));
+            }
+        }
+        let refined: Vec<u64> = signatures
+            .into_iter()
+            .map(|mut signature| {
+                signature[1..].sort();
+                fnv(&signature)
+            })
+            .collect();
+
+        let mut unique = refined.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        hashes = refined;
+        if unique.len() <= distinct {
+            break;
+        }
+        distinct = unique.len();
+    }
+
+    let mut order: Vec<usize> = (0..nodes.len()).collect();
+    order.sort_by_key(|&i| (hashes[i], i));
+    let mut labels: Vec<BlankNode> = vec![BlankNode::default(); nodes.len()];
+    for (label, i) in order.into_iter().enumerate() {
+        labels[i] = BlankNode::new_unchecked(format!("c{}", label));
+    }
+
+    triples
+        .into_iter()
+        .map(|triple| {
+            let subject = match triple.subject {
+                Subject::BlankNode(node) => Subject::BlankNode(labels[index[&node]].clone()),
+                subject => subject,
+            };
+            let object = match triple.object {
+                Term::BlankNode(node) => Term::BlankNode(labels[index[&node]].clone()),
+                object => object,
+            };
+            Triple::new(subject, triple.predicate, object)
+        })
+        .collect()
+}
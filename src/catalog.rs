@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// The file name Protégé uses for catalogs next to an ontology.
+pub const DEFAULT_CATALOG: &str = "catalog-v001.xml";
+
+/// Maps ontology IRIs to local files.
+///
+/// A catalog is either read from a Protégé-style OASIS XML catalog or it is a directory in which
+/// an IRI is resolved to the file named like the last segment of the IRI path.
+///
+/// Of an XML catalog, `uri` entries map the `name` IRI to the file in `uri` and `rewriteURI`
+/// entries map IRIs starting with `uriStartString` to files starting with `rewritePrefix`. Files
+/// are relative to the catalog or to the `xml:base` of the entry or an enclosing `group`.
+pub enum Catalog {
+    Xml {
+        uris: HashMap<String, PathBuf>,
+        //Rewritten start of IRIs and the path prefix replacing it
+        rewrites: Vec<(String, String)>,
+    },
+    Directory(PathBuf),
+}
+
+impl Catalog {
+    /// Opens a catalog from an XML catalog file or a directory.
+    pub fn open(path: &Path) -> PyResult<Catalog> {
+        if path.is_dir() {
+            return Ok(Catalog::Directory(path.to_path_buf()));
+        }
+
+        let content = fs::read_to_string(path).map_err(|e| {
+            PyValueError::new_err(format!("Unable to read catalog '{}': {}", path.display(), e))
+        })?;
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+
+        parse_catalog(&content, base).map_err(|e| {
+            PyValueError::new_err(format!("Unable to read catalog '{}': {}", path.display(), e))
+        })
+    }
+
+    /// Opens `catalog` if given, otherwise the default catalog next to the ontology file `ontology`.
+    pub fn for_ontology(ontology: &str, catalog: Option<String>) -> PyResult<Catalog> {
+        let path = match catalog {
+            Some(catalog) => PathBuf::from(catalog),
+            None if Path::new(ontology).is_file() => Path::new(ontology)
+                .parent()
+                .unwrap_or_else(|| Path::new(""))
+                .join(DEFAULT_CATALOG),
+            None => {
+                return Err(PyValueError::new_err(
+                    "A catalog is required to resolve the imports of an ontology given as text.",
+                ))
+            }
+        };
+
+        Catalog::open(&path)
+    }
+
+    /// Returns the local file for an ontology IRI, if the catalog maps it to an existing file.
+    pub fn resolve(&self, iri: &str) -> Option<PathBuf> {
+        let path = match self {
+            Catalog::Xml { uris, rewrites } => uris.get(iri).cloned().or_else(|| {
+                //The longest matching start string wins
+                rewrites
+                    .iter()
+                    .filter(|(start, _)| iri.starts_with(start.as_str()))
+                    .max_by_key(|(start, _)| start.len())
+                    .map(|(start, prefix)| PathBuf::from(format!("{}{}", prefix, &iri[start.len()..])))
+            }),
+            Catalog::Directory(dir) => iri
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .filter(|name| !name.is_empty())
+                .map(|name| dir.join(name)),
+        };
+
+        path.filter(|p| p.is_file())
+    }
+}
+
+fn parse_catalog(content: &str, base: &Path) -> quick_xml::Result<Catalog> {
+    let mut uris = HashMap::new();
+    let mut rewrites = Vec::new();
+
+    //The base directory of each open element, starting with the directory of the catalog
+    let mut bases = vec![base.to_path_buf()];
+    let mut reader = Reader::from_str(content);
+    loop {
+        let (element, open) = match reader.read_event()? {
+            Event::Start(element) => (element, true),
+            Event::Empty(element) => (element, false),
+            Event::End(_) => {
+                bases.pop();
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        let attributes = attributes(&element)?;
+        let mut base = bases.last().cloned().unwrap_or_default();
+        if let Some(xml_base) = attributes.get("xml:base") {
+            base = base_directory(&base, xml_base);
+        }
+
+        match element.local_name().as_ref() {
+            b"uri" => {
+                if let (Some(name), Some(uri)) = (attributes.get("name"), attributes.get("uri")) {
+                    uris.insert(name.clone(), base.join(local_path(uri)));
+                }
+            }
+            b"rewriteURI" => {
+                if let (Some(start), Some(prefix)) =
+                    (attributes.get("uriStartString"), attributes.get("rewritePrefix"))
+                {
+                    let prefix = base.join(local_path(prefix)).to_string_lossy().into_owned();
+                    rewrites.push((start.clone(), prefix));
+                }
+            }
+            _ => (),
+        }
+
+        if open {
+            bases.push(base);
+        }
+    }
+
+    Ok(Catalog::Xml { uris, rewrites })
+}
+
+/// Returns the attributes of an element by qualified name, with entities and character
+/// references decoded.
+fn attributes(element: &BytesStart) -> quick_xml::Result<HashMap<String, String>> {
+    let mut attributes = HashMap::new();
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+        let name = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        attributes.insert(name, attribute.unescape_value()?.into_owned());
+    }
+    Ok(attributes)
+}
+
+/// Strips the `file:` scheme from a URI referring to a local file.
+fn local_path(uri: &str) -> &str {
+    uri.strip_prefix("file://")
+        .or_else(|| uri.strip_prefix("file:"))
+        .unwrap_or(uri)
+}
+
+/// Resolves an `xml:base` against the base directory `base`. As for URIs, the last segment of
+/// the base is dropped unless it ends with a slash.
+fn base_directory(base: &Path, xml_base: &str) -> PathBuf {
+    let resolved = base.join(local_path(xml_base));
+    if xml_base.is_empty() || xml_base.ends_with('/') {
+        resolved
+    } else {
+        resolved.parent().map(Path::to_path_buf).unwrap_or_default()
+    }
+}
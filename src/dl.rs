@@ -0,0 +1,474 @@
+//! Evaluation of DL queries against the told (asserted) axioms of an ontology.
+//!
+//! No reasoner is available, so a class is considered a subclass of an expression if this
+//! follows from the asserted subclass and equivalence axioms by following named superclasses,
+//! intersections, unions and existential or universal restrictions on the same property.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use horned_owl::model::*;
+use horned_owl::vocab::OWL;
+
+use crate::PyIndexedOntology;
+
+/// Maximum nesting of restrictions followed when checking subsumption.
+const MAX_DEPTH: usize = 16;
+
+/// Splits a class expression into its conjuncts.
+fn conjuncts(ce: ClassExpression<ArcStr>) -> Vec<ClassExpression<ArcStr>> {
+    match ce {
+        ClassExpression::ObjectIntersectionOf(ces) => ces.into_iter().flat_map(conjuncts).collect(),
+        ce => vec![ce],
+    }
+}
+
+fn is_thing(ce: &ClassExpression<ArcStr>) -> bool {
+    matches!(ce, ClassExpression::Class(c) if c.0.to_string() == OWL::Thing.as_ref())
+}
+
+/// Returns the property IRI a restriction is on, if `ce` is a restriction.
+fn restricted_property(ce: &ClassExpression<ArcStr>) -> Option<IRI<ArcStr>> {
+    use ClassExpression::*;
+
+    match ce {
+        ObjectSomeValuesFrom { ope, .. }
+        | ObjectAllValuesFrom { ope, .. }
+        | ObjectHasValue { ope, .. }
+        | ObjectHasSelf(ope)
+        | ObjectMinCardinality { ope, .. }
+        | ObjectMaxCardinality { ope, .. }
+        | ObjectExactCardinality { ope, .. } => match ope {
+            ObjectPropertyExpression::ObjectProperty(op)
+            | ObjectPropertyExpression::InverseObjectProperty(op) => Some(op.0.clone()),
+        },
+        DataSomeValuesFrom { dp, .. }
+        | DataAllValuesFrom { dp, .. }
+        | DataHasValue { dp, .. }
+        | DataMinCardinality { dp, .. }
+        | DataMaxCardinality { dp, .. }
+        | DataExactCardinality { dp, .. } => Some(dp.0.clone()),
+        _ => None,
+    }
+}
+
+impl PyIndexedOntology {
+    /// Returns the asserted superclass expressions of a named class, split into conjuncts.
+    ///
+    /// These come from subclass axioms with the class on the left-hand side and from equivalence
+    /// axioms including the class.
+    fn told_superclass_expressions(&mut self, iri: &IRI<ArcStr>) -> Vec<ClassExpression<ArcStr>> {
+        let class = ClassExpression::Class(Class(iri.clone()));
+        let mut expressions = Vec::new();
+
+        for aax in self.ontology.components_for_iri(iri) {
+            match &aax.component {
+                Component::SubClassOf(SubClassOf { sub, sup }) if *sub == class => {
+                    expressions.push(sup.clone())
+                }
+                Component::EquivalentClasses(EquivalentClasses(ces)) if ces.contains(&class) => {
+                    expressions.extend(ces.iter().filter(|ce| **ce != class).cloned())
+                }
+                _ => (),
+            }
+        }
+
+        expressions.into_iter().flat_map(conjuncts).collect()
+    }
+
+    /// Returns the named classes asserted to be direct subclasses of, or equivalent to, a named class.
+    fn told_direct_subclasses(&mut self, iri: &IRI<ArcStr>) -> HashSet<IRI<ArcStr>> {
+        let class = ClassExpression::Class(Class(iri.clone()));
+        let mut subclasses = HashSet::new();
+
+        for aax in self.ontology.components_for_iri(iri) {
+            match &aax.component {
+                Component::SubClassOf(SubClassOf {
+                    sub: ClassExpression::Class(sub),
+                    sup,
+                }) if conjuncts(sup.clone()).contains(&class) => {
+                    subclasses.insert(sub.0.clone());
+                }
+                Component::EquivalentClasses(EquivalentClasses(ces))
+                    if ces.iter().any(|ce| conjuncts(ce.clone()).contains(&class)) =>
+                {
+                    subclasses.extend(ces.iter().filter_map(|ce| match ce {
+                        ClassExpression::Class(c) => Some(c.0.clone()),
+                        _ => None,
+                    }))
+                }
+                _ => (),
+            }
+        }
+
+        subclasses.remove(iri);
+        subclasses
+    }
+
+    /// Returns a named class and all its told named superclasses.
+    fn told_ancestors(&mut self, iri: &IRI<ArcStr>) -> HashSet<IRI<ArcStr>> {
+        let mut ancestors = HashSet::new();
+        let mut queue = VecDeque::from(vec![iri.clone()]);
+
+        while let Some(next) = queue.pop_front() {
+            if ancestors.insert(next.clone()) {
+                for ce in self.told_superclass_expressions(&next) {
+                    if let ClassExpression::Class(c) = ce {
+                        queue.push_back(c.0);
+                    }
+                }
+            }
+        }
+
+        ancestors
+    }
+
+    /// Returns a named class and all its told named subclasses.
+    fn told_descendants(&mut self, iri: &IRI<ArcStr>) -> HashSet<IRI<ArcStr>> {
+        let mut descendants = HashSet::new();
+        let mut queue = VecDeque::from(vec![iri.clone()]);
+
+        while let Some(next) = queue.pop_front() {
+            if descendants.insert(next.clone()) {
+                queue.extend(self.told_direct_subclasses(&next));
+            }
+        }
+
+        descendants
+    }
+
+    fn all_classes(&mut self) -> HashSet<IRI<ArcStr>> {
+        let mut classes: HashSet<IRI<ArcStr>> = self
+            .ontology
+            .component_for_kind(ComponentKind::DeclareClass)
+            .filter_map(|aax| match &aax.component {
+                Component::DeclareClass(DeclareClass(c)) => Some(c.0.clone()),
+                _ => None,
+            })
+            .collect();
+        classes.extend(self.classes_to_subclasses.keys().cloned());
+        classes.extend(self.classes_to_superclasses.keys().cloned());
+        classes
+    }
+
+    /// Returns a superset of the named classes that may be subclasses of `ce`, or `None` if every
+    /// class has to be checked.
+    fn subclass_candidates(&mut self, ce: &ClassExpression<ArcStr>) -> Option<HashSet<IRI<ArcStr>>> {
+        match ce {
+            _ if is_thing(ce) => None,
+            ClassExpression::Class(c) => Some(self.told_descendants(&c.0)),
+            ClassExpression::ObjectIntersectionOf(ces) => ces
+                .iter()
+                .filter_map(|ce| self.subclass_candidates(ce))
+                .min_by_key(|candidates| candidates.len()),
+            ClassExpression::ObjectUnionOf(ces) => {
+                let mut candidates = HashSet::new();
+                for ce in ces {
+                    candidates.extend(self.subclass_candidates(ce)?);
+                }
+                Some(candidates)
+            }
+            _ => {
+                let property = restricted_property(ce)?;
+                let restricted: HashSet<IRI<ArcStr>> = self
+                    .ontology
+                    .components_for_iri(&property)
+                    .flat_map(|aax| match &aax.component {
+                        Component::SubClassOf(SubClassOf {
+                            sub: ClassExpression::Class(c),
+                            ..
+                        }) => vec![c.0.clone()],
+                        Component::EquivalentClasses(EquivalentClasses(ces)) => ces
+                            .iter()
+                            .filter_map(|ce| match ce {
+                                ClassExpression::Class(c) => Some(c.0.clone()),
+                                _ => None,
+                            })
+                            .collect(),
+                        _ => vec![],
+                    })
+                    .collect();
+
+                let mut candidates = HashSet::new();
+                for iri in restricted {
+                    candidates.extend(self.told_descendants(&iri));
+                }
+                Some(candidates)
+            }
+        }
+    }
+
+    /// Checks whether the named class `iri` is a told subclass of `ce`.
+    fn is_told_subclass(&mut self, iri: &IRI<ArcStr>, ce: &ClassExpression<ArcStr>, depth: usize) -> bool {
+        if depth > MAX_DEPTH {
+            return false;
+        }
+
+        match ce {
+            _ if is_thing(ce) => true,
+            ClassExpression::Class(c) => self.told_ancestors(iri).contains(&c.0),
+            ClassExpression::ObjectIntersectionOf(ces) => {
+                ces.iter().all(|ce| self.is_told_subclass(iri, ce, depth))
+            }
+            ClassExpression::ObjectUnionOf(ces) => {
+                ces.iter().any(|ce| self.is_told_subclass(iri, ce, depth))
+            }
+            _ => {
+                for ancestor in self.told_ancestors(iri) {
+                    for sup in self.told_superclass_expressions(&ancestor) {
+                        if sup == *ce || self.is_told_restriction_subclass(&sup, ce, depth + 1) {
+                            return true;
+                        }
+                    }
+                }
+                false
+            }
+        }
+    }
+
+    fn is_told_restriction_subclass(
+        &mut self,
+        sub: &ClassExpression<ArcStr>,
+        sup: &ClassExpression<ArcStr>,
+        depth: usize,
+    ) -> bool {
+        use ClassExpression::*;
+
+        match (sub, sup) {
+            (
+                ObjectSomeValuesFrom { ope, bce },
+                ObjectSomeValuesFrom {
+                    ope: sup_ope,
+                    bce: sup_bce,
+                },
+            )
+            | (
+                ObjectAllValuesFrom { ope, bce },
+                ObjectAllValuesFrom {
+                    ope: sup_ope,
+                    bce: sup_bce,
+                },
+            ) if ope == sup_ope => self.is_told_expression_subclass(bce, sup_bce, depth),
+            _ => false,
+        }
+    }
+
+    fn is_told_expression_subclass(
+        &mut self,
+        sub: &ClassExpression<ArcStr>,
+        sup: &ClassExpression<ArcStr>,
+        depth: usize,
+    ) -> bool {
+        if sub == sup || is_thing(sup) {
+            return true;
+        }
+
+        match sub {
+            ClassExpression::Class(c) => self.is_told_subclass(&c.0, sup, depth),
+            ClassExpression::ObjectIntersectionOf(ces) => ces
+                .iter()
+                .any(|ce| self.is_told_expression_subclass(ce, sup, depth)),
+            _ => self.is_told_restriction_subclass(sub, sup, depth),
+        }
+    }
+
+    /// Returns the named classes that are told superclasses of `ce`, including its named
+    /// conjuncts and, for a named class, the class itself.
+    fn told_named_superclasses(&mut self, ce: &ClassExpression<ArcStr>) -> HashSet<IRI<ArcStr>> {
+        let mut direct = Vec::new();
+        match ce {
+            ClassExpression::Class(c) => return self.told_ancestors(&c.0),
+            ClassExpression::ObjectIntersectionOf(ces) => {
+                let mut superclasses = HashSet::new();
+                for ce in ces {
+                    superclasses.extend(self.told_named_superclasses(ce));
+                }
+                direct.extend(superclasses);
+            }
+            _ => (),
+        }
+
+        for aax in self.ontology.component_for_kind(ComponentKind::SubClassOf) {
+            if let Component::SubClassOf(SubClassOf {
+                sub,
+                sup: ClassExpression::Class(c),
+            }) = &aax.component
+            {
+                if sub == ce {
+                    direct.push(c.0.clone());
+                }
+            }
+        }
+        for aax in self.ontology.component_for_kind(ComponentKind::EquivalentClasses) {
+            if let Component::EquivalentClasses(EquivalentClasses(ces)) = &aax.component {
+                if ces.contains(ce) {
+                    direct.extend(ces.iter().filter_map(|ce| match ce {
+                        ClassExpression::Class(c) => Some(c.0.clone()),
+                        _ => None,
+                    }));
+                }
+            }
+        }
+
+        let mut superclasses = HashSet::new();
+        for iri in direct {
+            superclasses.extend(self.told_ancestors(&iri));
+        }
+        superclasses
+    }
+
+    /// Returns the named classes equivalent to `ce`, excluding `ce` itself if it is named.
+    pub(crate) fn query_equivalents(&mut self, ce: &ClassExpression<ArcStr>) -> HashSet<IRI<ArcStr>> {
+        let mut equivalents: HashSet<IRI<ArcStr>> = self
+            .told_named_superclasses(ce)
+            .into_iter()
+            .filter(|iri| self.is_told_subclass(iri, ce, 0))
+            .collect();
+
+        if let ClassExpression::Class(c) = ce {
+            equivalents.remove(&c.0);
+        }
+        equivalents
+    }
+
+    /// Returns the named strict subclasses of `ce`.
+    pub(crate) fn query_subclasses(&mut self, ce: &ClassExpression<ArcStr>) -> HashSet<IRI<ArcStr>> {
+        let candidates = match self.subclass_candidates(ce) {
+            Some(candidates) => candidates,
+            None => self.all_classes(),
+        };
+        let excluded = self.query_equivalents(ce);
+
+        candidates
+            .into_iter()
+            .filter(|iri| !excluded.contains(iri) && ce != &ClassExpression::Class(Class(iri.clone())))
+            .filter(|iri| self.is_told_subclass(iri, ce, 0))
+            .collect()
+    }
+
+    /// Returns the named strict superclasses of `ce`.
+    pub(crate) fn query_superclasses(&mut self, ce: &ClassExpression<ArcStr>) -> HashSet<IRI<ArcStr>> {
+        let excluded = self.query_equivalents(ce);
+
+        self.told_named_superclasses(ce)
+            .into_iter()
+            .filter(|iri| !excluded.contains(iri) && ce != &ClassExpression::Class(Class(iri.clone())))
+            .collect()
+    }
+
+    /// Returns the named individuals that are told instances of `ce`.
+    pub(crate) fn query_instances(&mut self, ce: &ClassExpression<ArcStr>) -> HashSet<IRI<ArcStr>> {
+        let mut cache = HashMap::new();
+        self.told_instances(ce, &mut cache, 0)
+    }
+
+    fn told_instances(
+        &mut self,
+        ce: &ClassExpression<ArcStr>,
+        cache: &mut HashMap<IRI<ArcStr>, bool>,
+        depth: usize,
+    ) -> HashSet<IRI<ArcStr>> {
+        let mut instances = HashSet::new();
+        if depth > MAX_DEPTH {
+            return instances;
+        }
+
+        let assertions: Vec<(ClassExpression<ArcStr>, IRI<ArcStr>)> = self
+            .ontology
+            .component_for_kind(ComponentKind::ClassAssertion)
+            .filter_map(|aax| match &aax.component {
+                Component::ClassAssertion(ClassAssertion {
+                    ce,
+                    i: Individual::Named(i),
+                }) => Some((ce.clone(), i.0.clone())),
+                _ => None,
+            })
+            .collect();
+
+        for (asserted, individual) in assertions {
+            let matches = match &asserted {
+                _ if asserted == *ce => true,
+                ClassExpression::Class(c) => match cache.get(&c.0) {
+                    Some(matches) => *matches,
+                    None => {
+                        let matches = self.is_told_subclass(&c.0, ce, depth);
+                        cache.insert(c.0.clone(), matches);
+                        matches
+                    }
+                },
+                _ => self.is_told_expression_subclass(&asserted, ce, depth),
+            };
+            if matches {
+                instances.insert(individual);
+            }
+        }
+
+        match ce {
+            ClassExpression::ObjectIntersectionOf(ces) => {
+                let mut operands = ces.iter();
+                if let Some(first) = operands.next() {
+                    let mut all = self.told_instances(first, cache, depth + 1);
+                    for operand in operands {
+                        let other = self.told_instances(operand, cache, depth + 1);
+                        all.retain(|i| other.contains(i));
+                    }
+                    instances.extend(all);
+                }
+            }
+            ClassExpression::ObjectUnionOf(ces) => {
+                for operand in ces {
+                    instances.extend(self.told_instances(operand, cache, depth + 1));
+                }
+            }
+            ClassExpression::ObjectSomeValuesFrom { ope, bce } => {
+                let fillers = self.told_instances(bce, cache, depth + 1);
+                instances.extend(
+                    self.object_property_assertions(ope)
+                        .into_iter()
+                        .filter(|(_, to)| fillers.contains(to))
+                        .map(|(from, _)| from),
+                );
+            }
+            ClassExpression::ObjectHasValue {
+                ope,
+                i: Individual::Named(value),
+            } => {
+                instances.extend(
+                    self.object_property_assertions(ope)
+                        .into_iter()
+                        .filter(|(_, to)| *to == value.0)
+                        .map(|(from, _)| from),
+                );
+            }
+            _ => (),
+        }
+
+        instances
+    }
+
+    /// Returns the pairs of named individuals related by an object property expression.
+    fn object_property_assertions(
+        &mut self,
+        ope: &ObjectPropertyExpression<ArcStr>,
+    ) -> Vec<(IRI<ArcStr>, IRI<ArcStr>)> {
+        let (op, inverse) = match ope {
+            ObjectPropertyExpression::ObjectProperty(op) => (op, false),
+            ObjectPropertyExpression::InverseObjectProperty(op) => (op, true),
+        };
+
+        self.ontology
+            .components_for_iri(&op.0)
+            .filter_map(|aax| match &aax.component {
+                Component::ObjectPropertyAssertion(ObjectPropertyAssertion {
+                    ope: ObjectPropertyExpression::ObjectProperty(p),
+                    from: Individual::Named(from),
+                    to: Individual::Named(to),
+                }) if p == op => Some(if inverse {
+                    (to.0.clone(), from.0.clone())
+                } else {
+                    (from.0.clone(), to.0.clone())
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,147 @@
+use std::io::{Cursor, Read};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Compression formats by file extension and magic number.
+const COMPRESSIONS: [(&str, &[u8]); 4] = [
+    ("gz", &[0x1f, 0x8b]),
+    ("bz2", b"BZh"),
+    ("zst", &[0x28, 0xb5, 0x2f, 0xfd]),
+    ("zip", b"PK\x03\x04"),
+];
+
+/// The formats that can be read, by name and by file extension.
+const FORMATS: [(&str, &[&str]); 7] = [
+    ("owx", &["owx"]),
+    ("rdfxml", &["owl", "rdf"]),
+    ("turtle", &["ttl"]),
+    ("ntriples", &["nt"]),
+    ("jsonld", &["jsonld"]),
+    ("obo", &["obo"]),
+    ("obographs", &["json"]),
+];
+
+/// Decompresses gzip, bzip2 or zstd data or a zip archive holding a single file, recognised by
+/// its magic number. Other data is returned unchanged.
+pub fn decompress(data: Vec<u8>) -> PyResult<Vec<u8>> {
+    let compression = COMPRESSIONS
+        .iter()
+        .find(|(_, magic)| data.starts_with(magic))
+        .map(|(extension, _)| *extension);
+    let mut decompressed = Vec::new();
+    let result = match compression {
+        Some("gz") => flate2::read::MultiGzDecoder::new(data.as_slice()).read_to_end(&mut decompressed),
+        Some("bz2") => bzip2::read::MultiBzDecoder::new(data.as_slice()).read_to_end(&mut decompressed),
+        Some("zst") => zstd::stream::read::Decoder::new(data.as_slice())?.read_to_end(&mut decompressed),
+        Some(_) => return unzip(&data),
+        None => return Ok(data),
+    };
+    result.map_err(|e| PyValueError::new_err(format!("Unable to decompress ontology: {}", e)))?;
+    Ok(decompressed)
+}
+
+fn zip_error<E: std::fmt::Display>(error: E) -> PyErr {
+    PyValueError::new_err(format!("Unable to read zip archive: {}", error))
+}
+
+/// Extracts the single file of a zip archive. Directory entries are ignored and archives holding
+/// more than one file are rejected.
+fn unzip(data: &[u8]) -> PyResult<Vec<u8>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(data)).map_err(zip_error)?;
+    let mut file = None;
+    for index in 0..archive.len() {
+        if archive.by_index_raw(index).map_err(zip_error)?.is_dir() {
+            continue;
+        }
+        if file.replace(index).is_some() {
+            return Err(zip_error("the archive holds more than one file"));
+        }
+    }
+
+    let index = file.ok_or_else(|| zip_error("the archive holds no file"))?;
+    let mut decompressed = Vec::new();
+    archive
+        .by_index(index)
+        .map_err(zip_error)?
+        .read_to_end(&mut decompressed)
+        .map_err(zip_error)?;
+    Ok(decompressed)
+}
+
+/// Decodes the text of an ontology document, decompressing it first if needed.
+pub fn text(data: Vec<u8>) -> PyResult<String> {
+    String::from_utf8(decompress(data)?)
+        .map_err(|e| PyValueError::new_err(format!("Ontology is not valid UTF-8: {}", e)))
+}
+
+/// Returns the name of a format given by its name or file extension.
+pub fn format_name(format: &str) -> PyResult<&'static str> {
+    let format = format.trim_start_matches('.').to_lowercase();
+    FORMATS
+        .iter()
+        .find(|(name, extensions)| *name == format || extensions.contains(&format.as_str()))
+        .map(|(name, _)| *name)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown format '{}'.", format)))
+}
+
+/// Returns the format of a file from its extension, ignoring a compression extension such as
+/// `.gz`.
+pub fn format_for_path(path: &str) -> Option<&'static str> {
+    let mut extensions = path.rsplit('.');
+    let mut extension = extensions.next()?;
+    if COMPRESSIONS.iter().any(|(compressed, _)| *compressed == extension) {
+        extension = extensions.next()?;
+    }
+    format_name(extension).ok()
+}
+
+/// Whether the first line of `text` is an OBO stanza header such as `[Term]` or `[Typedef]`.
+fn starts_with_stanza(text: &str) -> bool {
+    let line = text.lines().next().unwrap_or_default().trim_end();
+    line.strip_prefix('[')
+        .and_then(|line| line.strip_suffix(']'))
+        .is_some_and(|name| !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+/// Guesses the format of an ontology document from its start.
+pub fn guess_format(text: &str) -> PyResult<&'static str> {
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    //OBO files without a header start with a stanza, which looks like a JSON array
+    let format = if text.starts_with("format-version:")
+        || starts_with_stanza(text)
+        || (text.contains("\n[Term]") && !text.starts_with(['<', '{', '[']))
+    {
+        "obo"
+    } else if text.starts_with('{') || text.starts_with('[') {
+        //OBO Graphs documents are objects that use none of the JSON-LD keywords
+        let keywords = ["\"@context\"", "\"@graph\"", "\"@id\""];
+        if text.starts_with('[') || keywords.iter().any(|keyword| text.contains(keyword)) {
+            "jsonld"
+        } else {
+            "obographs"
+        }
+    } else if text.starts_with("@prefix") || text.starts_with("PREFIX") || text.starts_with("@base") {
+        "turtle"
+    } else if text.starts_with('<') && !text.starts_with("<?") && !text.starts_with("<!") {
+        let first_line = text.lines().next().unwrap_or_default().trim_end();
+        if first_line.ends_with('.') && first_line.contains("> ") {
+            "ntriples"
+        } else if first_line.starts_with("<rdf:RDF") {
+            "rdfxml"
+        } else {
+            "owx"
+        }
+    } else if text.contains("<rdf:RDF") {
+        "rdfxml"
+    } else if text.starts_with('<') {
+        "owx"
+    } else if text.contains("@prefix") || text.contains("PREFIX") {
+        "turtle"
+    } else {
+        return Err(PyValueError::new_err(
+            "Unknown format: unable to guess the format of the ontology, pass it as `format`.",
+        ));
+    };
+    Ok(format)
+}
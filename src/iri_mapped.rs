@@ -0,0 +1,140 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+use horned_owl::model::*;
+use horned_owl::ontology::component_mapped::ArcComponentMappedOntology;
+use horned_owl::ontology::set::SetOntology;
+use horned_owl::visitor::immutable::entity::IRIExtract;
+use horned_owl::visitor::immutable::Walk;
+
+/// An ontology indexed by component kind and by the IRIs each component mentions.
+///
+/// This replaces horned-owl's `IRIMappedOntology`, which only removes a component from the index
+/// of one of its IRIs, so that a removed component was still returned for the others.
+#[derive(Default)]
+pub struct IRIMappedOntology {
+    kinds: BTreeMap<ComponentKind, BTreeSet<Arc<AnnotatedComponent<ArcStr>>>>,
+    iris: BTreeMap<IRI<ArcStr>, BTreeSet<Arc<AnnotatedComponent<ArcStr>>>>,
+}
+
+/// The IRIs mentioned anywhere in `cmp`, including its annotations.
+fn mentioned_iris(cmp: &AnnotatedComponent<ArcStr>) -> BTreeSet<IRI<ArcStr>> {
+    let mut walk = Walk::new(IRIExtract::default());
+    walk.annotated_component(cmp);
+    walk.into_visit().into_vec().into_iter().collect()
+}
+
+impl IRIMappedOntology {
+    /// The components mentioning `iri`.
+    pub fn components_for_iri(&self, iri: &IRI<ArcStr>) -> impl Iterator<Item = &AnnotatedComponent<ArcStr>> {
+        self.iris.get(iri).into_iter().flat_map(|set| set.iter()).map(|cmp| cmp.as_ref())
+    }
+
+    /// The components of kind `cmk`.
+    pub fn component_for_kind(&self, cmk: ComponentKind) -> impl Iterator<Item = &AnnotatedComponent<ArcStr>> {
+        self.kinds.get(&cmk).into_iter().flat_map(|set| set.iter()).map(|cmp| cmp.as_ref())
+    }
+
+    /// All components, ordered by kind.
+    pub fn iter(&self) -> impl Iterator<Item = &AnnotatedComponent<ArcStr>> {
+        self.kinds.values().flat_map(|set| set.iter()).map(|cmp| cmp.as_ref())
+    }
+
+    /// Passes the ontology to `f` as the `ComponentMappedOntology` the horned-owl writers take.
+    ///
+    /// The writers of horned-owl 1.0 only accept that concrete type, which cannot borrow the
+    /// components, so they are moved into it and back rather than copied, so that writing does not
+    /// need a second copy of the ontology in memory. The ontology is restored even if `f` panics.
+    pub fn with_component_mapped<R>(&mut self, f: impl FnOnce(&ArcComponentMappedOntology) -> R) -> R {
+        let restore = Restore::new(self);
+        f(&restore.cmo)
+    }
+}
+
+/// Moves the components of an ontology into a `ComponentMappedOntology` and back when dropped.
+struct Restore<'a> {
+    ontology: &'a mut IRIMappedOntology,
+    cmo: ArcComponentMappedOntology,
+    //For each IRI, the positions in `iter` order of the components mentioning it, so that the
+    //index can be rebuilt without extracting the IRIs again
+    positions: Vec<(IRI<ArcStr>, Vec<usize>)>,
+}
+
+impl<'a> Restore<'a> {
+    fn new(ontology: &'a mut IRIMappedOntology) -> Restore<'a> {
+        let index: HashMap<*const AnnotatedComponent<ArcStr>, usize> =
+            ontology.kinds.values().flatten().enumerate().map(|(i, cmp)| (Arc::as_ptr(cmp), i)).collect();
+        let positions = std::mem::take(&mut ontology.iris)
+            .into_iter()
+            .map(|(iri, set)| (iri, set.iter().map(|cmp| index[&Arc::as_ptr(cmp)]).collect()))
+            .collect();
+
+        //With the IRI index gone each component has a single owner and is moved, not cloned
+        let mut cmo = ArcComponentMappedOntology::new_arc();
+        for cmp in std::mem::take(&mut ontology.kinds).into_values().flatten() {
+            cmo.insert(Arc::unwrap_or_clone(cmp));
+        }
+
+        Restore { ontology, cmo, positions }
+    }
+}
+
+impl Drop for Restore<'_> {
+    fn drop(&mut self) {
+        //The index returns the components ordered by kind, then by value, as `iter` does
+        let cmo = std::mem::replace(&mut self.cmo, ArcComponentMappedOntology::new_arc());
+        let components: Vec<Arc<AnnotatedComponent<ArcStr>>> = cmo.index().into_iter().map(Arc::new).collect();
+        for cmp in components.iter() {
+            self.ontology.kinds.entry(cmp.kind()).or_default().insert(cmp.clone());
+        }
+        for (iri, positions) in std::mem::take(&mut self.positions) {
+            let set = positions.into_iter().map(|i| components[i].clone()).collect();
+            self.ontology.iris.insert(iri, set);
+        }
+    }
+}
+
+impl Ontology<ArcStr> for IRIMappedOntology {}
+
+impl MutableOntology<ArcStr> for IRIMappedOntology {
+    fn insert<IAA>(&mut self, cmp: IAA) -> bool
+    where
+        IAA: Into<AnnotatedComponent<ArcStr>>,
+    {
+        let cmp = Arc::new(cmp.into());
+        if !self.kinds.entry(cmp.kind()).or_default().insert(cmp.clone()) {
+            return false;
+        }
+        for iri in mentioned_iris(&cmp) {
+            self.iris.entry(iri).or_default().insert(cmp.clone());
+        }
+        true
+    }
+
+    fn take(&mut self, cmp: &AnnotatedComponent<ArcStr>) -> Option<AnnotatedComponent<ArcStr>> {
+        let kind = self.kinds.get_mut(&cmp.kind())?;
+        let taken = kind.take(cmp)?;
+        if kind.is_empty() {
+            self.kinds.remove(&cmp.kind());
+        }
+        for iri in mentioned_iris(cmp) {
+            if let Some(set) = self.iris.get_mut(&iri) {
+                set.remove(cmp);
+                if set.is_empty() {
+                    self.iris.remove(&iri);
+                }
+            }
+        }
+        Some(Arc::unwrap_or_clone(taken))
+    }
+}
+
+impl From<SetOntology<ArcStr>> for IRIMappedOntology {
+    fn from(so: SetOntology<ArcStr>) -> IRIMappedOntology {
+        let mut imo = IRIMappedOntology::default();
+        for cmp in so {
+            imo.insert(cmp);
+        }
+        imo
+    }
+}
@@ -0,0 +1,351 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+
+use curie::PrefixMapping;
+use oxigraph::model::vocab::{rdf, xsd};
+use oxigraph::model::{BlankNode, Literal, NamedNode, Subject, Term, Triple};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde_json::{json, Map, Value};
+
+/// Writes triples as a flattened JSON-LD document.
+///
+/// The `@context` declares every prefix of `mapping` and is used to compact IRIs. The `@graph`
+/// holds one node object per subject, sorted by `@id`, whose properties are sorted and always
+/// have an array of values. `rdf:type` is written as `@type`.
+pub fn write<W: Write>(triples: Vec<Triple>, mapping: &PrefixMapping, out: W) -> PyResult<()> {
+    let mut prefixes: Vec<(String, String)> = mapping
+        .mappings()
+        .filter(|(prefix, _)| !prefix.is_empty())
+        .map(|(prefix, namespace)| (prefix.clone(), namespace.clone()))
+        .collect();
+    prefixes.sort();
+    let compact = |iri: &str| {
+        prefixes
+            .iter()
+            .filter(|(_, namespace)| iri.starts_with(namespace.as_str()))
+            .max_by_key(|(_, namespace)| namespace.len())
+            .map(|(prefix, namespace)| format!("{}:{}", prefix, &iri[namespace.len()..]))
+            .unwrap_or_else(|| iri.to_string())
+    };
+
+    let mut nodes: BTreeMap<String, BTreeMap<String, Vec<Value>>> = BTreeMap::new();
+    for triple in &triples {
+        let id = match &triple.subject {
+            Subject::NamedNode(node) => compact(node.as_str()),
+            subject => subject.to_string(),
+        };
+        let (key, value) = if triple.predicate == rdf::TYPE {
+            match &triple.object {
+                Term::NamedNode(node) => ("@type".to_string(), Value::String(compact(node.as_str()))),
+                object => ("@type".to_string(), Value::String(object.to_string())),
+            }
+        } else {
+            let value = match &triple.object {
+                Term::NamedNode(node) => json!({ "@id": compact(node.as_str()) }),
+                Term::BlankNode(node) => json!({ "@id": node.to_string() }),
+                Term::Literal(literal) => {
+                    let mut value = Map::new();
+                    value.insert("@value".to_string(), Value::String(literal.value().to_string()));
+                    if let Some(language) = literal.language() {
+                        value.insert("@language".to_string(), Value::String(language.to_string()));
+                    } else if literal.datatype() != xsd::STRING {
+                        value.insert("@type".to_string(), Value::String(compact(literal.datatype().as_str())));
+                    }
+                    Value::Object(value)
+                }
+                #[allow(unreachable_patterns)]
+                object => Value::String(object.to_string()),
+            };
+            (compact(triple.predicate.as_str()), value)
+        };
+        nodes.entry(id).or_default().entry(key).or_default().push(value);
+    }
+
+    let graph: Vec<Value> = nodes
+        .into_iter()
+        .map(|(id, properties)| {
+            let mut node = Map::new();
+            node.insert("@id".to_string(), Value::String(id));
+            for (key, mut values) in properties {
+                values.sort_by_cached_key(|value| value.to_string());
+                node.insert(key, Value::Array(values));
+            }
+            Value::Object(node)
+        })
+        .collect();
+    let context: Map<String, Value> = prefixes
+        .into_iter()
+        .map(|(prefix, namespace)| (prefix, Value::String(namespace)))
+        .collect();
+
+    serde_json::to_writer_pretty(out, &json!({ "@context": context, "@graph": graph })).map_err(std::io::Error::from)?;
+    Ok(())
+}
+
+/// A term definition of a JSON-LD context.
+#[derive(Clone)]
+struct Definition {
+    iri: String,
+    /// The `@type` coercion of the term, such as `@id` or a datatype IRI.
+    coerce: Option<String>,
+    language: Option<String>,
+}
+
+#[derive(Default)]
+struct Reader {
+    terms: HashMap<String, Definition>,
+    vocab: Option<String>,
+    base: Option<String>,
+    triples: Vec<Triple>,
+    blank_nodes: usize,
+}
+
+impl Reader {
+    fn context(&mut self, context: &Value) -> PyResult<()> {
+        match context {
+            Value::Null => {
+                self.terms.clear();
+                self.vocab = None;
+            }
+            Value::Array(contexts) => {
+                for context in contexts {
+                    self.context(context)?;
+                }
+            }
+            Value::Object(definitions) => {
+                for (term, definition) in definitions {
+                    match (term.as_str(), definition) {
+                        ("@vocab", Value::String(vocab)) => self.vocab = Some(self.expand(vocab, true)?),
+                        ("@base", Value::String(base)) => self.base = Some(base.clone()),
+                        (term, _) if term.starts_with('@') => {}
+                        (term, Value::String(iri)) => {
+                            let definition = Definition {
+                                iri: self.expand(iri, true)?,
+                                coerce: None,
+                                language: None,
+                            };
+                            self.terms.insert(term.to_string(), definition);
+                        }
+                        (term, Value::Object(definition)) => {
+                            let iri = match definition.get("@id").and_then(Value::as_str) {
+                                Some(iri) => self.expand(iri, true)?,
+                                None => self.expand(term, true)?,
+                            };
+                            let coerce = match definition.get("@type").and_then(Value::as_str) {
+                                Some(t) if t.starts_with('@') => Some(t.to_string()),
+                                Some(t) => Some(self.expand(t, true)?),
+                                None => None,
+                            };
+                            let language = definition
+                                .get("@language")
+                                .and_then(Value::as_str)
+                                .map(str::to_string);
+                            self.terms.insert(term.to_string(), Definition { iri, coerce, language });
+                        }
+                        (term, _) => {
+                            self.terms.remove(term);
+                        }
+                    }
+                }
+            }
+            Value::String(url) => {
+                return Err(to_py_err(format!("remote context '{}' cannot be loaded", url)))
+            }
+            _ => return Err(to_py_err("invalid @context")),
+        }
+        Ok(())
+    }
+
+    /// Expands a term, compact IRI or relative IRI. `vocab` is true for properties and types.
+    fn expand(&self, value: &str, vocab: bool) -> PyResult<String> {
+        if value.starts_with("_:") {
+            return Ok(value.to_string());
+        }
+        if vocab {
+            if let Some(definition) = self.terms.get(value) {
+                return Ok(definition.iri.clone());
+            }
+        }
+        if let Some((prefix, suffix)) = value.split_once(':') {
+            if suffix.starts_with("//") {
+                return Ok(value.to_string());
+            }
+            if let Some(definition) = self.terms.get(prefix) {
+                return Ok(format!("{}{}", definition.iri, suffix));
+            }
+            return Ok(value.to_string());
+        }
+        match (vocab, &self.vocab, &self.base) {
+            (true, Some(vocab), _) => Ok(format!("{}{}", vocab, value)),
+            (false, _, Some(base)) => Ok(format!("{}{}", base, value)),
+            _ => Err(to_py_err(format!("cannot expand '{}' to an IRI", value))),
+        }
+    }
+
+    fn named_node(&self, value: &str, vocab: bool) -> PyResult<NamedNode> {
+        NamedNode::new(self.expand(value, vocab)?).map_err(to_py_err)
+    }
+
+    fn subject(&mut self, id: Option<&str>) -> PyResult<Subject> {
+        match id {
+            Some(id) if id.starts_with("_:") => Ok(BlankNode::new_unchecked(&id[2..]).into()),
+            Some(id) => Ok(self.named_node(id, false)?.into()),
+            None => {
+                self.blank_nodes += 1;
+                Ok(BlankNode::new_unchecked(format!("jsonld{}", self.blank_nodes)).into())
+            }
+        }
+    }
+
+    /// Reads a node object, returning its subject.
+    fn node(&mut self, node: &Map<String, Value>) -> PyResult<Subject> {
+        if let Some(context) = node.get("@context") {
+            self.context(context)?;
+        }
+        let subject = self.subject(node.get("@id").and_then(Value::as_str))?;
+
+        for (key, value) in node {
+            match key.as_str() {
+                "@type" => {
+                    for t in as_array(value) {
+                        let t = t.as_str().ok_or_else(|| to_py_err("@type must be a string"))?;
+                        let object = self.named_node(t, true)?;
+                        self.triples.push(Triple::new(subject.clone(), rdf::TYPE, object));
+                    }
+                }
+                "@graph" => self.graph(value)?,
+                key if key.starts_with('@') => {}
+                key => {
+                    let definition = self.terms.get(key).cloned();
+                    let predicate = self.named_node(key, true)?;
+                    for value in as_array(value) {
+                        let object = self.value(value, definition.as_ref())?;
+                        self.triples.push(Triple::new(subject.clone(), predicate.clone(), object));
+                    }
+                }
+            }
+        }
+        Ok(subject)
+    }
+
+    /// Reads a property value, returning the object of the triple.
+    fn value(&mut self, value: &Value, definition: Option<&Definition>) -> PyResult<Term> {
+        let coerce = definition.and_then(|d| d.coerce.as_deref());
+        Ok(match value {
+            Value::String(s) => match coerce {
+                Some("@id") => self.subject(Some(s))?.into(),
+                Some("@vocab") => self.named_node(s, true)?.into(),
+                Some(datatype) => Literal::new_typed_literal(s.as_str(), self.named_node(datatype, true)?).into(),
+                None => match definition.and_then(|d| d.language.as_deref()) {
+                    Some(language) => Literal::new_language_tagged_literal(s.as_str(), language)
+                        .map_err(to_py_err)?
+                        .into(),
+                    None => Literal::new_simple_literal(s.as_str()).into(),
+                },
+            },
+            Value::Bool(b) => Literal::new_typed_literal(b.to_string(), xsd::BOOLEAN).into(),
+            Value::Number(n) if n.is_i64() || n.is_u64() => {
+                Literal::new_typed_literal(n.to_string(), xsd::INTEGER).into()
+            }
+            Value::Number(n) => Literal::new_typed_literal(n.to_string(), xsd::DOUBLE).into(),
+            Value::Object(object) => {
+                if let Some(literal) = object.get("@value") {
+                    let lexical = match literal {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    match (object.get("@language"), object.get("@type")) {
+                        (Some(Value::String(language)), _) => {
+                            Literal::new_language_tagged_literal(lexical, language.as_str())
+                                .map_err(to_py_err)?
+                                .into()
+                        }
+                        (_, Some(Value::String(datatype))) => {
+                            Literal::new_typed_literal(lexical, self.named_node(datatype, true)?).into()
+                        }
+                        _ => match literal {
+                            Value::String(_) => Literal::new_simple_literal(lexical).into(),
+                            other => self.value(other, None)?,
+                        },
+                    }
+                } else if let Some(items) = object.get("@list") {
+                    self.list(items, definition)?
+                } else {
+                    self.node(object)?.into()
+                }
+            }
+            _ => return Err(to_py_err(format!("unsupported value {}", value))),
+        })
+    }
+
+    /// Reads a `@list`, returning the head of the RDF collection.
+    fn list(&mut self, items: &Value, definition: Option<&Definition>) -> PyResult<Term> {
+        let mut head: Term = NamedNode::from(rdf::NIL).into();
+        for item in as_array(items).iter().rev() {
+            let object = self.value(item, definition)?;
+            let node = self.subject(None)?;
+            self.triples.push(Triple::new(node.clone(), rdf::FIRST, object));
+            self.triples.push(Triple::new(node.clone(), rdf::REST, head));
+            head = node.into();
+        }
+        Ok(head)
+    }
+
+    fn graph(&mut self, graph: &Value) -> PyResult<()> {
+        for node in as_array(graph) {
+            match node {
+                Value::Object(node) => {
+                    self.node(node)?;
+                }
+                _ => return Err(to_py_err("nodes must be objects")),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn as_array(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    }
+}
+
+/// Reads a JSON-LD document into its triples and the prefixes declared in its top-level
+/// `@context`.
+///
+/// Contexts must be embedded in the document; remote contexts are not loaded.
+pub fn read(text: &str) -> PyResult<(Vec<Triple>, PrefixMapping)> {
+    let document: Value = serde_json::from_str(text).map_err(to_py_err)?;
+    let mut reader = Reader::default();
+    match &document {
+        Value::Object(object) if object.contains_key("@graph") && !object.contains_key("@id") => {
+            if let Some(context) = object.get("@context") {
+                reader.context(context)?;
+            }
+            reader.graph(&object["@graph"])?;
+        }
+        Value::Object(object) => {
+            reader.node(object)?;
+        }
+        Value::Array(_) => reader.graph(&document)?,
+        _ => return Err(to_py_err("expected an object or array")),
+    }
+
+    let mut mapping = PrefixMapping::default();
+    if let Some(Value::Object(context)) = document.get("@context") {
+        for (prefix, namespace) in context {
+            if let (false, Value::String(namespace)) = (prefix.starts_with('@'), namespace) {
+                if namespace.ends_with('/') || namespace.ends_with('#') {
+                    let _ = mapping.add_prefix(prefix, namespace);
+                }
+            }
+        }
+    }
+    Ok((reader.triples, mapping))
+}
+
+fn to_py_err<E: std::fmt::Display>(error: E) -> PyErr {
+    PyValueError::new_err(format!("Invalid JSON-LD: {}", error))
+}
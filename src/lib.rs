@@ -1,47 +1,90 @@
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyUserWarning, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyString, PyTuple};
 use pyo3::wrap_pyfunction;
 use std::fs::File;
 use std::io::BufReader;
-use std::io::Cursor;
+use std::io::{BufWriter, Write};
 
 #[macro_use]
 mod doc;
+mod canonical;
+mod catalog;
+mod dl;
+mod input;
+mod iri_mapped;
+mod jsonld;
+mod manager;
+mod manchester;
 mod model;
+mod obo;
+mod obographs;
+mod pattern;
+mod rdf_syntax;
+mod sparql;
+mod stream;
 
 use horned_owl::model::*;
 //use horned_owl::ontology::axiom_mapped::AxiomMappedOntology;
-use horned_owl::ontology::component_mapped::ComponentMappedOntology;
-use horned_owl::ontology::iri_mapped::IRIMappedOntology;
+use crate::iri_mapped::IRIMappedOntology;
 use horned_owl::vocab::AnnotationBuiltIn;
 //use horned_owl::ontology::declaration_mapped::DeclarationMappedIndex;
 //use horned_owl::ontology::logically_equal::LogicallyEqualIndex;
-use horned_owl::io::rdf::reader::IncompleteParse;
-use horned_owl::io::{ParserConfiguration, RDFParserConfiguration};
+use horned_owl::io::ParserConfiguration;
 //use horned_owl::ontology::indexed::ThreeIndexedOntology;
 //use horned_owl::ontology::set::SetIndex;
-use horned_owl::error::HornedError;
 use horned_owl::io::rdf::reader::RDFOntology;
 //use horned_owl::ontology::axiom_mapped::ArcAxiomMappedOntology;  
-use horned_owl::ontology::component_mapped::ArcComponentMappedOntology;
-use horned_owl::ontology::iri_mapped::ArcIRIMappedOntology;
 use horned_owl::ontology::set::SetOntology;
 
 use curie::{Curie, PrefixMapping};
+use oxigraph::io::RdfFormat;
 
 use std::collections::HashSet;
 use std::collections::{BTreeSet, HashMap};
 use std::default::Default;
 use std::ops::Deref;
 use std::path::Path;
-use std::time::Instant;
 //use failure::Error;
-use std::sync::Arc;
+use std::borrow::Borrow;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema#";
+const RDFS_LITERAL: &str = "http://www.w3.org/2000/01/rdf-schema#Literal";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const IAO_TERM_REPLACED_BY: &str = "http://purl.obolibrary.org/obo/IAO_0100001";
+const IAO_OBSOLESCENCE_REASON: &str = "http://purl.obolibrary.org/obo/IAO_0000231";
+const OBO_IN_OWL_CONSIDER: &str = "http://www.geneontology.org/formats/oboInOwl#consider";
+const OBO_NS: &str = "http://purl.obolibrary.org/obo/";
+const OBO_IN_OWL_NS: &str = "http://www.geneontology.org/formats/oboInOwl#";
+const IAO_DEFINITION: &str = "http://purl.obolibrary.org/obo/IAO_0000115";
+const OBO_IN_OWL_HAS_DBXREF: &str = "http://www.geneontology.org/formats/oboInOwl#hasDbXref";
+const OBO_IN_OWL_HAS_SYNONYM_TYPE: &str = "http://www.geneontology.org/formats/oboInOwl#hasSynonymType";
+const OBO_IN_OWL_IN_SUBSET: &str = "http://www.geneontology.org/formats/oboInOwl#inSubset";
+const OBSOLETE_LABEL_PREFIX: &str = "obsolete ";
+
+/// A `Build` that can be shared by several ontologies, so that they intern their IRIs in one
+/// cache.
+#[derive(Clone, Default)]
+pub struct SharedBuild(Arc<Mutex<Build<ArcStr>>>);
+
+impl SharedBuild {
+    /// Locks the cache for use where a `Build` is needed.
+    pub fn lock(&self) -> MutexGuard<'_, Build<ArcStr>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn iri<S: Borrow<str>>(&self, s: S) -> IRI<ArcStr> {
+        self.lock().iri(s)
+    }
 
+    pub fn annotation_property<S: Borrow<str>>(&self, s: S) -> AnnotationProperty<ArcStr> {
+        self.lock().annotation_property(s)
+    }
+}
 
 /// Represents a loaded ontology.
-#[pyclass]
+#[pyclass(module = "pyhornedowl")]
 struct PyIndexedOntology {
     //State variables private to Rust, exposed through methods to Python
     labels_to_iris: HashMap<String, IRI<ArcStr>>,
@@ -51,10 +94,14 @@ struct PyIndexedOntology {
     classes_to_superclasses: HashMap<IRI<ArcStr>, HashSet<IRI<ArcStr>>>,
 
     //The primary store of the axioms is a Horned OWL indexed ontology
-    ontology: ArcIRIMappedOntology,
+    ontology: IRIMappedOntology,
     //Need this for converting IRIs to IDs and for saving again afterwards
     mapping: PrefixMapping,
-    build: Build<ArcStr>,
+    build: SharedBuild,
+    //Whether writers use the canonical ordering, see set_canonical_ordering
+    canonical: bool,
+    //The RDF triples of the ontology for SPARQL queries, dropped whenever the ontology changes
+    rdf_store: Option<oxigraph::store::Store>,
 }
 
 impl Default for PyIndexedOntology {
@@ -63,9 +110,11 @@ impl Default for PyIndexedOntology {
             labels_to_iris: Default::default(),
             classes_to_subclasses: Default::default(),
             classes_to_superclasses: Default::default(),
-            ontology: ArcIRIMappedOntology::new_arc(),
+            ontology: IRIMappedOntology::default(),
             mapping: Default::default(),
-            build: Build::new_arc(),
+            build: Default::default(),
+            canonical: false,
+            rdf_store: None,
         }
     }
 }
@@ -88,6 +137,12 @@ impl PyIndexedOntology {
 
 #[pymethods]
 impl PyIndexedOntology {
+    /// Creates an empty ontology, which unpickling then fills through `__setstate__`.
+    #[new]
+    fn new() -> Self {
+        PyIndexedOntology::default()
+    }
+
     /// get_id_for_iri(self, iri: str) -> Optional[str]
     ///
     /// Gets the ID of term by it IRI.
@@ -188,14 +243,102 @@ impl PyIndexedOntology {
             .next();
 
         if let Some(old_ax) = old_ax {
-            self.ontology.update_axiom(old_ax, ax1);
-        } else {
-            //If no label already, just add one
-            self.ontology.insert(ax1);
+            self.remove_component(old_ax);
+        }
+        self.add_component(ax1);
+        Ok(())
+    }
+
+    /// set_annotation(self, iri: str, property_iri: str, value: typing.Union[str, model.AnnotationValue], lang: Optional[str], datatype: Optional[str], replace: Optional[bool]) -> None
+    ///
+    /// Sets an annotation of a term by iri.
+    ///
+    /// If `value` is a string, it is annotated as a literal with the language tag `lang` or the
+    /// datatype `datatype`, or as a simple literal if neither is given. Otherwise `value` is used as is,
+    /// e.g. a `model.IRI` for IRI-valued annotations. Unless `replace` is false, all existing
+    /// `AnnotationAssertion`s of `property_iri` on the term are replaced; the axiom annotations of the
+    /// replaced assertions are kept on the new one.
+    fn set_annotation(
+        &mut self,
+        iri: String,
+        property_iri: String,
+        value: &PyAny,
+        lang: Option<String>,
+        datatype: Option<String>,
+        replace: Option<bool>,
+    ) -> PyResult<()> {
+        let subject = self.build.iri(iri);
+        let av = self.annotation_value(value, lang, datatype)?;
+
+        let mut ann = BTreeSet::new();
+        if replace.unwrap_or(true) {
+            for old_ax in self.annotation_assertions(&subject, &property_iri).iter() {
+                self.remove_component(old_ax);
+                ann.extend(old_ax.ann.iter().cloned());
+            }
         }
+
+        let component = Component::AnnotationAssertion(AnnotationAssertion {
+            subject: subject.into(),
+            ann: Annotation {
+                ap: self.build.annotation_property(property_iri),
+                av,
+            },
+        });
+        self.add_component(AnnotatedComponent { component, ann });
         Ok(())
     }
 
+    /// add_annotation(self, iri: str, property_iri: str, value: typing.Union[str, model.AnnotationValue], lang: Optional[str], datatype: Optional[str]) -> None
+    ///
+    /// Adds an annotation to a term by iri, keeping existing annotations of the same property.
+    ///
+    /// `value`, `lang` and `datatype` are interpreted as in `PyIndexedOntology.set_annotation`.
+    fn add_annotation(
+        &mut self,
+        iri: String,
+        property_iri: String,
+        value: &PyAny,
+        lang: Option<String>,
+        datatype: Option<String>,
+    ) -> PyResult<()> {
+        self.set_annotation(iri, property_iri, value, lang, datatype, Some(false))
+    }
+
+    /// remove_annotations(self, iri: str, property_iri: str, value: Optional[typing.Union[str, model.AnnotationValue]]) -> int
+    ///
+    /// Removes the annotations of a term by iri for an annotation property.
+    ///
+    /// If `value` is given, only annotations with this value are removed. A string matches literals
+    /// with the same lexical value regardless of their language or datatype.
+    ///
+    /// Returns the number of removed annotations.
+    fn remove_annotations(&mut self, iri: String, property_iri: String, value: Option<&PyAny>) -> PyResult<usize> {
+        let subject = self.build.iri(iri);
+
+        let value = value.map(AnnotationValueFilter::extract).transpose()?;
+
+        let to_remove: Vec<AnnotatedComponent<ArcStr>> = self
+            .annotation_assertions(&subject, &property_iri)
+            .into_iter()
+            .filter(|aax| match (&aax.component, &value) {
+                (_, None) => true,
+                (Component::AnnotationAssertion(AnnotationAssertion { ann, .. }), Some(value)) => {
+                    value.matches(&ann.av)
+                }
+                _ => false,
+            })
+            .collect();
+
+        let mut removed = 0;
+        for aax in to_remove.iter() {
+            if self.remove_component(aax) {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
     /// get_iri_for_label(self, label: str) -> Optional[str]
     ///
     /// Returns the IRI of a term by its label if it exists.
@@ -252,6 +395,98 @@ impl PyIndexedOntology {
 	}
     }
 
+    /// set_iri(self, iri: Optional[str]) -> None
+    ///
+    /// Sets the ontology iri, keeping the version iri.
+    ///
+    /// If `iri` is `None`, the ontology iri is removed.
+    fn set_iri(&mut self, iri: Option<String>) -> PyResult<()> {
+        let viri = self.get_ontology_id().and_then(|id| id.viri.clone());
+        let iri = iri.map(|iri| self.build.iri(iri));
+        self.replace_ontology_id(OntologyID { iri, viri });
+        Ok(())
+    }
+
+    /// set_version_iri(self, iri: Optional[str]) -> None
+    ///
+    /// Sets the ontologys version iri, keeping the ontology iri.
+    ///
+    /// If `iri` is `None`, the version iri is removed.
+    fn set_version_iri(&mut self, iri: Option<String>) -> PyResult<()> {
+        let ontology_iri = self.get_ontology_id().and_then(|id| id.iri.clone());
+        let viri = iri.map(|iri| self.build.iri(iri));
+        self.replace_ontology_id(OntologyID {
+            iri: ontology_iri,
+            viri,
+        });
+        Ok(())
+    }
+
+    /// get_ontology_annotations(self) -> List[model.Annotation]
+    ///
+    /// Returns the annotations of the ontology itself.
+    fn get_ontology_annotations(&mut self) -> PyResult<Vec<model::Annotation>> {
+        let annotations = self
+            .ontology
+            .component_for_kind(ComponentKind::OntologyAnnotation)
+            .filter_map(|aax| match &aax.component {
+                Component::OntologyAnnotation(OntologyAnnotation(ann)) => Some(ann.into()),
+                _ => None,
+            })
+            .collect();
+        Ok(annotations)
+    }
+
+    /// add_ontology_annotation(self, ann: model.Annotation) -> None
+    ///
+    /// Adds an annotation to the ontology itself.
+    fn add_ontology_annotation(&mut self, ann: model::Annotation) -> PyResult<()> {
+        self.add_component(Component::OntologyAnnotation(OntologyAnnotation(ann.into())).into());
+        Ok(())
+    }
+
+    /// remove_ontology_annotation(self, ann: model.Annotation) -> None
+    ///
+    /// Removes an annotation of the ontology itself.
+    ///
+    /// Raises a `ValueError` if the ontology does not have this annotation.
+    fn remove_ontology_annotation(&mut self, ann: model::Annotation) -> PyResult<()> {
+        let component = Component::OntologyAnnotation(OntologyAnnotation(ann.into()));
+        if self.remove_components_of_kind(ComponentKind::OntologyAnnotation, &component) == 0 {
+            return Err(PyValueError::new_err("The ontology does not have this annotation."));
+        }
+        Ok(())
+    }
+
+    /// get_imports(self) -> List[str]
+    ///
+    /// Returns the IRIs of all ontologies imported by this ontology.
+    fn get_imports(&mut self) -> PyResult<Vec<String>> {
+        Ok(self.import_iris())
+    }
+
+    /// add_import(self, iri: str) -> None
+    ///
+    /// Adds an import of the ontology with the given iri.
+    fn add_import(&mut self, iri: String) -> PyResult<()> {
+        let iri = self.build.iri(iri);
+        self.add_component(Component::Import(Import(iri)).into());
+        Ok(())
+    }
+
+    /// remove_import(self, iri: str) -> None
+    ///
+    /// Removes the import of the ontology with the given iri.
+    ///
+    /// Raises a `ValueError` if the ontology does not import `iri`.
+    fn remove_import(&mut self, iri: String) -> PyResult<()> {
+        let component = Component::Import(Import(self.build.iri(iri)));
+        if self.remove_components_of_kind(ComponentKind::Import, &component) == 0 {
+            return Err(PyValueError::new_err("The ontology does not have this import."));
+        }
+        Ok(())
+    }
+
     /// get_subclasses(self, iri: str) -> Set[str]
     ///
     /// Gets all subclasses of an entity.
@@ -404,90 +639,285 @@ impl PyIndexedOntology {
         Ok(literal_values)
     }
 
-    /// save_to_file(self, file_name: str) -> None
+    /// get_annotation_values(self, class_iri: str, ann_iri: str, native: Optional[bool]) -> List[typing.Any]
+    ///
+    /// Gets all annotated values for an entity and annotation property with their types.
+    ///
+    /// By default the values are returned as `model.AnnotationValue`, keeping language tags, datatypes
+    /// and IRI- or anonymous-individual-valued annotations. With `native=True` literals are converted to
+    /// Python values instead: `xsd:integer` and its subtypes to `int`, `xsd:boolean` to `bool`,
+    /// `xsd:decimal` to `decimal.Decimal`, `xsd:double` and `xsd:float` to `float`, `xsd:dateTime` to
+    /// `datetime.datetime` and `xsd:date` to `datetime.date`, dropping its timezone. Other literals,
+    /// and literals whose lexical form is not valid for their datatype, become `str` unchanged.
     ///
-    /// Saves the ontology to disk in owx format.
-    fn save_to_file(&mut self, file_name: String) -> PyResult<()> {
-        let before = Instant::now();
+    /// Note: The order is neither necessarily the same as in the ontology neither is it stable.
+    fn get_annotation_values(
+        &mut self,
+        py: Python,
+        class_iri: String,
+        ann_iri: String,
+        native: Option<bool>,
+    ) -> PyResult<Vec<PyObject>> {
+        let subject = self.build.iri(class_iri);
+        let native = native.unwrap_or(false);
 
-        let mut file = File::create(file_name)?;
-        //let mut amo: ArcAxiomMappedOntology = AxiomMappedOntology::new_arc();
-	let mut amo: ArcComponentMappedOntology = ComponentMappedOntology::new_arc();
-        //amo.mut_id().iri = oid.iri.clone();
-        //amo.mut_id().viri = oid.viri.clone();
-        //Copy the axioms into an AxiomMappedOntology as that is what horned owl writes
-        for aax in self.ontology.iter() {
-            amo.insert(aax.clone());
-        }
-        let time_middle = before.elapsed().as_secs();
-        println!(
-            "Finished preparing ontology for saving in {:?} seconds.",
-            time_middle
-        );
-        let before = Instant::now();
+        self.annotation_assertions(&subject, &ann_iri)
+            .iter()
+            .filter_map(|aax| match &aax.component {
+                Component::AnnotationAssertion(AnnotationAssertion { ann, .. }) => Some(&ann.av),
+                _ => None,
+            })
+            .map(|av| match av {
+                AnnotationValue::Literal(literal) if native => literal_to_py(py, literal),
+                _ => Ok(model::AnnotationValue::from(av).into_py(py)),
+            })
+            .collect()
+    }
+
+    /// get_axiom_annotations(self, ax: model.Component) -> List[model.Annotation]
+    ///
+    /// Gets the annotations on an axiom of the ontology.
+    ///
+    /// Raises a `ValueError` if the ontology does not contain the axiom.
+    fn get_axiom_annotations(&mut self, ax: model::Component) -> PyResult<Vec<model::Annotation>> {
+        let annotated = self.annotated_components(&ax.into())?;
+        let ann: BTreeSet<&Annotation<ArcStr>> = annotated.iter().flat_map(|aax| aax.ann.iter()).collect();
+        Ok(ann.into_iter().map(model::Annotation::from).collect())
+    }
 
-        let result = horned_owl::io::owx::writer::write(&mut file, &amo, Some(&self.mapping));
+    /// add_axiom_annotation(self, ax: model.Component, ann: model.Annotation) -> None
+    ///
+    /// Adds an annotation to an axiom of the ontology, keeping its other annotations.
+    ///
+    /// Raises a `ValueError` if the ontology does not contain the axiom.
+    fn add_axiom_annotation(&mut self, ax: model::Component, ann: model::Annotation) -> PyResult<()> {
+        let component: Component<ArcStr> = ax.into();
+        let mut annotations = self.take_annotated_components(&component)?;
+        annotations.insert(ann.into());
+        self.add_component(AnnotatedComponent {
+            component,
+            ann: annotations,
+        });
+        Ok(())
+    }
+
+    /// remove_axiom_annotation(self, ax: model.Component, ann: model.Annotation) -> None
+    ///
+    /// Removes an annotation from an axiom of the ontology, keeping the axiom itself.
+    ///
+    /// Raises a `ValueError` if the ontology does not contain the axiom.
+    fn remove_axiom_annotation(&mut self, ax: model::Component, ann: model::Annotation) -> PyResult<()> {
+        let component: Component<ArcStr> = ax.into();
+        let ann: Annotation<ArcStr> = ann.into();
+        let mut annotations = self.take_annotated_components(&component)?;
+        annotations.remove(&ann);
+        self.add_component(AnnotatedComponent {
+            component,
+            ann: annotations,
+        });
+        Ok(())
+    }
+
+    /// get_axioms_with_annotation(self, ann_iri: str, value: Optional[typing.Union[str, model.AnnotationValue]]) -> List[model.AnnotatedComponent]
+    ///
+    /// Returns all axioms annotated with the annotation property `ann_iri`.
+    ///
+    /// If `value` is given, only axioms with an annotation of this value are returned. A string matches
+    /// literals with the same lexical value regardless of their language or datatype.
+    fn get_axioms_with_annotation(
+        &mut self,
+        py: Python,
+        ann_iri: String,
+        value: Option<&PyAny>,
+    ) -> PyResult<Vec<PyObject>> {
+        let value = value.map(AnnotationValueFilter::extract).transpose()?;
+
+        let axioms = self
+            .ontology
+            .iter()
+            .filter(|aax| {
+                aax.ann.iter().any(|ann| {
+                    ann_iri.eq(&ann.ap.0.to_string())
+                        && value.as_ref().is_none_or(|v| v.matches(&ann.av))
+                })
+            })
+            .map(|aax| model::AnnotatedComponent::from(aax).into_py(py))
+            .collect();
 
-        let time_after = before.elapsed().as_secs();
-        println!(
-            "Finished saving ontology to file in  {:?} seconds.",
-            time_after
-        );
+        Ok(axioms)
+    }
 
-        match result {
-            Ok(()) => Ok(()),
-            Err(error) => panic!("Problem saving the ontology to a file: {:?}", error),
+    /// save_to_file(self, file_name: str, serialization: typing.Optional[str] = None, progress: typing.Optional[typing.Callable[[int], None]] = None) -> None
+    ///
+    /// Saves the ontology to disk.
+    ///
+    /// `serialization` is either "owx", "rdfxml", "turtle", "ntriples", "jsonld", "obo" (OBO 1.4
+    /// flat file) or "obographs" (OBO Graphs JSON). If it is not given, it is guessed from the
+    /// extension of `file_name` as for `open_ontology_from_file`, with `.json` written as OBO Graphs,
+    /// defaulting to owx.
+    ///
+    /// OWX, RDF/XML and OBO are written to the file as they are produced, OWX and RDF/XML without
+    /// copying the ontology. The other serializations, and RDF/XML with canonical ordering, are
+    /// first built in memory: Turtle, N-Triples and JSON-LD from all triples of the ontology, which
+    /// are obtained by writing RDF/XML and parsing it again. If `progress` is given, it is called
+    /// with the number of bytes written so far after every MiB and when the file is complete.
+    fn save_to_file(
+        &mut self,
+        file_name: String,
+        serialization: Option<String>,
+        progress: Option<PyObject>,
+    ) -> PyResult<()> {
+        let serialization = serialization
+            .as_deref()
+            .unwrap_or_else(|| input::format_for_path(&file_name).unwrap_or("owx"));
+        if !SERIALIZATIONS.contains(&serialization) {
+            return Err(PyValueError::new_err(format!(
+                "Unknown serialization '{}'.",
+                serialization
+            )));
         }
+
+        let file = BufWriter::new(File::create(file_name)?);
+        let mut out = stream::Progress::new(file, progress);
+        self.write_serialization(&mut out, serialization)?;
+        out.flush()?;
+        Ok(())
+    }
+
+    /// set_canonical_ordering(self, canonical: bool) -> None
+    ///
+    /// Sets whether the ontology is written in a canonical order, so that writing the same
+    /// components always produces the same output.
+    ///
+    /// Components are sorted by kind, then by the IRI of the entity they are about, then
+    /// structurally. RDF output is sorted by subject, predicate and object, and blank nodes are
+    /// labelled from the triples around them instead of the order in which they were written.
+    /// OWX output is always grouped by kind and sorted structurally by horned-owl.
+    fn set_canonical_ordering(&mut self, canonical: bool) {
+        self.canonical = canonical;
+        self.rdf_store = None;
     }
 
     /// write_to_rdf_string(self) -> None
     ///
     /// Writes the ontology to a string in rdf format.
     fn write_to_rdf_string(&mut self) -> PyResult<String> {
-        let mut buf = Cursor::new(Vec::new());
-        //let mut amo: ArcAxiomMappedOntology = AxiomMappedOntology::new_arc();
-	let mut amo: ArcComponentMappedOntology = ComponentMappedOntology::new_arc();
-        //let oid = &self.ontology.id().clone();
-
-        //amo.mut_id().iri = oid.iri.clone();
-        //amo.mut_id().viri = oid.viri.clone();
-        //Copy the axioms into an AxiomMappedOntology
-        for aax in self.ontology.iter() {
-            amo.insert(aax.clone());
-        }
-        let _ = horned_owl::io::rdf::writer::write(&mut buf, &amo).unwrap();
+        self.write_to_string("rdfxml")
+    }
 
-        let bytes = buf.into_inner();
-        let result = String::from_utf8(bytes);
+    /// write_to_turtle_string(self) -> str
+    ///
+    /// Writes the ontology to a string in Turtle format.
+    ///
+    /// The prefixes of the ontology are declared and used to abbreviate IRIs. Triples are sorted by
+    /// subject, predicate and object, so the same ontology is always written the same way.
+    fn write_to_turtle_string(&mut self) -> PyResult<String> {
+        self.write_to_string("turtle")
+    }
 
-        match result {
-            Ok(string) => Ok(string),
-            Err(error) => panic!("Problem writing the ontology to a string: {:?}", error),
-        }
+    /// write_to_ntriples_string(self) -> str
+    ///
+    /// Writes the ontology to a string in N-Triples format, one sorted triple per line.
+    fn write_to_ntriples_string(&mut self) -> PyResult<String> {
+        self.write_to_string("ntriples")
     }
 
-    /// write_to_owx_string(self) -> None
+    /// write_to_jsonld_string(self) -> str
     ///
-    /// Writes the ontology to a string in rdf format.
-    fn write_to_owx_string(&mut self) -> PyResult<String> {
-        let mut buf = Cursor::new(Vec::new());
-        //let mut amo: ArcAxiomMappedOntology = AxiomMappedOntology::new_arc();
-	let mut amo: ArcComponentMappedOntology = ComponentMappedOntology::new_arc();
-        //let oid = &self.ontology.id().clone();
+    /// Writes the RDF mapping of the ontology as a flattened JSON-LD document.
+    ///
+    /// The `@context` declares the prefixes of the ontology, which are used to compact IRIs. Each
+    /// subject becomes a node object in `@graph`, with nodes and their values sorted.
+    fn write_to_jsonld_string(&mut self) -> PyResult<String> {
+        self.write_to_string("jsonld")
+    }
 
-        //Copy the axioms into an AxiomMappedOntology
-        for aax in self.ontology.iter() {
-            amo.insert(aax.clone());
-        }
-        let _ = horned_owl::io::owx::writer::write(&mut buf, &amo, Some(&self.mapping)).unwrap();
+    /// write_to_obographs_string(self) -> str
+    ///
+    /// Writes the ontology as OBO Graphs JSON.
+    ///
+    /// Declared entities become nodes carrying their label, definition, synonyms, xrefs, comments,
+    /// subsets, deprecation and other annotations as metadata. `SubClassOf` axioms between named
+    /// classes become `is_a` edges and existential restrictions on named classes edges labelled
+    /// with the property. Equivalences to intersections of named classes and existential
+    /// restrictions become logical definitions. Other axioms are not represented.
+    fn write_to_obographs_string(&mut self) -> PyResult<String> {
+        self.write_to_string("obographs")
+    }
 
-        let bytes = buf.into_inner();
-        let result = String::from_utf8(bytes);
+    /// write_to_obo_string(self) -> str
+    ///
+    /// Writes the ontology as an OBO 1.4 flat file.
+    ///
+    /// Classes, object properties and named individuals become `[Term]`, `[Typedef]` and
+    /// `[Instance]` stanzas following the OBO to OWL mapping. Labels, definitions, synonyms, xrefs,
+    /// subsets and obsolescence are written as their tags, `SubClassOf` axioms as `is_a` or
+    /// `relationship` and equivalences to intersections as `intersection_of`. Axioms without an
+    /// OBO representation, such as nested class expressions, are not written.
+    fn write_to_obo_string(&mut self) -> PyResult<String> {
+        self.write_to_string("obo")
+    }
 
-        match result {
-            Ok(string) => Ok(string),
-            Err(error) => panic!("Problem writing the ontology to a string: {:?}", error),
+    /// sparql_query(self, query: str) -> typing.Union[List[Dict[str, typing.Any]], bool, List[Tuple[str, str, typing.Any]]]
+    ///
+    /// Evaluates a SPARQL 1.1 query over the RDF triples of the ontology.
+    ///
+    /// The triples are those written by `PyIndexedOntology.write_to_rdf_string`. SELECT queries
+    /// return one dict per solution mapping variable names to values, with `None` for unbound
+    /// variables. ASK queries return a bool, CONSTRUCT and DESCRIBE queries a list of
+    /// `(subject, predicate, object)` tuples. IRIs are returned as strings, blank nodes as `_:id`
+    /// and literals as native Python values where the datatype is known. The triples are kept
+    /// between queries until the ontology changes.
+    fn sparql_query(&mut self, py: Python, query: String) -> PyResult<PyObject> {
+        if self.rdf_store.is_none() {
+            self.rdf_store = Some(sparql::store(&self.rdf_triples()?)?);
         }
+        let store = self.rdf_store.as_ref().unwrap();
+        sparql::query(py, store, &query, &self.build.lock())
+    }
+
+    /// clone(self) -> PyIndexedOntology
+    ///
+    /// Returns an independent copy of the ontology including its prefix mapping.
+    fn clone(&self) -> PyIndexedOntology {
+        self.duplicate()
+    }
+
+    fn __copy__(&self) -> PyIndexedOntology {
+        self.duplicate()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyAny) -> PyIndexedOntology {
+        self.duplicate()
+    }
+
+    /// Returns the ontology serialised as OWX and its canonical ordering flag, which are used as
+    /// the pickle payload.
+    fn __getstate__(&mut self) -> PyResult<(String, bool)> {
+        Ok((self.write_to_owx_string()?, self.canonical))
+    }
+
+    fn __setstate__(&mut self, state: (String, bool)) -> PyResult<()> {
+        let (owx, canonical) = state;
+        let (o, m) = horned_owl::io::owx::reader::read_with_build(&mut owx.as_bytes(), &Build::new_arc())
+            .map_err(|e| PyValueError::new_err(format!("Unable to unpickle ontology: {:?}", e)))?;
+        let mut lo = PyIndexedOntology::from(IRIMappedOntology::from(o));
+        lo.mapping = m;
+        lo.canonical = canonical;
+        *self = lo;
+        Ok(())
+    }
+
+    fn __reduce__(&mut self, py: Python) -> PyResult<(PyObject, Py<PyTuple>, (String, bool))> {
+        let cls = py.get_type_bound::<PyIndexedOntology>().into_py(py);
+        Ok((cls, PyTuple::empty_bound(py).unbind(), self.__getstate__()?))
+    }
+
+    /// write_to_owx_string(self) -> None
+    ///
+    /// Writes the ontology to a string in rdf format.
+    fn write_to_owx_string(&mut self) -> PyResult<String> {
+        self.write_to_string("owx")
     }
 
     /// get_axioms_for_iri(self, iri: str) -> List[model.AnnotatedComponent]
@@ -521,6 +951,217 @@ impl PyIndexedOntology {
         Ok(r)
     }
 
+    /// get_components(self, kind: typing.Union[model.ComponentKind, List[model.ComponentKind]], iri: Optional[str]) -> List[model.AnnotatedComponent]
+    ///
+    /// Returns all components of one or several kinds.
+    ///
+    /// If `iri` is given, only components that reference this IRI are returned. This is much faster
+    /// than filtering the result of `PyIndexedOntology.get_axioms`, as the components are looked up
+    /// in the index of the ontology.
+    fn get_components(&mut self, py: Python, kind: &PyAny, iri: Option<String>) -> PyResult<Vec<PyObject>> {
+        let requested: Vec<model::ComponentKind> = match kind.extract::<model::ComponentKind>() {
+            Ok(kind) => vec![kind],
+            Err(_) => kind.extract()?,
+        };
+        let mut kinds: Vec<ComponentKind> = Vec::new();
+        for kind in requested.into_iter().map(ComponentKind::from) {
+            if !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+
+        let components = match iri {
+            Some(iri) => {
+                let iri = self.build.iri(iri);
+                self.ontology
+                    .components_for_iri(&iri)
+                    .filter(|aax| kinds.contains(&aax.kind()))
+                    .map(|aax| model::AnnotatedComponent::from(aax).into_py(py))
+                    .collect()
+            }
+            None => {
+                let mut components = Vec::new();
+                for kind in kinds {
+                    components.extend(
+                        self.ontology
+                            .component_for_kind(kind)
+                            .map(|aax| model::AnnotatedComponent::from(aax).into_py(py)),
+                    );
+                }
+                components
+            }
+        };
+
+        Ok(components)
+    }
+
+    /// parse_class_expression(self, text: str) -> model.ClassExpression
+    ///
+    /// Parses a class expression in Manchester syntax.
+    ///
+    /// Names are resolved as labels (quoted with `'` if they contain spaces), CURIEs using the
+    /// prefix mapping of the ontology or full IRIs in angle brackets.
+    fn parse_class_expression(&mut self, text: String) -> PyResult<model::ClassExpression> {
+        let ce = manchester::parse_class_expression(&text, &self.manchester_resolver())?;
+        Ok(ce.into())
+    }
+
+    /// parse_component(self, text: str) -> model.Component
+    ///
+    /// Parses an axiom or declaration in Manchester syntax.
+    ///
+    /// Axioms are written like a single line of a Manchester syntax frame, e.g.
+    /// `'carbon atom' SubClassOf: atom`, `part_of Characteristics: Transitive`, `i Facts: p j` or
+    /// `A Annotations: rdfs:label "a"`, and declarations like `Class: ex:A`. Whether the subject
+    /// is an object, data or annotation property is taken from the declarations in the ontology.
+    fn parse_component(&mut self, text: String) -> PyResult<model::Component> {
+        let component = manchester::parse_component(&text, &self.manchester_resolver())?;
+        Ok(component.into())
+    }
+
+    /// render_manchester(self, obj: typing.Union[model.Component, model.AnnotatedComponent, model.ClassExpression, model.ObjectPropertyExpression, model.DataRange, model.Individual, model.Literal, model.IRI]) -> str
+    ///
+    /// Renders a model object in Manchester syntax.
+    ///
+    /// Entities are written by their label where they have one, the smallest if they have several,
+    /// otherwise as CURIE or full IRI.
+    /// Components are rendered in the form accepted by `PyIndexedOntology.parse_component`.
+    fn render_manchester(&mut self, obj: &PyAny) -> PyResult<String> {
+        let renderer = self.manchester_renderer();
+
+        if let Ok(aax) = obj.extract::<model::AnnotatedComponent>() {
+            return renderer.component(&aax.component.into());
+        }
+        if let Ok(component) = obj.extract::<model::Component>() {
+            return renderer.component(&component.into());
+        }
+        if let Ok(ce) = obj.extract::<model::ClassExpression>() {
+            return Ok(renderer.class_expression(&ce.into()));
+        }
+        if let Ok(ope) = obj.extract::<model::ObjectPropertyExpression>() {
+            return Ok(renderer.object_property_expression(&ope.into()));
+        }
+        if let Ok(dr) = obj.extract::<model::DataRange>() {
+            return Ok(renderer.data_range(&dr.into()));
+        }
+        if let Ok(individual) = obj.extract::<model::Individual>() {
+            return Ok(renderer.individual(&individual.into()));
+        }
+        if let Ok(literal) = obj.extract::<model::Literal>() {
+            return Ok(renderer.literal(&literal.into()));
+        }
+        if let Ok(iri) = obj.extract::<model::IRI>() {
+            return Ok(renderer.iri(&iri.into()));
+        }
+
+        Err(PyValueError::new_err(format!(
+            "Cannot render {} in Manchester syntax.",
+            obj.get_type().name()?
+        )))
+    }
+
+    /// render_functional(self, obj: typing.Any) -> str
+    ///
+    /// Renders a model object in OWL Functional Syntax, abbreviating IRIs with the prefix mapping
+    /// of the ontology.
+    fn render_functional(&mut self, py: Python, obj: &PyAny) -> PyResult<String> {
+        let prefixes: HashMap<String, String> = self
+            .mapping
+            .mappings()
+            .map(|(prefix, iri)| (prefix.to_string(), iri.to_string()))
+            .collect();
+
+        if let Ok(iri) = obj.extract::<model::IRI>() {
+            let iri = horned_owl::model::IRI::<ArcStr>::from(iri).to_string();
+            return Ok(match self.mapping.shrink_iri(&iri) {
+                Ok(curie) => curie.to_string(),
+                Err(_) => format!("<{}>", iri),
+            });
+        }
+
+        obj.call_method1("to_functional", (prefixes.into_py(py),))?
+            .extract()
+    }
+
+    /// dl_query(self, expression: str, kind: Optional[str]) -> Set[str]
+    ///
+    /// Evaluates a DL query given as a Manchester syntax class expression.
+    ///
+    /// Names in the expression are resolved as labels (quoted with `'` if they contain spaces),
+    /// CURIEs using the prefix mapping of the ontology or full IRIs in angle brackets. `kind` is one
+    /// of "subclasses" (the default), "superclasses", "equivalents" or "instances" and the IRIs of
+    /// the matching named classes or individuals are returned.
+    ///
+    /// The query is answered from the asserted subclass, equivalence, class assertion and object
+    /// property assertion axioms without a reasoner, following named superclasses, intersections,
+    /// unions and existential or universal restrictions.
+    fn dl_query(&mut self, expression: String, kind: Option<String>) -> PyResult<HashSet<String>> {
+        let ce = manchester::parse_class_expression(&expression, &self.manchester_resolver())?;
+
+        let result = match kind.as_deref().unwrap_or("subclasses") {
+            "subclasses" => self.query_subclasses(&ce),
+            "superclasses" => self.query_superclasses(&ce),
+            "equivalents" => self.query_equivalents(&ce),
+            "instances" => self.query_instances(&ce),
+            kind => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid query kind '{}'. Expected 'subclasses', 'superclasses', 'equivalents' or 'instances'.",
+                    kind
+                )))
+            }
+        };
+
+        Ok(result.into_iter().map(|iri| iri.to_string()).collect())
+    }
+
+    /// match_pattern(self, pattern: model.Component) -> List[Tuple[model.AnnotatedComponent, Dict[str, typing.Any]]]
+    ///
+    /// Returns all components matching a structural pattern together with the bindings of its variables.
+    ///
+    /// The pattern is a component in which IRIs starting with `?` are variables, e.g.
+    /// `SubClassOf(ObjectSomeValuesFrom(part_of, Class(IRI.parse("?y"))), Class(IRI.parse("?x")))`.
+    /// A variable used as a class, object property, datatype, individual or IRI annotation value
+    /// matches any expression in that position, and a simple literal `"?name"` matches any literal.
+    /// Variables occurring several times must bind to the same value, and the anonymous variable `?`
+    /// matches anything without being bound. The operands of n-ary constructs such as
+    /// `EquivalentClasses` or `ObjectIntersectionOf` are matched in any order, property chains element
+    /// by element and SWRL rules only literally.
+    ///
+    /// The bindings map each variable name without `?` to the matched model object. Candidates are
+    /// looked up in the index of the ontology using the IRIs of the pattern that are not variables.
+    fn match_pattern(
+        &mut self,
+        py: Python,
+        pattern: model::Component,
+    ) -> PyResult<Vec<(PyObject, HashMap<String, PyObject>)>> {
+        let pattern: Component<ArcStr> = pattern.into();
+        let kind = pattern.kind();
+        let anchor = pattern::constant_iris(&pattern)
+            .into_iter()
+            .min_by_key(|iri| self.ontology.components_for_iri(iri).count());
+
+        let matches = match anchor {
+            Some(iri) => pattern::find_matches(
+                &pattern,
+                self.ontology
+                    .components_for_iri(&iri)
+                    .filter(|aax| aax.kind() == kind),
+            ),
+            None => pattern::find_matches(&pattern, self.ontology.component_for_kind(kind)),
+        };
+
+        Ok(matches
+            .into_iter()
+            .map(|(aax, bindings)| {
+                let bindings = bindings
+                    .into_iter()
+                    .map(|(name, value)| (name, value.into_py(py)))
+                    .collect();
+                (model::AnnotatedComponent::from(aax).into_py(py), bindings)
+            })
+            .collect())
+    }
+
     /// add_axiom(self, ax: model.Component, annotations: Optional[List[model.Annotation]]) -> None
     ///
     /// Adds an axiom to the ontology with optional annotations.
@@ -535,6 +1176,7 @@ impl PyIndexedOntology {
             ann,
         };
         self.ontology.insert(annotated_axiom);
+        self.rdf_store = None;
 
         Ok(())
     }
@@ -551,39 +1193,257 @@ impl PyIndexedOntology {
             .ok_or(PyValueError::new_err("args"))?
             .to_owned();
         self.ontology.remove(&annotated);
+        self.rdf_store = None;
 
         Ok(())
     }
 
     fn remove(&mut self, ac: model::AnnotatedComponent) -> PyResult<()> {
         self.ontology.remove(&ac.into());
+        self.rdf_store = None;
         Ok(())
     }
 
-    /// iri(self, iri: str) -> model.IRI
+    /// remove_entity(self, iri: str, mode: Optional[str]) -> int
     ///
-    /// Creates an new IRI from string.
+    /// Removes an entity together with the axioms that reference it.
     ///
-    /// Use this method instead of  `model.IRI.parse` if possible as it is more optimized using caches.
-    fn iri(&self, iri: String) -> model::IRI {
-        model::IRI::new(iri, &self.build)
-    }
-}
+    /// With `mode="all"` (the default) every component mentioning `iri` is removed, including
+    /// its declarations and annotation assertions. With `mode="subject"` only the declarations,
+    /// annotation assertions and axioms about the entity are removed, while axioms that merely
+    /// refer to it (e.g. as a superclass or filler) are kept. `mode="rewire"` removes the same
+    /// components as `"all"`, but first asserts every direct subclass of the removed class as a
+    /// subclass of each of its direct superclasses.
+    ///
+    /// Returns the number of removed components.
+    fn remove_entity(&mut self, iri: String, mode: Option<String>) -> PyResult<usize> {
+        let iri = self.build.iri(iri);
+        let mode = mode.unwrap_or_else(|| "all".to_string());
 
-impl PyIndexedOntology {
-    fn insert(&mut self, ax: &AnnotatedComponent<ArcStr>) -> () {
-        let b = Build::new();
+        let referencing: Vec<AnnotatedComponent<ArcStr>> = self
+            .ontology
+            .components_for_iri(&iri)
+            .cloned()
+            .collect();
 
-        match ax.kind() {
-            ComponentKind::AnnotationAssertion => match ax.clone().component {
-                Component::AnnotationAssertion(AnnotationAssertion { subject, ann }) => match ann {
-                    Annotation {
-                        ap,
-                        av: AnnotationValue::Literal(Literal::Simple { literal }),
-                    } => {
-                        if AnnotationBuiltIn::Label.as_ref().eq(&ap.0.to_string()) {
-                            let _ = &self
-                                .labels_to_iris
+        let to_remove: Vec<AnnotatedComponent<ArcStr>> = match mode.as_str() {
+            "all" => referencing,
+            "subject" => referencing
+                .into_iter()
+                .filter(|aax| is_subject_of(&aax.component, &iri))
+                .collect(),
+            "rewire" => {
+                let parents = self.classes_to_superclasses.get(&iri).cloned().unwrap_or_default();
+                let children = self.classes_to_subclasses.get(&iri).cloned().unwrap_or_default();
+
+                for child in children.iter().filter(|c| **c != iri) {
+                    for parent in parents.iter().filter(|p| **p != iri) {
+                        self.add_component(
+                            Component::SubClassOf(SubClassOf {
+                                sup: ClassExpression::Class(Class(parent.clone())),
+                                sub: ClassExpression::Class(Class(child.clone())),
+                            })
+                            .into(),
+                        );
+                    }
+                }
+                referencing
+            }
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown removal mode '{}'. Expected 'all', 'subject' or 'rewire'.",
+                    mode
+                )))
+            }
+        };
+
+        let mut removed = 0;
+        for aax in to_remove.iter() {
+            if self.remove_component(aax) {
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// obsolete_entity(self, iri: str, replaced_by: Optional[str], consider: Optional[List[str]], reason: Optional[str]) -> None
+    ///
+    /// Marks an entity as obsolete following the OBO deprecation pattern.
+    ///
+    /// Asserts `owl:deprecated true`, prefixes the `rdfs:label` with "obsolete " and removes the
+    /// logical axioms about the entity. Declarations, annotation assertions and axioms of other
+    /// entities referring to it are kept. `replaced_by` adds an `IAO:0100001` (term replaced by)
+    /// annotation, every IRI in `consider` an `oboInOwl:consider` annotation and `reason` an
+    /// `IAO:0000231` (has obsolescence reason) annotation.
+    ///
+    /// As in OBO ontologies, `reason` must be the absolute IRI of a reason term (such as
+    /// `http://purl.obolibrary.org/obo/OMO_0001000`, out of scope) rather than free text;
+    /// otherwise a ValueError is raised and the ontology is left unchanged.
+    fn obsolete_entity(
+        &mut self,
+        iri: String,
+        replaced_by: Option<String>,
+        consider: Option<Vec<String>>,
+        reason: Option<String>,
+    ) -> PyResult<()> {
+        if let Some(reason) = &reason {
+            oxigraph::model::NamedNode::new(reason.as_str()).map_err(|e| {
+                PyValueError::new_err(format!("Obsolescence reason '{}' is not an absolute IRI: {}", reason, e))
+            })?;
+        }
+
+        let subject = self.build.iri(iri);
+
+        let logical: Vec<AnnotatedComponent<ArcStr>> = self
+            .ontology
+            .components_for_iri(&subject)
+            .filter(|aax| is_logical_axiom(&aax.component) && is_subject_of(&aax.component, &subject))
+            .cloned()
+            .collect();
+        for aax in logical.iter() {
+            self.remove_component(aax);
+        }
+
+        //Replace the label that is there, whatever kind of literal it is, keeping its annotations
+        let label = self
+            .ontology
+            .components_for_iri(&subject)
+            .find(|aax| match &aax.component {
+                Component::AnnotationAssertion(AnnotationAssertion {
+                    subject: AnnotationSubject::IRI(s),
+                    ann: Annotation { ap, av: AnnotationValue::Literal(_) },
+                }) => *s == subject && ap.0.as_ref() == AnnotationBuiltIn::Label.as_ref(),
+                _ => false,
+            })
+            .cloned();
+        if let Some(label) = label {
+            let mut obsolete = label.clone();
+            if let Component::AnnotationAssertion(AnnotationAssertion {
+                ann: Annotation { av: AnnotationValue::Literal(literal), .. },
+                ..
+            }) = &mut obsolete.component
+            {
+                let text = match literal {
+                    Literal::Simple { literal }
+                    | Literal::Language { literal, .. }
+                    | Literal::Datatype { literal, .. } => literal,
+                };
+                if !text.starts_with(OBSOLETE_LABEL_PREFIX) {
+                    text.insert_str(0, OBSOLETE_LABEL_PREFIX);
+                    self.remove_component(&label);
+                    self.add_component(obsolete);
+                }
+            }
+        }
+
+        let deprecated = AnnotationValue::Literal(Literal::Datatype {
+            literal: "true".to_string(),
+            datatype_iri: self.build.iri(XSD_BOOLEAN),
+        });
+        self.add_annotation_assertion(&subject, AnnotationBuiltIn::Deprecated.as_ref(), deprecated);
+
+        if let Some(replaced_by) = replaced_by {
+            let value = AnnotationValue::IRI(self.build.iri(replaced_by));
+            self.add_annotation_assertion(&subject, IAO_TERM_REPLACED_BY, value);
+        }
+        for c in consider.unwrap_or_default() {
+            let value = AnnotationValue::IRI(self.build.iri(c));
+            self.add_annotation_assertion(&subject, OBO_IN_OWL_CONSIDER, value);
+        }
+        if let Some(reason) = reason {
+            let value = AnnotationValue::IRI(self.build.iri(reason));
+            self.add_annotation_assertion(&subject, IAO_OBSOLESCENCE_REASON, value);
+        }
+
+        Ok(())
+    }
+
+    /// get_obsolete_entities(self) -> Set[str]
+    ///
+    /// Returns the IRIs of all entities annotated with `owl:deprecated true`.
+    fn get_obsolete_entities(&mut self) -> PyResult<HashSet<String>> {
+        let obsolete = self
+            .ontology
+            .component_for_kind(ComponentKind::AnnotationAssertion)
+            .filter_map(|aax| match &aax.component {
+                Component::AnnotationAssertion(AnnotationAssertion {
+                    subject: AnnotationSubject::IRI(subject),
+                    ann:
+                        Annotation {
+                            ap,
+                            av: AnnotationValue::Literal(literal),
+                        },
+                }) => {
+                    if AnnotationBuiltIn::Deprecated.as_ref().eq(&ap.0.to_string())
+                        && is_true_literal(literal)
+                    {
+                        Some(subject.to_string())
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok(obsolete)
+    }
+
+    /// merge(self, other: PyIndexedOntology, ontology_id: Optional[model.OntologyID], drop_merged_imports: Optional[bool]) -> List[str]
+    ///
+    /// Adds all components and prefixes of `other` to this ontology.
+    ///
+    /// The `OntologyID` of this ontology is kept, or taken from `other` if this ontology has none,
+    /// unless `ontology_id` is given. If `drop_merged_imports` is true, `Import`s of either of the
+    /// two ontologies are removed.
+    ///
+    /// Returns the prefixes that `other` binds to a different IRI than this ontology. These keep
+    /// their current binding.
+    fn merge(
+        &mut self,
+        other: &PyIndexedOntology,
+        ontology_id: Option<model::OntologyID>,
+        drop_merged_imports: Option<bool>,
+    ) -> PyResult<Vec<String>> {
+        let mut merged_iris = self.ontology_iris();
+        let (conflicts, other_iris) = self.merge_from(other);
+        merged_iris.extend(other_iris);
+
+        if let Some(ontology_id) = ontology_id {
+            self.replace_ontology_id(ontology_id.into());
+        }
+        if drop_merged_imports.unwrap_or(false) {
+            self.remove_imports(&merged_iris);
+        }
+
+        Ok(conflicts)
+    }
+
+    /// iri(self, iri: str) -> model.IRI
+    ///
+    /// Creates an new IRI from string.
+    ///
+    /// Use this method instead of  `model.IRI.parse` if possible as it is more optimized using caches.
+    fn iri(&self, iri: String) -> model::IRI {
+        model::IRI::new(iri, &self.build.lock())
+    }
+}
+
+impl PyIndexedOntology {
+    fn insert(&mut self, ax: &AnnotatedComponent<ArcStr>) -> () {
+        let b = Build::new();
+
+        match ax.kind() {
+            ComponentKind::AnnotationAssertion => match ax.clone().component {
+                Component::AnnotationAssertion(AnnotationAssertion { subject, ann }) => match ann {
+                    Annotation {
+                        ap,
+                        av: AnnotationValue::Literal(Literal::Simple { literal }),
+                    } => {
+                        if AnnotationBuiltIn::Label.as_ref().eq(&ap.0.to_string()) {
+                            let _ = &self
+                                .labels_to_iris
                                 .insert(literal.clone(), b.iri(subject.deref()));
                         }
                     }
@@ -623,7 +1483,419 @@ impl PyIndexedOntology {
         }
     }
 
-    fn from(iro: IRIMappedOntology<ArcStr, Arc<AnnotatedComponent<ArcStr>>>) -> PyIndexedOntology {
+    /// Returns the ontology IRI and version IRI of this ontology, if present.
+    fn ontology_iris(&mut self) -> HashSet<IRI<ArcStr>> {
+        self.get_ontology_id()
+            .map(|id| id.iri.iter().chain(id.viri.iter()).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Copies the components and prefixes of `other` into this ontology.
+    ///
+    /// The `OntologyID` of `other` is only copied if this ontology has none. Returns the
+    /// conflicting prefixes and the ontology IRI and version IRI of `other`.
+    fn merge_from(&mut self, other: &PyIndexedOntology) -> (Vec<String>, HashSet<IRI<ArcStr>>) {
+        let has_id = self.get_ontology_id().is_some();
+        let mut other_iris = HashSet::new();
+
+        for aax in other.ontology.iter() {
+            if let Component::OntologyID(OntologyID { iri, viri }) = &aax.component {
+                other_iris.extend(iri.iter().chain(viri.iter()).cloned());
+                if has_id {
+                    continue;
+                }
+            }
+            self.add_component(aax.clone());
+        }
+
+        let mut conflicts = Vec::new();
+        for (prefix, value) in other.mapping.mappings() {
+            let existing = self
+                .mapping
+                .mappings()
+                .find(|(p, _)| *p == prefix)
+                .map(|(_, v)| v.clone());
+            match existing {
+                Some(existing) => {
+                    if &existing != value {
+                        conflicts.push(prefix.clone());
+                    }
+                }
+                None => {
+                    let _ = self.mapping.add_prefix(prefix, value);
+                }
+            }
+        }
+
+        (conflicts, other_iris)
+    }
+
+    /// Replaces the `OntologyID` component of the ontology.
+    fn replace_ontology_id(&mut self, ontology_id: OntologyID<ArcStr>) {
+        let old: Vec<AnnotatedComponent<ArcStr>> = self
+            .ontology
+            .component_for_kind(ComponentKind::OntologyID)
+            .cloned()
+            .collect();
+        for aax in old.iter() {
+            self.remove_component(aax);
+        }
+        self.add_component(Component::OntologyID(ontology_id).into());
+    }
+
+    /// Removes every component of `kind` equal to `component`, regardless of its axiom annotations,
+    /// and returns the number of removed components.
+    fn remove_components_of_kind(&mut self, kind: ComponentKind, component: &Component<ArcStr>) -> usize {
+        let matching: Vec<AnnotatedComponent<ArcStr>> = self
+            .ontology
+            .component_for_kind(kind)
+            .filter(|aax| &aax.component == component)
+            .cloned()
+            .collect();
+        let mut removed = 0;
+        for aax in matching.iter() {
+            if self.remove_component(aax) {
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    /// Removes all `Import`s of the given ontology IRIs.
+    fn remove_imports(&mut self, iris: &HashSet<IRI<ArcStr>>) {
+        let imports: Vec<AnnotatedComponent<ArcStr>> = self
+            .ontology
+            .component_for_kind(ComponentKind::Import)
+            .filter(|aax| matches!(&aax.component, Component::Import(Import(i)) if iris.contains(i)))
+            .cloned()
+            .collect();
+        for aax in imports.iter() {
+            self.remove_component(aax);
+        }
+    }
+
+    /// Returns the ontology IRI, if present.
+    fn ontology_iri(&mut self) -> Option<String> {
+        self.get_ontology_id()
+            .and_then(|id| id.iri.as_ref())
+            .map(|iri| iri.to_string())
+    }
+
+    /// Returns the IRIs of all `Import`s of this ontology.
+    fn import_iris(&mut self) -> Vec<String> {
+        self.ontology
+            .component_for_kind(ComponentKind::Import)
+            .filter_map(|aax| match &aax.component {
+                Component::Import(Import(iri)) => Some(iri.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Loads the imports closure through `catalog` and merges it into this ontology.
+    fn resolve_imports(&mut self, catalog: &catalog::Catalog) -> PyResult<()> {
+        let mut visited: HashSet<String> = self.ontology_iris().iter().map(|i| i.to_string()).collect();
+        let mut pending = self.import_iris();
+
+        while let Some(import) = pending.pop() {
+            if !visited.insert(import.clone()) {
+                continue;
+            }
+
+            let path = catalog.resolve(&import).ok_or_else(|| {
+                PyValueError::new_err(format!("Unable to resolve import '{}' to a local file.", import))
+            })?;
+            let mut imported = load_ontology(&path.to_string_lossy(), &self.build.lock())?;
+
+            visited.extend(imported.ontology_iris().iter().map(|i| i.to_string()));
+            pending.extend(imported.import_iris());
+            self.merge_from(&imported);
+        }
+
+        Ok(())
+    }
+
+    /// Returns a resolver for names in Manchester syntax using the labels, prefixes and
+    /// declarations of this ontology.
+    fn manchester_resolver(&mut self) -> manchester::Resolver<'_> {
+        let mut object_properties = HashSet::new();
+        let mut data_properties = HashSet::new();
+        let mut annotation_properties = HashSet::new();
+        let mut datatypes = HashSet::new();
+
+        for aax in self.ontology.component_for_kind(ComponentKind::DeclareObjectProperty) {
+            if let Component::DeclareObjectProperty(DeclareObjectProperty(op)) = &aax.component {
+                object_properties.insert(op.0.clone());
+            }
+        }
+        for aax in self.ontology.component_for_kind(ComponentKind::DeclareDataProperty) {
+            if let Component::DeclareDataProperty(DeclareDataProperty(dp)) = &aax.component {
+                data_properties.insert(dp.0.clone());
+            }
+        }
+        for aax in self.ontology.component_for_kind(ComponentKind::DeclareAnnotationProperty) {
+            if let Component::DeclareAnnotationProperty(DeclareAnnotationProperty(ap)) = &aax.component {
+                annotation_properties.insert(ap.0.clone());
+            }
+        }
+        for aax in self.ontology.component_for_kind(ComponentKind::DeclareDatatype) {
+            if let Component::DeclareDatatype(DeclareDatatype(dt)) = &aax.component {
+                datatypes.insert(dt.0.clone());
+            }
+        }
+
+        manchester::Resolver {
+            labels: &self.labels_to_iris,
+            mapping: &self.mapping,
+            build: &self.build,
+            object_properties,
+            data_properties,
+            annotation_properties,
+            datatypes,
+        }
+    }
+
+    /// Returns a renderer for Manchester syntax using the labels and prefixes of this ontology.
+    fn manchester_renderer(&self) -> manchester::Renderer<'_> {
+        //An entity may have several labels, use the smallest so that the output is deterministic
+        let mut labels: HashMap<IRI<ArcStr>, &str> = HashMap::new();
+        for (label, iri) in self.labels_to_iris.iter() {
+            let current = labels.entry(iri.clone()).or_insert(label);
+            if label.as_str() < *current {
+                *current = label;
+            }
+        }
+
+        manchester::Renderer {
+            labels,
+            mapping: &self.mapping,
+        }
+    }
+
+    /// Copies the components and prefixes into a new ontology.
+    fn duplicate(&self) -> PyIndexedOntology {
+        let mut copy = PyIndexedOntology::default();
+        for aax in self.ontology.iter() {
+            copy.add_component(aax.clone());
+        }
+        for (prefix, iri) in self.mapping.mappings() {
+            let _ = copy.mapping.add_prefix(prefix, iri);
+        }
+        copy.canonical = self.canonical;
+        copy
+    }
+
+    /// The components in the order writers should use.
+    fn components(&self) -> Vec<&AnnotatedComponent<ArcStr>> {
+        if self.canonical {
+            canonical::order(self.ontology.iter())
+        } else {
+            self.ontology.iter().collect()
+        }
+    }
+
+    /// The triples of the RDF mapping, with canonical blank node labels in canonical mode.
+    fn rdf_triples(&mut self) -> PyResult<Vec<oxigraph::model::Triple>> {
+        let triples = stream::triples(&mut self.ontology)?;
+        if self.canonical {
+            Ok(canonical::label_blank_nodes(triples))
+        } else {
+            Ok(triples)
+        }
+    }
+
+    /// Writes the RDF mapping of the ontology in `format` to `out`.
+    ///
+    /// RDF/XML is written by horned-owl unless in canonical mode. Turtle, N-Triples and canonical
+    /// RDF/XML are sorted, which needs all triples at once.
+    fn write_rdf<W: Write>(&mut self, out: W, format: RdfFormat) -> PyResult<()> {
+        if self.canonical || format != RdfFormat::RdfXml {
+            rdf_syntax::write(self.rdf_triples()?, format, &self.mapping, out)
+        } else {
+            stream::write_rdf_xml(out, &mut self.ontology)
+        }
+    }
+
+    /// Writes the ontology to `out` in one of `SERIALIZATIONS`.
+    fn write_serialization<W: Write>(&mut self, out: W, serialization: &str) -> PyResult<()> {
+        match serialization {
+            "owx" => stream::write_owx(out, &mut self.ontology, &self.mapping),
+            "rdfxml" => self.write_rdf(out, RdfFormat::RdfXml),
+            "turtle" => self.write_rdf(out, RdfFormat::Turtle),
+            "ntriples" => self.write_rdf(out, RdfFormat::NTriples),
+            "jsonld" => jsonld::write(self.rdf_triples()?, &self.mapping, out),
+            "obo" => obo::write(self.components().into_iter(), &self.mapping, out),
+            "obographs" => obographs::write(self.components().into_iter(), out),
+            other => Err(PyValueError::new_err(format!(
+                "Unknown serialization '{}'.",
+                other
+            ))),
+        }
+    }
+
+    /// Writes the ontology to a string in one of `SERIALIZATIONS`.
+    fn write_to_string(&mut self, serialization: &str) -> PyResult<String> {
+        let mut buf = Vec::new();
+        self.write_serialization(&mut buf, serialization)?;
+        String::from_utf8(buf).map_err(|e| {
+            PyValueError::new_err(format!("Problem writing the ontology to a string: {}", e))
+        })
+    }
+
+    /// Reverts the index updates made by `insert` for a component that is being removed.
+    fn remove_from_indexes(&mut self, ax: &AnnotatedComponent<ArcStr>) {
+        match &ax.component {
+            Component::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(subject),
+                ann:
+                    Annotation {
+                        ap,
+                        av: AnnotationValue::Literal(Literal::Simple { literal }),
+                    },
+            }) if AnnotationBuiltIn::Label.as_ref().eq(&ap.0.to_string())
+                && self.labels_to_iris.get(literal) == Some(subject) =>
+            {
+                self.labels_to_iris.remove(literal);
+            }
+            Component::SubClassOf(SubClassOf {
+                sup: ClassExpression::Class(c),
+                sub: ClassExpression::Class(d),
+            }) => {
+                //The same axiom with other annotations still asserts the edge
+                let asserted = self.ontology.components_for_iri(&d.0).any(|aax| {
+                    aax != ax
+                        && matches!(&aax.component, Component::SubClassOf(SubClassOf {
+                            sup: ClassExpression::Class(sup),
+                            sub: ClassExpression::Class(sub),
+                        }) if sup == c && sub == d)
+                });
+                if asserted {
+                    return;
+                }
+                if let Some(subclasses) = self.classes_to_subclasses.get_mut(&c.0) {
+                    subclasses.remove(&d.0);
+                    if subclasses.is_empty() {
+                        self.classes_to_subclasses.remove(&c.0);
+                    }
+                }
+                if let Some(superclasses) = self.classes_to_superclasses.get_mut(&d.0) {
+                    superclasses.remove(&c.0);
+                    if superclasses.is_empty() {
+                        self.classes_to_superclasses.remove(&d.0);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Returns all annotated components of the ontology for `component`.
+    ///
+    /// Raises a `ValueError` if there are none.
+    fn annotated_components(&mut self, component: &Component<ArcStr>) -> PyResult<Vec<AnnotatedComponent<ArcStr>>> {
+        let annotated: Vec<AnnotatedComponent<ArcStr>> = self
+            .ontology
+            .component_for_kind(component.kind())
+            .filter(|aax| &aax.component == component)
+            .cloned()
+            .collect();
+
+        if annotated.is_empty() {
+            Err(PyValueError::new_err("The ontology does not contain this axiom."))
+        } else {
+            Ok(annotated)
+        }
+    }
+
+    /// Removes all annotated components for `component` and returns the union of their annotations.
+    ///
+    /// Raises a `ValueError` if there are none.
+    fn take_annotated_components(&mut self, component: &Component<ArcStr>) -> PyResult<BTreeSet<Annotation<ArcStr>>> {
+        let mut annotations = BTreeSet::new();
+        for aax in self.annotated_components(component)? {
+            self.remove_component(&aax);
+            annotations.extend(aax.ann);
+        }
+        Ok(annotations)
+    }
+
+    /// Returns all `AnnotationAssertion`s on `subject` for the annotation property `ap`.
+    fn annotation_assertions(&mut self, subject: &IRI<ArcStr>, ap: &str) -> Vec<AnnotatedComponent<ArcStr>> {
+        self.ontology
+            .components_for_iri(subject)
+            .filter(|aax| match &aax.component {
+                Component::AnnotationAssertion(AnnotationAssertion {
+                    subject: AnnotationSubject::IRI(s),
+                    ann,
+                }) => s == subject && ap.eq(&ann.ap.0.to_string()),
+                _ => false,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Converts a Python annotation value into an `AnnotationValue`.
+    ///
+    /// Strings become literals with the given language tag or datatype, anything else is extracted
+    /// as `model.AnnotationValue`.
+    fn annotation_value(
+        &self,
+        value: &PyAny,
+        lang: Option<String>,
+        datatype: Option<String>,
+    ) -> PyResult<AnnotationValue<ArcStr>> {
+        let literal = match value.extract::<String>() {
+            Ok(literal) => literal,
+            Err(_) => return Ok(value.extract::<model::AnnotationValue>()?.into()),
+        };
+
+        let literal = match (lang, datatype) {
+            (Some(_), Some(_)) => {
+                return Err(PyValueError::new_err(
+                    "A literal cannot have both a language tag and a datatype.",
+                ))
+            }
+            (Some(lang), None) => Literal::Language { literal, lang },
+            (None, Some(datatype)) => Literal::Datatype {
+                literal,
+                datatype_iri: self.build.iri(datatype),
+            },
+            (None, None) => Literal::Simple { literal },
+        };
+        Ok(AnnotationValue::Literal(literal))
+    }
+
+    /// Adds an `AnnotationAssertion` on `subject` for the annotation property `ap`.
+    fn add_annotation_assertion(&mut self, subject: &IRI<ArcStr>, ap: &str, av: AnnotationValue<ArcStr>) -> bool {
+        let ax: AnnotatedComponent<ArcStr> = Component::AnnotationAssertion(AnnotationAssertion {
+            subject: subject.clone().into(),
+            ann: Annotation {
+                ap: self.build.annotation_property(ap),
+                av,
+            },
+        })
+        .into();
+        self.add_component(ax)
+    }
+
+    /// Adds a component to the ontology and updates the label and hierarchy indexes.
+    fn add_component(&mut self, ax: AnnotatedComponent<ArcStr>) -> bool {
+        self.insert(&ax);
+        self.rdf_store = None;
+        self.ontology.insert(ax)
+    }
+
+    /// Removes a component from the ontology and updates the label and hierarchy indexes.
+    fn remove_component(&mut self, ax: &AnnotatedComponent<ArcStr>) -> bool {
+        let removed = self.ontology.remove(ax);
+        if removed {
+            self.rdf_store = None;
+            self.remove_from_indexes(ax);
+        }
+        removed
+    }
+
+    fn from(iro: IRIMappedOntology) -> PyIndexedOntology {
         let mut ino = PyIndexedOntology::default();
 
         for ax in iro.iter() {
@@ -636,118 +1908,456 @@ impl PyIndexedOntology {
     }
 }
 
-fn open_ontology_owx(
-    ontology: &str,
-    b: &Build<Arc<str>>,
-) -> Result<(SetOntology<ArcStr>, PrefixMapping), HornedError> {
-    let r = if Path::new(&ontology).exists() {
-        let file = File::open(ontology).ok().unwrap();
-        let mut f = BufReader::new(file);
-        horned_owl::io::owx::reader::read_with_build(&mut f, &b)
-    } else {
-        //just try to parse the string
-        let str_val = ontology.as_bytes();
-        let mut f = BufReader::new(str_val);
-        horned_owl::io::owx::reader::read_with_build(&mut f, &b)
-    };
-    r
-}
-
-fn open_ontology_rdf(
-    ontology: &str,
-    b: &Build<Arc<str>>,
-) -> Result<
-    (
-        RDFOntology<ArcStr, Arc<AnnotatedComponent<ArcStr>>>,
-        IncompleteParse<Arc<str>>,
-    ),
-    HornedError,
-> {
-    let r = if Path::new(&ontology).exists() {
-        let file = File::open(ontology).ok().unwrap();
-        let mut f = BufReader::new(file);
-        horned_owl::io::rdf::reader::read_with_build(&mut f, &b, ParserConfiguration::default())
-    } else {
-        //just try to parse the string
-        let str_val = ontology.as_bytes();
-        let mut f = BufReader::new(str_val);
+fn read_owx(text: &str, b: &Build<ArcStr>) -> PyResult<PyIndexedOntology> {
+    let (o, m) = horned_owl::io::owx::reader::read_with_build(&mut BufReader::new(text.as_bytes()), b)
+        .map_err(|e| PyValueError::new_err(format!("Unable to open ontology: {:?}", e)))?;
+    let iro = IRIMappedOntology::from(o);
+    let mut lo = PyIndexedOntology::from(iro);
+    lo.mapping = m;
+    Ok(lo)
+}
+
+fn read_rdf_syntax(text: &str, format: RdfFormat, b: &Build<ArcStr>) -> PyResult<PyIndexedOntology> {
+    let (rdf_xml, mapping) = rdf_syntax::to_rdf_xml(text.as_bytes(), format)?;
+    ontology_from_rdf_xml(&rdf_xml, mapping, b)
+}
+
+fn read_jsonld(text: &str, b: &Build<ArcStr>) -> PyResult<PyIndexedOntology> {
+    let (triples, mapping) = jsonld::read(text)?;
+    ontology_from_rdf_xml(&rdf_syntax::rdf_xml(&triples)?, mapping, b)
+}
+
+/// Reads RDF/XML converted from another RDF syntax, keeping the prefixes of the original document.
+fn ontology_from_rdf_xml(
+    rdf_xml: &[u8],
+    mapping: PrefixMapping,
+    b: &Build<ArcStr>,
+) -> PyResult<PyIndexedOntology> {
+    let (o, _): (RDFOntology<ArcStr, Arc<AnnotatedComponent<ArcStr>>>, _) =
         horned_owl::io::rdf::reader::read_with_build(
-            &mut f,
-            &b,
-            ParserConfiguration {
-                rdf: RDFParserConfiguration { lax: true },
-                ..Default::default()
-            },
+            &mut BufReader::new(rdf_xml),
+            b,
+            ParserConfiguration::default(),
         )
-    };
-    r
+        .map_err(|e| PyValueError::new_err(format!("Unable to open ontology: {:?}", e)))?;
+    let so = SetOntology::from(o);
+    let iro = IRIMappedOntology::from(so);
+    let mut lo = PyIndexedOntology::from(iro);
+    lo.mapping = mapping;
+    Ok(lo)
+}
+
+fn read_obographs(text: &str, b: &Build<ArcStr>) -> PyResult<PyIndexedOntology> {
+    let mut lo = PyIndexedOntology::default();
+    for ac in obographs::read(text, b)? {
+        lo.add_component(ac);
+    }
+    Ok(lo)
+}
+
+fn read_obo(text: &str, b: &Build<ArcStr>) -> PyResult<PyIndexedOntology> {
+    let (components, mapping) = obo::read(text, b)?;
+    let mut lo = PyIndexedOntology::default();
+    for ac in components {
+        lo.add_component(ac);
+    }
+    lo.mapping = mapping;
+    Ok(lo)
 }
 
+/// The serializations `PyIndexedOntology.save_to_file` can write.
+const SERIALIZATIONS: [&str; 7] = ["owx", "rdfxml", "turtle", "ntriples", "jsonld", "obo", "obographs"];
 
-/// open_ontology(ontology: str) -> PyIndexedOntology
+/// open_ontology(ontology: str, resolve_imports: Optional[bool], catalog: Optional[str]) -> PyIndexedOntology
 ///
 /// Opens an ontology from a path or plain text.
 ///
-/// If `ontology` is a path, the file is loaded. Otherwise, `ontology` is interepreted as an ontology in either owx or owl format.
-/// Note: Only .owl and .owx files are currently supported.
+/// If `ontology` is the path of an existing file, it is loaded as by `open_ontology_from_file`.
+/// Otherwise `ontology` is read as the text of an ontology as by `open_ontology_from_string`. In
+/// both cases the format is guessed from the file extension or the content; as OBO Graphs and
+/// JSON-LD documents both use `.json`, the content decides between them. Use
+/// `open_ontology_from_file` or `open_ontology_from_string` to avoid guessing whether `ontology`
+/// is a path.
+///
+/// If `resolve_imports` is true, the imports closure is loaded from local files and merged into the
+/// returned ontology. Import IRIs are resolved through `catalog`, which is either a Protégé-style XML
+/// catalog or a directory containing the imported files named like the last segment of their IRI.
+/// It defaults to the `catalog-v001.xml` next to the ontology file. Imports are never fetched from
+/// the network; an import that cannot be resolved raises a `ValueError`.
 #[pyfunction]
-fn open_ontology(ontology: &PyString) -> PyResult<PyIndexedOntology> {
+fn open_ontology(
+    ontology: &PyString,
+    resolve_imports: Option<bool>,
+    catalog: Option<String>,
+) -> PyResult<PyIndexedOntology> {
     let ontology: String = ontology.extract().unwrap();
 
     let b = Build::new_arc();
+    let mut lo = load_ontology(&ontology, &b)?;
 
-    let result = if ontology.ends_with("owx") {
-        let r = open_ontology_owx(&ontology, &b);
-        //println!("Got result {:?}",r);
-        if r.is_ok() {
-            let (o, m) = r.ok().unwrap();
-            //println!("Got ontology from owx {:?}",o);
-            //println!("About to build indexes");
-            let iro = IRIMappedOntology::from(o);
-            let mut lo = PyIndexedOntology::from(iro);
-            lo.mapping = m; //Needed when saving
-            Ok(lo)
-        } else {
-            Err(PyValueError::new_err("Unable to open ontology"))
-        }
-    } else if ontology.ends_with("owl") {
-        let r2 = open_ontology_rdf(&ontology, &b);
-        if r2.is_ok() {
-            let (o, _) = r2.ok().unwrap();
-            //println!("Got ontology from rdf {:?}",o);
-            let so = SetOntology::from(o);
-            let iro = IRIMappedOntology::from(so);
-            let lo = PyIndexedOntology::from(iro);
-            Ok(lo)
-        } else {
-            Err(PyValueError::new_err("Unable to open ontology"))
-        }
+    if resolve_imports.unwrap_or(false) {
+        let catalog = catalog::Catalog::for_ontology(&ontology, catalog)?;
+        lo.resolve_imports(&catalog)?;
+    }
+
+    Ok(lo)
+}
+
+/// Loads an ontology from a path or plain text, guessing the format from the file extension or
+/// the content.
+fn load_ontology(ontology: &str, b: &Build<ArcStr>) -> PyResult<PyIndexedOntology> {
+    if Path::new(ontology).exists() {
+        read_ontology_file(ontology, None, b)
+    } else if !ontology.contains('\n') && input::format_for_path(ontology).is_some() {
+        Err(PyValueError::new_err(format!("Unable to open ontology: no such file '{}'.", ontology)))
     } else {
-        // No recognised suffix, maybe it is a string value, just try to parse
-        let r = open_ontology_owx(&ontology, &b);
-        if r.is_ok() {
-            let (o, m) = r.ok().unwrap();
-            //println!("Got ontology from owx {:?}",o);
-            //println!("About to build indexes");
-            let iro = IRIMappedOntology::from(o);
-            let mut lo = PyIndexedOntology::from(iro);
-            lo.mapping = m; //Needed when saving
-            Ok(lo)
-        } else {
-            let r2 = open_ontology_rdf(&ontology, &b);
-            if r2.is_ok() {
-                let (o, _) = r2.ok().unwrap();
-                //println!("Got ontology from rdf {:?}",o);
-                let so = SetOntology::from(o);
-                let iro = IRIMappedOntology::from(so);
-                let lo = PyIndexedOntology::from(iro);
-                Ok(lo)
-            } else {
-                Err(PyValueError::new_err("Unable to open ontology"))
-            }
+        read_ontology(ontology, None, b)
+    }
+}
+
+/// Reads an ontology document in `format`, guessing the format from the text if it is not given.
+fn read_ontology(text: &str, format: Option<&str>, b: &Build<ArcStr>) -> PyResult<PyIndexedOntology> {
+    let format = match format {
+        Some(format) => input::format_name(format)?,
+        None => input::guess_format(text)?,
+    };
+    match format {
+        "owx" => read_owx(text, b),
+        "rdfxml" => ontology_from_rdf_xml(text.as_bytes(), rdf_syntax::rdf_xml_prefixes(text.as_bytes())?, b),
+        "turtle" => read_rdf_syntax(text, RdfFormat::Turtle, b),
+        "ntriples" => read_rdf_syntax(text, RdfFormat::NTriples, b),
+        "jsonld" => read_jsonld(text, b),
+        "obo" => read_obo(text, b),
+        "obographs" => read_obographs(text, b),
+        _ => unreachable!(),
+    }
+}
+
+/// Reads an ontology file, guessing the format from its extension or else its content if it is
+/// not given.
+fn read_ontology_file(path: &str, format: Option<&str>, b: &Build<ArcStr>) -> PyResult<PyIndexedOntology> {
+    let text = input::text(std::fs::read(path)?)?;
+    //OBO Graphs and JSON-LD documents both use `.json`, so the content decides between them
+    let format = format.or_else(|| input::format_for_path(path).filter(|format| *format != "obographs"));
+    read_ontology(&text, format, b)
+}
+
+/// open_ontology_from_file(path: str, format: Optional[str], resolve_imports: Optional[bool], catalog: Optional[str]) -> PyIndexedOntology
+///
+/// Opens an ontology from a file.
+///
+/// `format` is one of "owx", "rdfxml", "turtle", "ntriples", "jsonld", "obo" or "obographs", or
+/// a file extension such as "owl" or "ttl". If it is not given, it is guessed from the extension
+/// of `path` and otherwise from the content of the file, and a `ValueError` is raised if it cannot
+/// be. `.json` files are read as JSON-LD or OBO Graphs depending on their content. Files compressed with gzip, bzip2 or zstd and zip archives holding a single file are
+/// decompressed transparently. `resolve_imports` and `catalog` are as for `open_ontology`.
+#[pyfunction]
+fn open_ontology_from_file(
+    path: String,
+    format: Option<String>,
+    resolve_imports: Option<bool>,
+    catalog: Option<String>,
+) -> PyResult<PyIndexedOntology> {
+    let b = Build::new_arc();
+    let mut lo = read_ontology_file(&path, format.as_deref(), &b)?;
+
+    if resolve_imports.unwrap_or(false) {
+        let catalog = catalog::Catalog::for_ontology(&path, catalog)?;
+        lo.resolve_imports(&catalog)?;
+    }
+
+    Ok(lo)
+}
+
+/// open_ontology_from_string(text: str, format: Optional[str]) -> PyIndexedOntology
+///
+/// Opens an ontology from its text.
+///
+/// `format` is as for `open_ontology_from_file`. If it is not given, it is guessed from the
+/// text. Unlike `open_ontology`, `text` is never interpreted as a path.
+#[pyfunction]
+fn open_ontology_from_string(text: String, format: Option<String>) -> PyResult<PyIndexedOntology> {
+    read_ontology(&text, format.as_deref(), &Build::new_arc())
+}
+
+/// open_ontology_from_bytes(data: bytes, format: Optional[str]) -> PyIndexedOntology
+///
+/// Opens an ontology from the bytes of a document, which may be compressed with gzip, bzip2 or
+/// zstd or be a zip archive holding a single file.
+///
+/// `format` is as for `open_ontology_from_file`. If it is not given, it is guessed from the
+/// decompressed content.
+#[pyfunction]
+fn open_ontology_from_bytes(data: &[u8], format: Option<String>) -> PyResult<PyIndexedOntology> {
+    read_ontology(&input::text(data.to_vec())?, format.as_deref(), &Build::new_arc())
+}
+
+/// open_ontology_from_reader(reader: typing.Union[typing.BinaryIO, typing.TextIO], format: Optional[str]) -> PyIndexedOntology
+///
+/// Opens an ontology from a file-like object, reading it to the end.
+///
+/// Binary readers may return compressed data as for `open_ontology_from_bytes`. `format` is as
+/// for `open_ontology_from_file`. If it is not given, it is guessed from the content.
+#[pyfunction]
+fn open_ontology_from_reader(reader: &PyAny, format: Option<String>) -> PyResult<PyIndexedOntology> {
+    let content = reader.call_method0("read")?;
+    let text = match content.downcast::<PyString>() {
+        Ok(text) => text.to_str()?.to_string(),
+        Err(_) => input::text(content.extract::<&[u8]>()?.to_vec())?,
+    };
+    read_ontology(&text, format.as_deref(), &Build::new_arc())
+}
+
+/// A value to match annotation values against.
+///
+/// A string matches literals with the same lexical value regardless of their language or datatype,
+/// any other value must be equal to the annotation value.
+enum AnnotationValueFilter {
+    Lexical(String),
+    Exact(AnnotationValue<ArcStr>),
+}
+
+impl AnnotationValueFilter {
+    fn extract(value: &PyAny) -> PyResult<Self> {
+        match value.extract::<String>() {
+            Ok(literal) => Ok(AnnotationValueFilter::Lexical(literal)),
+            Err(_) => Ok(AnnotationValueFilter::Exact(
+                value.extract::<model::AnnotationValue>()?.into(),
+            )),
         }
+    }
+
+    fn matches(&self, av: &AnnotationValue<ArcStr>) -> bool {
+        match (self, av) {
+            (AnnotationValueFilter::Exact(value), _) => value == av,
+            (
+                AnnotationValueFilter::Lexical(value),
+                AnnotationValue::Literal(Literal::Simple { literal })
+                | AnnotationValue::Literal(Literal::Language { literal, .. })
+                | AnnotationValue::Literal(Literal::Datatype { literal, .. }),
+            ) => value == literal,
+            _ => false,
+        }
+    }
+}
+
+/// Converts a literal into the corresponding Python value based on its XSD datatype.
+///
+/// Literals without a datatype, with an unsupported datatype or whose lexical form is not valid
+/// for their datatype are returned as `str`, unchanged. An `xsd:date` loses its timezone, which
+/// `datetime.date` cannot hold.
+fn literal_to_py(py: Python, literal: &Literal<ArcStr>) -> PyResult<PyObject> {
+    let (literal, datatype) = match literal {
+        Literal::Simple { literal } | Literal::Language { literal, .. } => {
+            return Ok(literal.to_object(py))
+        }
+        Literal::Datatype {
+            literal,
+            datatype_iri,
+        } => (literal, datatype_iri),
     };
-    result
+
+    let datatype = datatype.as_ref().strip_prefix(XSD_NS).unwrap_or_default();
+    match native_value(py, literal.trim(), datatype) {
+        Ok(Some(value)) => Ok(value),
+        Ok(None) | Err(_) => Ok(literal.to_object(py)),
+    }
+}
+
+/// Converts the whitespace-trimmed lexical form of a literal with the XSD datatype `datatype`,
+/// given by its local name. Returns `None` for unsupported datatypes.
+fn native_value(py: Python, value: &str, datatype: &str) -> PyResult<Option<PyObject>> {
+    let converted = match datatype {
+        "integer" | "int" | "long" | "short" | "byte" | "nonNegativeInteger" | "positiveInteger"
+        | "nonPositiveInteger" | "negativeInteger" | "unsignedLong" | "unsignedInt"
+        | "unsignedShort" | "unsignedByte" => py.import("builtins")?.getattr("int")?.call1((value,))?,
+        "boolean" => match value {
+            "true" | "1" => return Ok(Some(true.to_object(py))),
+            "false" | "0" => return Ok(Some(false.to_object(py))),
+            _ => return Ok(None),
+        },
+        "decimal" => py.import("decimal")?.getattr("Decimal")?.call1((value,))?,
+        "double" | "float" => py.import("builtins")?.getattr("float")?.call1((value,))?,
+        "dateTime" | "dateTimeStamp" => {
+            //Older Pythons do not accept "Z" for UTC
+            let value = match value.strip_suffix('Z') {
+                Some(value) => format!("{}+00:00", value),
+                None => value.to_string(),
+            };
+            py.import("datetime")?
+                .getattr("datetime")?
+                .call_method1("fromisoformat", (value,))?
+        }
+        "date" => py
+            .import("datetime")?
+            .getattr("date")?
+            .call_method1("fromisoformat", (strip_timezone(value),))?,
+        _ => return Ok(None),
+    };
+
+    Ok(Some(converted.to_object(py)))
+}
+
+/// Removes a trailing XSD timezone, either `Z` or an offset such as `+02:00`.
+fn strip_timezone(value: &str) -> &str {
+    if let Some(value) = value.strip_suffix('Z') {
+        return value;
+    }
+    match value.len().checked_sub(6).and_then(|i| Some((value.get(..i)?, value.get(i..)?))) {
+        Some((date, offset)) if offset.starts_with(['+', '-']) && offset.as_bytes()[3] == b':' => date,
+        _ => value,
+    }
+}
+
+/// Checks whether a component is a logical axiom, as opposed to a declaration, an annotation
+/// axiom or an ontology header component.
+fn is_logical_axiom(component: &Component<ArcStr>) -> bool {
+    !matches!(
+        component,
+        Component::DocIRI(_)
+            | Component::OntologyID(_)
+            | Component::OntologyAnnotation(_)
+            | Component::Import(_)
+            | Component::DeclareClass(_)
+            | Component::DeclareObjectProperty(_)
+            | Component::DeclareAnnotationProperty(_)
+            | Component::DeclareDataProperty(_)
+            | Component::DeclareNamedIndividual(_)
+            | Component::DeclareDatatype(_)
+            | Component::AnnotationAssertion(_)
+            | Component::SubAnnotationPropertyOf(_)
+            | Component::AnnotationPropertyDomain(_)
+            | Component::AnnotationPropertyRange(_)
+    )
+}
+
+/// Checks whether a literal is the boolean value `true`, as used by `owl:deprecated`.
+fn is_true_literal(literal: &Literal<ArcStr>) -> bool {
+    match literal {
+        Literal::Simple { literal } | Literal::Datatype { literal, .. } => {
+            literal == "true" || literal == "1"
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether `iri` is the entity a component is about, i.e. the declared entity, the annotated
+/// subject, or the entity in the defining position of an axiom (the subclass of a `SubClassOf`, the
+/// property of a characteristic, the source individual of an assertion and so on).
+fn is_subject_of(component: &Component<ArcStr>, iri: &IRI<ArcStr>) -> bool {
+    let is_class = |ce: &ClassExpression<ArcStr>| matches!(ce, ClassExpression::Class(Class(c)) if c == iri);
+    let is_ope = |ope: &ObjectPropertyExpression<ArcStr>| {
+        matches!(ope, ObjectPropertyExpression::ObjectProperty(ObjectProperty(op)) if op == iri)
+    };
+    let is_individual = |i: &Individual<ArcStr>| matches!(i, Individual::Named(NamedIndividual(n)) if n == iri);
+
+    match component {
+        Component::DeclareClass(DeclareClass(Class(c))) => c == iri,
+        Component::DeclareObjectProperty(DeclareObjectProperty(ObjectProperty(op))) => op == iri,
+        Component::DeclareAnnotationProperty(DeclareAnnotationProperty(AnnotationProperty(ap))) => {
+            ap == iri
+        }
+        Component::DeclareDataProperty(DeclareDataProperty(DataProperty(dp))) => dp == iri,
+        Component::DeclareNamedIndividual(DeclareNamedIndividual(NamedIndividual(i))) => i == iri,
+        Component::DeclareDatatype(DeclareDatatype(Datatype(dt))) => dt == iri,
+        Component::AnnotationAssertion(AnnotationAssertion { subject, .. }) => {
+            matches!(subject, AnnotationSubject::IRI(s) if s == iri)
+        }
+        Component::SubClassOf(SubClassOf { sub, .. }) => is_class(sub),
+        Component::EquivalentClasses(EquivalentClasses(ces)) => ces.iter().any(is_class),
+        Component::DisjointUnion(DisjointUnion(Class(c), _)) => c == iri,
+        Component::HasKey(HasKey { ce, .. }) => is_class(ce),
+        Component::SubObjectPropertyOf(SubObjectPropertyOf { sub, .. }) => {
+            matches!(sub, SubObjectPropertyExpression::ObjectPropertyExpression(ope) if is_ope(ope))
+        }
+        Component::EquivalentObjectProperties(EquivalentObjectProperties(opes)) => {
+            opes.iter().any(is_ope)
+        }
+        Component::InverseObjectProperties(InverseObjectProperties(ObjectProperty(op), _)) => {
+            op == iri
+        }
+        Component::ObjectPropertyDomain(ObjectPropertyDomain { ope, .. })
+        | Component::ObjectPropertyRange(ObjectPropertyRange { ope, .. })
+        | Component::FunctionalObjectProperty(FunctionalObjectProperty(ope))
+        | Component::InverseFunctionalObjectProperty(InverseFunctionalObjectProperty(ope))
+        | Component::ReflexiveObjectProperty(ReflexiveObjectProperty(ope))
+        | Component::IrreflexiveObjectProperty(IrreflexiveObjectProperty(ope))
+        | Component::SymmetricObjectProperty(SymmetricObjectProperty(ope))
+        | Component::AsymmetricObjectProperty(AsymmetricObjectProperty(ope))
+        | Component::TransitiveObjectProperty(TransitiveObjectProperty(ope)) => is_ope(ope),
+        Component::SubDataPropertyOf(SubDataPropertyOf { sub: DataProperty(dp), .. })
+        | Component::DataPropertyDomain(DataPropertyDomain { dp: DataProperty(dp), .. })
+        | Component::DataPropertyRange(DataPropertyRange { dp: DataProperty(dp), .. })
+        | Component::FunctionalDataProperty(FunctionalDataProperty(DataProperty(dp))) => dp == iri,
+        Component::EquivalentDataProperties(EquivalentDataProperties(dps)) => {
+            dps.iter().any(|DataProperty(dp)| dp == iri)
+        }
+        Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf {
+            sub: AnnotationProperty(ap),
+            ..
+        })
+        | Component::AnnotationPropertyDomain(AnnotationPropertyDomain {
+            ap: AnnotationProperty(ap),
+            ..
+        })
+        | Component::AnnotationPropertyRange(AnnotationPropertyRange {
+            ap: AnnotationProperty(ap),
+            ..
+        }) => ap == iri,
+        Component::DatatypeDefinition(DatatypeDefinition { kind: Datatype(dt), .. }) => dt == iri,
+        Component::ClassAssertion(ClassAssertion { i, .. }) => is_individual(i),
+        Component::ObjectPropertyAssertion(ObjectPropertyAssertion { from, .. })
+        | Component::NegativeObjectPropertyAssertion(NegativeObjectPropertyAssertion {
+            from, ..
+        })
+        | Component::DataPropertyAssertion(DataPropertyAssertion { from, .. })
+        | Component::NegativeDataPropertyAssertion(NegativeDataPropertyAssertion { from, .. }) => {
+            is_individual(from)
+        }
+        _ => false,
+    }
+}
+
+/// merge_ontologies(ontologies: List[PyIndexedOntology], ontology_id: Optional[model.OntologyID], drop_merged_imports: Optional[bool]) -> PyIndexedOntology
+///
+/// Merges several ontologies into a new ontology.
+///
+/// Components and prefixes are combined as in `PyIndexedOntology.merge`. The `OntologyID` of the
+/// first ontology that has one is used unless `ontology_id` is given. If `drop_merged_imports` is
+/// true, `Import`s of any of the merged ontologies are removed. A prefix bound to different IRIs
+/// keeps its first binding and a warning is emitted.
+#[pyfunction]
+fn merge_ontologies(
+    py: Python,
+    ontologies: Vec<PyRef<PyIndexedOntology>>,
+    ontology_id: Option<model::OntologyID>,
+    drop_merged_imports: Option<bool>,
+) -> PyResult<PyIndexedOntology> {
+    let mut merged = PyIndexedOntology::default();
+    let mut merged_iris = HashSet::new();
+
+    for onto in ontologies.iter() {
+        let (conflicts, iris) = merged.merge_from(onto);
+        merged_iris.extend(iris);
+
+        for prefix in conflicts {
+            PyErr::warn(
+                py,
+                py.get_type::<PyUserWarning>(),
+                &format!("Prefix '{}' is bound to different IRIs, keeping the first binding.", prefix),
+                0,
+            )?;
+        }
+    }
+
+    if let Some(ontology_id) = ontology_id {
+        merged.replace_ontology_id(ontology_id.into());
+    }
+    if drop_merged_imports.unwrap_or(false) {
+        merged.remove_imports(&merged_iris);
+    }
+
+    Ok(merged)
 }
 
 /// get_descendants(onto: PyIndexedOntology, parent: str) -> Set[str]
@@ -813,10 +2423,16 @@ fn recurse_ancestors(
 #[pymodule]
 fn pyhornedowl(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyIndexedOntology>()?;
+    m.add_class::<manager::OntologyManager>()?;
 
     m.add_function(wrap_pyfunction!(open_ontology, m)?)?;
+    m.add_function(wrap_pyfunction!(open_ontology_from_file, m)?)?;
+    m.add_function(wrap_pyfunction!(open_ontology_from_string, m)?)?;
+    m.add_function(wrap_pyfunction!(open_ontology_from_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(open_ontology_from_reader, m)?)?;
     m.add_function(wrap_pyfunction!(get_descendants, m)?)?;
     m.add_function(wrap_pyfunction!(get_ancestors, m)?)?;
+    m.add_function(wrap_pyfunction!(merge_ontologies, m)?)?;
 
     let model_sub_module = model::py_module(py)?;
     m.add_submodule(model_sub_module)?;
@@ -0,0 +1,335 @@
+use std::collections::{HashMap, HashSet};
+
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::catalog::Catalog;
+use crate::{load_ontology, PyIndexedOntology, SharedBuild};
+
+/// Holds several ontologies keyed by their ontology IRI.
+///
+/// All ontologies loaded through the manager intern their IRIs in one shared cache. Queries take
+/// the IRI of a member and are answered over its imports closure, i.e. the member itself and all
+/// members it imports directly or indirectly.
+#[pyclass]
+pub struct OntologyManager {
+    ontologies: HashMap<String, Py<PyIndexedOntology>>,
+    //Import and version IRIs that refer to a member stored under a different key
+    aliases: HashMap<String, String>,
+    build: SharedBuild,
+}
+
+impl OntologyManager {
+    fn key_for(&self, iri: &str) -> Option<String> {
+        if self.ontologies.contains_key(iri) {
+            Some(iri.to_string())
+        } else {
+            self.aliases.get(iri).cloned()
+        }
+    }
+
+    fn insert(&mut self, py: Python, key: String, mut ontology: PyIndexedOntology) -> PyResult<()> {
+        let viri = ontology
+            .get_ontology_id()
+            .and_then(|id| id.viri.as_ref())
+            .map(|viri| viri.to_string());
+        if let Some(viri) = viri {
+            self.aliases.insert(viri, key.clone());
+        }
+
+        self.ontologies.insert(key, Py::new(py, ontology)?);
+        Ok(())
+    }
+
+    /// Returns the members in the imports closure of `iri`, starting with the member itself.
+    /// Imports that are not members of the manager are skipped.
+    fn closure(&self, py: Python, iri: &str) -> PyResult<Vec<(String, Py<PyIndexedOntology>)>> {
+        let root = self
+            .key_for(iri)
+            .ok_or_else(|| PyKeyError::new_err(format!("Unknown ontology '{}'.", iri)))?;
+
+        let mut visited = HashSet::new();
+        let mut members = Vec::new();
+        let mut pending = vec![root];
+
+        while let Some(key) = pending.pop() {
+            if !visited.insert(key.clone()) {
+                continue;
+            }
+            if let Some(onto) = self.ontologies.get(&key) {
+                let imports = onto.try_borrow_mut(py)?.import_iris();
+                pending.extend(imports.iter().filter_map(|i| self.key_for(i)));
+                members.push((key, onto.clone_ref(py)));
+            }
+        }
+
+        Ok(members)
+    }
+
+    fn union_over_closure<F>(&self, py: Python, iri: &str, f: F) -> PyResult<HashSet<String>>
+    where
+        F: Fn(&mut PyIndexedOntology) -> PyResult<HashSet<String>>,
+    {
+        let mut result = HashSet::new();
+        for (_, onto) in self.closure(py, iri)?.iter() {
+            let mut o = onto.try_borrow_mut(py)?;
+            result.extend(f(&mut o)?);
+        }
+        Ok(result)
+    }
+
+    fn transitive_over_closure(
+        &self,
+        py: Python,
+        ontology: &str,
+        iri: String,
+        ancestors: bool,
+    ) -> PyResult<HashSet<String>> {
+        let closure = self.closure(py, ontology)?;
+
+        let mut result = HashSet::new();
+        let mut pending = vec![iri];
+        while let Some(cls) = pending.pop() {
+            if result.insert(cls.clone()) {
+                //Borrowed one at a time, as the same ontology may be a member under several keys
+                for (_, onto) in closure.iter() {
+                    let mut onto = onto.try_borrow_mut(py)?;
+                    let next = if ancestors {
+                        onto.get_superclasses(cls.clone())?
+                    } else {
+                        onto.get_subclasses(cls.clone())?
+                    };
+                    pending.extend(next);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[pymethods]
+impl OntologyManager {
+    #[new]
+    fn new() -> Self {
+        OntologyManager {
+            ontologies: Default::default(),
+            aliases: Default::default(),
+            build: Default::default(),
+        }
+    }
+
+    /// load(self, ontology: str, resolve_imports: Optional[bool], catalog: Optional[str]) -> str
+    ///
+    /// Loads an ontology from a path or plain text and adds it to the manager.
+    ///
+    /// If `resolve_imports` is true, the imports closure is loaded as separate members, resolving
+    /// import IRIs through `catalog` as in `open_ontology`. Imports that are already members are not
+    /// loaded again.
+    ///
+    /// Returns the key of the loaded ontology, which is its ontology IRI or, if it has none, `ontology`.
+    fn load(
+        &mut self,
+        py: Python,
+        ontology: String,
+        resolve_imports: Option<bool>,
+        catalog: Option<String>,
+    ) -> PyResult<String> {
+        let catalog = if resolve_imports.unwrap_or(false) {
+            Some(Catalog::for_ontology(&ontology, catalog)?)
+        } else {
+            None
+        };
+
+        let mut loaded = load_ontology(&ontology, &self.build.lock())?;
+        loaded.build = self.build.clone();
+        let key = loaded.ontology_iri().unwrap_or_else(|| ontology.clone());
+        let mut pending = loaded.import_iris();
+        self.insert(py, key.clone(), loaded)?;
+
+        if let Some(catalog) = catalog {
+            while let Some(import) = pending.pop() {
+                if self.key_for(&import).is_some() {
+                    continue;
+                }
+
+                let path = catalog.resolve(&import).ok_or_else(|| {
+                    PyValueError::new_err(format!("Unable to resolve import '{}' to a local file.", import))
+                })?;
+                let mut imported = load_ontology(&path.to_string_lossy(), &self.build.lock())?;
+                imported.build = self.build.clone();
+                pending.extend(imported.import_iris());
+
+                let imported_key = imported.ontology_iri().unwrap_or_else(|| import.clone());
+                if imported_key != import {
+                    self.aliases.insert(import, imported_key.clone());
+                }
+                self.insert(py, imported_key, imported)?;
+            }
+        }
+
+        Ok(key)
+    }
+
+    /// add(self, ontology: PyIndexedOntology, iri: Optional[str]) -> str
+    ///
+    /// Adds an already loaded ontology to the manager.
+    ///
+    /// The ontology is stored under `iri` or, if not given, its ontology IRI. The ontology is shared
+    /// with the caller, so later changes to it are visible to the manager. Like ontologies loaded
+    /// through the manager, it then shares the manager's IRI cache. Returns the key.
+    fn add(&mut self, py: Python, ontology: Py<PyIndexedOntology>, iri: Option<String>) -> PyResult<String> {
+        let key = match iri {
+            Some(iri) => iri,
+            None => ontology.try_borrow_mut(py)?.ontology_iri().ok_or_else(|| {
+                PyValueError::new_err("The ontology has no IRI, pass one explicitly.")
+            })?,
+        };
+
+        let viri = ontology
+            .try_borrow_mut(py)?
+            .get_ontology_id()
+            .and_then(|id| id.viri.as_ref())
+            .map(|viri| viri.to_string());
+        if let Some(viri) = viri {
+            self.aliases.insert(viri, key.clone());
+        }
+
+        ontology.try_borrow_mut(py)?.build = self.build.clone();
+        self.ontologies.insert(key.clone(), ontology);
+        Ok(key)
+    }
+
+    /// get(self, iri: str) -> PyIndexedOntology
+    ///
+    /// Returns the member with the given ontology IRI, version IRI or import IRI.
+    fn get(&self, py: Python, iri: String) -> PyResult<Py<PyIndexedOntology>> {
+        self.key_for(&iri)
+            .and_then(|key| self.ontologies.get(&key))
+            .map(|onto| onto.clone_ref(py))
+            .ok_or_else(|| PyKeyError::new_err(format!("Unknown ontology '{}'.", iri)))
+    }
+
+    /// remove(self, iri: str) -> None
+    ///
+    /// Removes a member from the manager.
+    fn remove(&mut self, iri: String) -> PyResult<()> {
+        let key = self
+            .key_for(&iri)
+            .ok_or_else(|| PyKeyError::new_err(format!("Unknown ontology '{}'.", iri)))?;
+
+        self.ontologies.remove(&key);
+        self.aliases.retain(|_, k| *k != key);
+        Ok(())
+    }
+
+    /// get_ontology_iris(self) -> Set[str]
+    ///
+    /// Returns the keys of all members.
+    fn get_ontology_iris(&self) -> HashSet<String> {
+        self.ontologies.keys().cloned().collect()
+    }
+
+    /// get_imports(self, iri: str) -> Set[str]
+    ///
+    /// Returns the IRIs directly imported by a member, whether or not they are members themselves.
+    fn get_imports(&self, py: Python, iri: String) -> PyResult<HashSet<String>> {
+        let onto = self.get(py, iri)?;
+        let imports = onto.try_borrow_mut(py)?.import_iris();
+        Ok(imports.into_iter().collect())
+    }
+
+    /// get_imports_closure(self, iri: str) -> List[str]
+    ///
+    /// Returns the keys of the members in the imports closure of a member, starting with the member itself.
+    fn get_imports_closure(&self, py: Python, iri: String) -> PyResult<Vec<String>> {
+        let closure = self.closure(py, &iri)?;
+        Ok(closure.into_iter().map(|(key, _)| key).collect())
+    }
+
+    /// get_classes(self, iri: str) -> Set[str]
+    ///
+    /// Returns the IRIs of all classes declared in the imports closure of a member.
+    fn get_classes(&self, py: Python, iri: String) -> PyResult<HashSet<String>> {
+        self.union_over_closure(py, &iri, |onto| onto.get_classes())
+    }
+
+    /// get_object_properties(self, iri: str) -> Set[str]
+    ///
+    /// Returns the IRIs of all object properties declared in the imports closure of a member.
+    fn get_object_properties(&self, py: Python, iri: String) -> PyResult<HashSet<String>> {
+        self.union_over_closure(py, &iri, |onto| onto.get_object_properties())
+    }
+
+    /// get_data_properties(self, iri: str) -> Set[str]
+    ///
+    /// Returns the IRIs of all data properties declared in the imports closure of a member.
+    fn get_data_properties(&self, py: Python, iri: String) -> PyResult<HashSet<String>> {
+        self.union_over_closure(py, &iri, |onto| onto.get_data_properties())
+    }
+
+    /// get_axioms(self, iri: str) -> List[model.AnnotatedComponent]
+    ///
+    /// Returns all axioms in the imports closure of a member.
+    fn get_axioms(&self, py: Python, iri: String) -> PyResult<Vec<PyObject>> {
+        let mut axioms = Vec::new();
+        for (_, onto) in self.closure(py, &iri)?.iter() {
+            axioms.extend(onto.try_borrow_mut(py)?.get_axioms(py)?);
+        }
+        Ok(axioms)
+    }
+
+    /// get_axioms_for_iri(self, ontology: str, iri: str) -> List[model.AnnotatedComponent]
+    ///
+    /// Gets all axioms for an entity in the imports closure of a member.
+    fn get_axioms_for_iri(&self, py: Python, ontology: String, iri: String) -> PyResult<Vec<PyObject>> {
+        let mut axioms = Vec::new();
+        for (_, onto) in self.closure(py, &ontology)?.iter() {
+            axioms.extend(onto.try_borrow_mut(py)?.get_axioms_for_iri(py, iri.clone())?);
+        }
+        Ok(axioms)
+    }
+
+    /// get_iri_for_label(self, ontology: str, label: str) -> Optional[str]
+    ///
+    /// Returns the IRI of a term by its label in the imports closure of a member.
+    ///
+    /// If no member in the closure has a term with this label, `None` is returned.
+    fn get_iri_for_label(&self, py: Python, ontology: String, label: String) -> PyResult<PyObject> {
+        for (_, onto) in self.closure(py, &ontology)?.iter() {
+            let iri = onto.try_borrow_mut(py)?.get_iri_for_label(py, label.clone())?;
+            if !iri.is_none(py) {
+                return Ok(iri);
+            }
+        }
+        Ok(().to_object(py))
+    }
+
+    /// get_subclasses(self, ontology: str, iri: str) -> Set[str]
+    ///
+    /// Gets all direct subclasses of a class asserted in the imports closure of a member.
+    fn get_subclasses(&self, py: Python, ontology: String, iri: String) -> PyResult<HashSet<String>> {
+        self.union_over_closure(py, &ontology, |onto| onto.get_subclasses(iri.clone()))
+    }
+
+    /// get_superclasses(self, ontology: str, iri: str) -> Set[str]
+    ///
+    /// Gets all direct superclasses of a class asserted in the imports closure of a member.
+    fn get_superclasses(&self, py: Python, ontology: String, iri: String) -> PyResult<HashSet<String>> {
+        self.union_over_closure(py, &ontology, |onto| onto.get_superclasses(iri.clone()))
+    }
+
+    /// get_descendants(self, ontology: str, iri: str) -> Set[str]
+    ///
+    /// Gets all direct and indirect subclasses of a class in the imports closure of a member.
+    fn get_descendants(&self, py: Python, ontology: String, iri: String) -> PyResult<HashSet<String>> {
+        self.transitive_over_closure(py, &ontology, iri, false)
+    }
+
+    /// get_ancestors(self, ontology: str, iri: str) -> Set[str]
+    ///
+    /// Gets all direct and indirect superclasses of a class in the imports closure of a member.
+    fn get_ancestors(&self, py: Python, ontology: String, iri: String) -> PyResult<HashSet<String>> {
+        self.transitive_over_closure(py, &ontology, iri, true)
+    }
+}
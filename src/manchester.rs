@@ -0,0 +1,1325 @@
+use std::collections::{HashMap, HashSet};
+
+use curie::{Curie, PrefixMapping};
+use horned_owl::model::*;
+use horned_owl::vocab::{Facet, OWL};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{SharedBuild, RDFS_LITERAL, XSD_NS};
+
+const KEYWORDS: &[&str] = &[
+    "some", "only", "value", "min", "max", "exactly", "and", "or", "not", "that", "inverse", "Self",
+];
+
+const FRAME_KEYWORDS: &[&str] = &[
+    "SubClassOf", "EquivalentTo", "DisjointWith", "DisjointUnionOf", "HasKey", "SubPropertyOf",
+    "SubPropertyChain", "InverseOf", "Domain", "Range", "Characteristics", "Types", "Facts",
+    "SameAs", "DifferentFrom", "Annotations",
+];
+
+/// Datatypes that may be written without a prefix.
+const XSD_DATATYPES: &[&str] = &[
+    "string", "integer", "int", "long", "short", "byte", "decimal", "float", "double", "boolean",
+    "dateTime", "date", "anyURI", "nonNegativeInteger", "positiveInteger", "nonPositiveInteger",
+    "negativeInteger",
+];
+
+/// Resolves the names used in Manchester syntax to IRIs.
+///
+/// A name is a full IRI in angle brackets, a label (in single quotes if it contains spaces) or a
+/// CURIE expanded with the prefix mapping of the ontology.
+pub struct Resolver<'a> {
+    pub labels: &'a HashMap<String, IRI<ArcStr>>,
+    pub mapping: &'a PrefixMapping,
+    pub build: &'a SharedBuild,
+    pub object_properties: HashSet<IRI<ArcStr>>,
+    pub data_properties: HashSet<IRI<ArcStr>>,
+    pub annotation_properties: HashSet<IRI<ArcStr>>,
+    pub datatypes: HashSet<IRI<ArcStr>>,
+}
+
+impl<'a> Resolver<'a> {
+    fn resolve(&self, name: &str) -> Option<IRI<ArcStr>> {
+        if let Some(iri) = self.labels.get(name) {
+            return Some(iri.clone());
+        }
+
+        let (prefix, reference) = name.split_once(':')?;
+        let prefix = if prefix.is_empty() { None } else { Some(prefix) };
+        self.mapping
+            .expand_curie(&Curie::new(prefix, reference))
+            .ok()
+            .map(|iri| self.build.iri(iri))
+    }
+
+    fn property_kind(&self, iri: &IRI<ArcStr>) -> Option<PropertyKind> {
+        if self.object_properties.contains(iri) {
+            Some(PropertyKind::Object)
+        } else if self.data_properties.contains(iri) {
+            Some(PropertyKind::Data)
+        } else if self.annotation_properties.contains(iri) {
+            Some(PropertyKind::Annotation)
+        } else {
+            None
+        }
+    }
+
+    fn resolve_datatype(&self, name: &str) -> Option<IRI<ArcStr>> {
+        if XSD_DATATYPES.contains(&name) {
+            return Some(self.build.iri(format!("{}{}", XSD_NS, name)));
+        }
+        if name == "Literal" {
+            return Some(self.build.iri(RDFS_LITERAL));
+        }
+        if let Some(local) = name.strip_prefix("xsd:") {
+            return Some(self.build.iri(format!("{}{}", XSD_NS, local)));
+        }
+
+        self.resolve(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PropertyKind {
+    Object,
+    Data,
+    Annotation,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    FullIRI(String),
+    Str(String),
+    Lang(String),
+    DatatypeMarker,
+    Punct(char),
+}
+
+fn tokenize(text: &str) -> PyResult<Vec<(usize, Token)>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    let delimited = |start: usize, end: char| -> PyResult<(String, usize)> {
+        let mut value = String::new();
+        let mut j = start + 1;
+        while j < chars.len() && chars[j] != end {
+            if chars[j] == '\\' && j + 1 < chars.len() {
+                j += 1;
+            }
+            value.push(chars[j]);
+            j += 1;
+        }
+        if j >= chars.len() {
+            return Err(syntax_error(start, &format!("unterminated {}", chars[start])));
+        }
+        Ok((value, j + 1))
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let token = match c {
+            '(' | ')' | '{' | '}' | '[' | ']' | ',' => {
+                i += 1;
+                Token::Punct(c)
+            }
+            '\'' => {
+                let (value, next) = delimited(i, '\'')?;
+                i = next;
+                Token::Quoted(value)
+            }
+            '"' => {
+                let (value, next) = delimited(i, '"')?;
+                i = next;
+                Token::Str(value)
+            }
+            '<' if i + 1 < chars.len() && !chars[i + 1].is_whitespace() && chars[i + 1] != '=' => {
+                let (value, next) = delimited(i, '>')?;
+                i = next;
+                Token::FullIRI(value)
+            }
+            '@' => {
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '-') {
+                    i += 1;
+                }
+                Token::Lang(chars[start + 1..i].iter().collect())
+            }
+            '^' if i + 1 < chars.len() && chars[i + 1] == '^' => {
+                i += 2;
+                Token::DatatypeMarker
+            }
+            _ => {
+                let delimiter = |c: char| c.is_whitespace() || "(){}[],'\"".contains(c);
+                while i < chars.len() && !delimiter(chars[i]) && (i == start || chars[i] != '^') {
+                    i += 1;
+                }
+                Token::Word(chars[start..i].iter().collect())
+            }
+        };
+        tokens.push((start, token));
+    }
+
+    Ok(tokens)
+}
+
+fn syntax_error(position: usize, message: &str) -> PyErr {
+    PyValueError::new_err(format!(
+        "Invalid Manchester syntax at position {}: {}",
+        position, message
+    ))
+}
+
+/// Returns the keyword of an axiom, which may be followed by a colon as in frames.
+fn frame_keyword(word: &str) -> Option<&str> {
+    let keyword = word.strip_suffix(':').unwrap_or(word);
+    if FRAME_KEYWORDS.contains(&keyword) {
+        Some(keyword)
+    } else {
+        None
+    }
+}
+
+fn is_keyword(token: &Token) -> bool {
+    matches!(token, Token::Word(w) if KEYWORDS.contains(&w.as_str()))
+}
+
+/// Recursive descent parser for Manchester syntax.
+pub struct Parser<'a> {
+    tokens: Vec<(usize, Token)>,
+    position: usize,
+    end: usize,
+    resolver: &'a Resolver<'a>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(text: &str, resolver: &'a Resolver<'a>) -> PyResult<Parser<'a>> {
+        Ok(Parser {
+            tokens: tokenize(text)?,
+            position: 0,
+            end: text.len(),
+            resolver,
+        })
+    }
+
+    /// Fails unless all input has been consumed.
+    pub fn finish(&self) -> PyResult<()> {
+        match self.tokens.get(self.position) {
+            None => Ok(()),
+            Some((offset, token)) => Err(syntax_error(*offset, &format!("unexpected {:?}", token))),
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(_, t)| t)
+    }
+
+    fn peek_word(&self) -> Option<&str> {
+        match self.peek() {
+            Some(Token::Word(w)) => Some(w.as_str()),
+            _ => None,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map(|(o, _)| *o)
+            .unwrap_or(self.end)
+    }
+
+    fn error<T>(&self, message: &str) -> PyResult<T> {
+        Err(syntax_error(self.offset(), message))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).map(|(_, t)| t.clone());
+        self.position += 1;
+        token
+    }
+
+    fn accept_word(&mut self, word: &str) -> bool {
+        if self.peek_word() == Some(word) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn accept_punct(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_punct(&mut self, c: char) -> PyResult<()> {
+        if self.accept_punct(c) {
+            Ok(())
+        } else {
+            self.error(&format!("expected '{}'", c))
+        }
+    }
+
+    /// Parses a name and resolves it with `resolve`.
+    ///
+    /// Unquoted labels may consist of several words, the longest sequence of words forming a
+    /// known name is used.
+    fn name_with(
+        &mut self,
+        resolve: impl Fn(&Resolver, &str) -> Option<IRI<ArcStr>>,
+    ) -> PyResult<IRI<ArcStr>> {
+        match self.peek().cloned() {
+            Some(Token::FullIRI(iri)) => {
+                self.position += 1;
+                Ok(self.resolver.build.iri(iri))
+            }
+            Some(Token::Quoted(name)) => match resolve(self.resolver, &name) {
+                Some(iri) => {
+                    self.position += 1;
+                    Ok(iri)
+                }
+                None => self.error(&format!("unknown entity '{}'", name)),
+            },
+            Some(Token::Word(_)) if !self.peek().is_some_and(is_keyword) => {
+                let words: Vec<String> = self.tokens[self.position..]
+                    .iter()
+                    .map(|(_, t)| t)
+                    .take_while(|t| matches!(t, Token::Word(w) if frame_keyword(w).is_none()) && !is_keyword(t))
+                    .map(|t| match t {
+                        Token::Word(w) => w.clone(),
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                if words.is_empty() {
+                    return self.error("expected a name");
+                }
+
+                for n in (1..=words.len()).rev() {
+                    if let Some(iri) = resolve(self.resolver, &words[..n].join(" ")) {
+                        self.position += n;
+                        return Ok(iri);
+                    }
+                }
+                self.error(&format!("unknown entity '{}'", words[0]))
+            }
+            _ => self.error("expected a name"),
+        }
+    }
+
+    fn name(&mut self) -> PyResult<IRI<ArcStr>> {
+        self.name_with(|r, n| r.resolve(n))
+    }
+
+    /// Parses a class expression.
+    pub fn class_expression(&mut self) -> PyResult<ClassExpression<ArcStr>> {
+        let mut operands = vec![self.conjunction()?];
+        while self.accept_word("or") {
+            operands.push(self.conjunction()?);
+        }
+
+        Ok(if operands.len() == 1 {
+            operands.pop().unwrap()
+        } else {
+            ClassExpression::ObjectUnionOf(operands)
+        })
+    }
+
+    fn conjunction(&mut self) -> PyResult<ClassExpression<ArcStr>> {
+        let mut operands = vec![self.primary()?];
+        while self.accept_word("and") || self.accept_word("that") {
+            operands.push(self.primary()?);
+        }
+
+        Ok(if operands.len() == 1 {
+            operands.pop().unwrap()
+        } else {
+            ClassExpression::ObjectIntersectionOf(operands)
+        })
+    }
+
+    fn primary(&mut self) -> PyResult<ClassExpression<ArcStr>> {
+        if self.accept_word("not") {
+            return Ok(ClassExpression::ObjectComplementOf(Box::new(self.primary()?)));
+        }
+        if self.accept_punct('(') {
+            let ce = self.class_expression()?;
+            self.expect_punct(')')?;
+            return Ok(ce);
+        }
+        if self.accept_punct('{') {
+            let mut individuals = vec![self.individual()?];
+            while self.accept_punct(',') {
+                individuals.push(self.individual()?);
+            }
+            self.expect_punct('}')?;
+            return Ok(ClassExpression::ObjectOneOf(individuals));
+        }
+        if self.peek_word() == Some("inverse") {
+            let ope = self.object_property_expression()?;
+            return self.object_restriction(ope);
+        }
+
+        let iri = self.name()?;
+        match self.peek_word() {
+            Some("some" | "only" | "value" | "min" | "max" | "exactly" | "Self") => {
+                if self.resolver.data_properties.contains(&iri) {
+                    self.data_restriction(DataProperty(iri))
+                } else {
+                    self.object_restriction(ObjectPropertyExpression::ObjectProperty(ObjectProperty(iri)))
+                }
+            }
+            _ => Ok(ClassExpression::Class(Class(iri))),
+        }
+    }
+
+    /// Parses an object property or an inverse object property.
+    pub fn object_property_expression(&mut self) -> PyResult<ObjectPropertyExpression<ArcStr>> {
+        if self.accept_word("inverse") {
+            let parenthesised = self.accept_punct('(');
+            let ope = self.object_property_expression()?;
+            if parenthesised {
+                self.expect_punct(')')?;
+            }
+            return Ok(match ope {
+                ObjectPropertyExpression::ObjectProperty(op) => {
+                    ObjectPropertyExpression::InverseObjectProperty(op)
+                }
+                ObjectPropertyExpression::InverseObjectProperty(op) => {
+                    ObjectPropertyExpression::ObjectProperty(op)
+                }
+            });
+        }
+
+        Ok(ObjectPropertyExpression::ObjectProperty(ObjectProperty(self.name()?)))
+    }
+
+    fn cardinality(&mut self) -> PyResult<u32> {
+        match self.peek_word().map(str::parse::<u32>) {
+            Some(Ok(n)) => {
+                self.position += 1;
+                Ok(n)
+            }
+            _ => self.error("expected a non-negative integer"),
+        }
+    }
+
+    /// Whether the next token can start a class expression or data range.
+    fn at_operand(&self) -> bool {
+        match self.peek() {
+            None => false,
+            Some(Token::Punct('(' | '{')) => true,
+            Some(Token::Punct(_)) => false,
+            Some(Token::Word(w)) if frame_keyword(w).is_some() => false,
+            Some(Token::Word(w)) => !KEYWORDS.contains(&w.as_str()) || w == "not" || w == "inverse",
+            _ => true,
+        }
+    }
+
+    fn object_restriction(
+        &mut self,
+        ope: ObjectPropertyExpression<ArcStr>,
+    ) -> PyResult<ClassExpression<ArcStr>> {
+        let keyword = match self.next() {
+            Some(Token::Word(w)) => w,
+            _ => return self.error("expected a restriction"),
+        };
+
+        Ok(match keyword.as_str() {
+            "some" => ClassExpression::ObjectSomeValuesFrom {
+                ope,
+                bce: Box::new(self.primary()?),
+            },
+            "only" => ClassExpression::ObjectAllValuesFrom {
+                ope,
+                bce: Box::new(self.primary()?),
+            },
+            "value" => ClassExpression::ObjectHasValue {
+                ope,
+                i: self.individual()?,
+            },
+            "Self" => ClassExpression::ObjectHasSelf(ope),
+            "min" | "max" | "exactly" => {
+                let n = self.cardinality()?;
+                let bce = Box::new(if self.at_operand() {
+                    self.primary()?
+                } else {
+                    ClassExpression::Class(Class(self.resolver.build.iri(OWL::Thing.as_ref())))
+                });
+                match keyword.as_str() {
+                    "min" => ClassExpression::ObjectMinCardinality { n, ope, bce },
+                    "max" => ClassExpression::ObjectMaxCardinality { n, ope, bce },
+                    _ => ClassExpression::ObjectExactCardinality { n, ope, bce },
+                }
+            }
+            _ => return self.error("expected a restriction"),
+        })
+    }
+
+    fn data_restriction(&mut self, dp: DataProperty<ArcStr>) -> PyResult<ClassExpression<ArcStr>> {
+        let keyword = match self.next() {
+            Some(Token::Word(w)) => w,
+            _ => return self.error("expected a restriction"),
+        };
+
+        Ok(match keyword.as_str() {
+            "some" => ClassExpression::DataSomeValuesFrom {
+                dp,
+                dr: self.data_primary()?,
+            },
+            "only" => ClassExpression::DataAllValuesFrom {
+                dp,
+                dr: self.data_primary()?,
+            },
+            "value" => ClassExpression::DataHasValue {
+                dp,
+                l: self.literal()?,
+            },
+            "min" | "max" | "exactly" => {
+                let n = self.cardinality()?;
+                let dr = if self.at_operand() {
+                    self.data_primary()?
+                } else {
+                    DataRange::Datatype(Datatype(self.resolver.build.iri(RDFS_LITERAL)))
+                };
+                match keyword.as_str() {
+                    "min" => ClassExpression::DataMinCardinality { n, dp, dr },
+                    "max" => ClassExpression::DataMaxCardinality { n, dp, dr },
+                    _ => ClassExpression::DataExactCardinality { n, dp, dr },
+                }
+            }
+            _ => return self.error("expected a data restriction"),
+        })
+    }
+
+    /// Parses a named individual.
+    pub fn individual(&mut self) -> PyResult<Individual<ArcStr>> {
+        Ok(Individual::Named(NamedIndividual(self.name()?)))
+    }
+
+    /// Parses a data range.
+    pub fn data_range(&mut self) -> PyResult<DataRange<ArcStr>> {
+        let mut operands = vec![self.data_conjunction()?];
+        while self.accept_word("or") {
+            operands.push(self.data_conjunction()?);
+        }
+
+        Ok(if operands.len() == 1 {
+            operands.pop().unwrap()
+        } else {
+            DataRange::DataUnionOf(operands)
+        })
+    }
+
+    fn data_conjunction(&mut self) -> PyResult<DataRange<ArcStr>> {
+        let mut operands = vec![self.data_primary()?];
+        while self.accept_word("and") {
+            operands.push(self.data_primary()?);
+        }
+
+        Ok(if operands.len() == 1 {
+            operands.pop().unwrap()
+        } else {
+            DataRange::DataIntersectionOf(operands)
+        })
+    }
+
+    fn data_primary(&mut self) -> PyResult<DataRange<ArcStr>> {
+        if self.accept_word("not") {
+            return Ok(DataRange::DataComplementOf(Box::new(self.data_primary()?)));
+        }
+        if self.accept_punct('(') {
+            let dr = self.data_range()?;
+            self.expect_punct(')')?;
+            return Ok(dr);
+        }
+        if self.accept_punct('{') {
+            let mut literals = vec![self.literal()?];
+            while self.accept_punct(',') {
+                literals.push(self.literal()?);
+            }
+            self.expect_punct('}')?;
+            return Ok(DataRange::DataOneOf(literals));
+        }
+
+        let datatype = Datatype(self.name_with(|r, n| r.resolve_datatype(n))?);
+        if !self.accept_punct('[') {
+            return Ok(DataRange::Datatype(datatype));
+        }
+
+        let mut restrictions = vec![self.facet_restriction()?];
+        while self.accept_punct(',') {
+            restrictions.push(self.facet_restriction()?);
+        }
+        self.expect_punct(']')?;
+
+        Ok(DataRange::DatatypeRestriction(datatype, restrictions))
+    }
+
+    fn facet_restriction(&mut self) -> PyResult<FacetRestriction<ArcStr>> {
+        let f = match self.peek_word() {
+            Some("length") => Facet::Length,
+            Some("minLength") => Facet::MinLength,
+            Some("maxLength") => Facet::MaxLength,
+            Some("pattern") => Facet::Pattern,
+            Some("langRange") => Facet::LangRange,
+            Some("totalDigits") => Facet::TotalDigits,
+            Some("fractionDigits") => Facet::FractionDigits,
+            Some("<=") => Facet::MaxInclusive,
+            Some("<") => Facet::MaxExclusive,
+            Some(">=") => Facet::MinInclusive,
+            Some(">") => Facet::MinExclusive,
+            _ => return self.error("expected a facet"),
+        };
+        self.position += 1;
+
+        Ok(FacetRestriction {
+            f,
+            l: self.literal()?,
+        })
+    }
+
+    /// Parses a literal.
+    ///
+    /// Besides quoted strings with an optional language tag or datatype, integers, decimals,
+    /// floats and booleans may be written directly.
+    pub fn literal(&mut self) -> PyResult<Literal<ArcStr>> {
+        let xsd = |r: &Resolver, local: &str| r.build.iri(format!("{}{}", XSD_NS, local));
+
+        match self.peek().cloned() {
+            Some(Token::Str(literal)) => {
+                self.position += 1;
+                match self.peek().cloned() {
+                    Some(Token::Lang(lang)) => {
+                        self.position += 1;
+                        Ok(Literal::Language { literal, lang })
+                    }
+                    Some(Token::DatatypeMarker) => {
+                        self.position += 1;
+                        let datatype_iri = self.name_with(|r, n| r.resolve_datatype(n))?;
+                        Ok(Literal::Datatype {
+                            literal,
+                            datatype_iri,
+                        })
+                    }
+                    _ => Ok(Literal::Simple { literal }),
+                }
+            }
+            Some(Token::Word(word)) => {
+                let datatype = if word == "true" || word == "false" {
+                    "boolean"
+                } else if word.parse::<i64>().is_ok() {
+                    "integer"
+                } else if word.ends_with(['f', 'F']) && word[..word.len() - 1].parse::<f64>().is_ok() {
+                    "float"
+                } else if word.contains(['e', 'E']) && word.parse::<f64>().is_ok() {
+                    "double"
+                } else if word.parse::<f64>().is_ok() {
+                    "decimal"
+                } else {
+                    return self.error("expected a literal");
+                };
+                self.position += 1;
+
+                let literal = if datatype == "float" {
+                    word[..word.len() - 1].to_string()
+                } else {
+                    word
+                };
+                Ok(Literal::Datatype {
+                    literal,
+                    datatype_iri: xsd(self.resolver, datatype),
+                })
+            }
+            _ => self.error("expected a literal"),
+        }
+    }
+}
+
+impl<'a> Parser<'a> {
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> PyResult<T>) -> PyResult<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.accept_punct(',') {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Parses a single axiom or declaration.
+    ///
+    /// Axioms are written like one line of a Manchester syntax frame, with the subject followed by
+    /// the keyword of the section, e.g. `A SubClassOf: B`, `p Characteristics: Transitive`,
+    /// `i Facts: p j` or `A Annotations: rdfs:label "a"`. Declarations are written `Class: A`.
+    pub fn component(&mut self) -> PyResult<Component<ArcStr>> {
+        if let Some(declaration) = self.declaration()? {
+            return Ok(declaration);
+        }
+
+        let subject = self.class_expression()?;
+        let keyword = match self.peek_word().and_then(frame_keyword) {
+            Some(keyword) => keyword.to_string(),
+            None => return self.error("expected an axiom keyword such as 'SubClassOf:'"),
+        };
+        self.position += 1;
+
+        let iri = match subject {
+            ClassExpression::Class(Class(iri)) => iri,
+            subject => return self.class_axiom(subject, &keyword),
+        };
+
+        match keyword.as_str() {
+            "Types" | "Facts" | "SameAs" | "DifferentFrom" => return self.individual_axiom(iri, &keyword),
+            "Annotations" => return self.annotation_assertion(iri),
+            "EquivalentTo" if self.resolver.datatypes.contains(&iri) => {
+                return Ok(Component::DatatypeDefinition(DatatypeDefinition {
+                    kind: Datatype(iri),
+                    range: self.data_range()?,
+                }))
+            }
+            _ => (),
+        }
+
+        match self.resolver.property_kind(&iri) {
+            Some(PropertyKind::Data) => self.data_property_axiom(DataProperty(iri), &keyword),
+            Some(PropertyKind::Annotation) => {
+                self.annotation_property_axiom(AnnotationProperty(iri), &keyword)
+            }
+            Some(PropertyKind::Object) => self.object_property_axiom(ObjectProperty(iri), &keyword),
+            None => match keyword.as_str() {
+                "SubClassOf" | "EquivalentTo" | "DisjointWith" | "DisjointUnionOf" | "HasKey" => {
+                    self.class_axiom(ClassExpression::Class(Class(iri)), &keyword)
+                }
+                _ => self.object_property_axiom(ObjectProperty(iri), &keyword),
+            },
+        }
+    }
+
+    fn declaration(&mut self) -> PyResult<Option<Component<ArcStr>>> {
+        let keyword = match self.peek_word() {
+            Some(
+                keyword @ ("Class:" | "ObjectProperty:" | "DataProperty:" | "AnnotationProperty:"
+                | "Individual:" | "Datatype:"),
+            ) => keyword.to_string(),
+            _ => return Ok(None),
+        };
+        self.position += 1;
+        let iri = self.name()?;
+
+        Ok(Some(match keyword.as_str() {
+            "Class:" => Component::DeclareClass(DeclareClass(Class(iri))),
+            "ObjectProperty:" => Component::DeclareObjectProperty(DeclareObjectProperty(ObjectProperty(iri))),
+            "DataProperty:" => Component::DeclareDataProperty(DeclareDataProperty(DataProperty(iri))),
+            "AnnotationProperty:" => {
+                Component::DeclareAnnotationProperty(DeclareAnnotationProperty(AnnotationProperty(iri)))
+            }
+            "Individual:" => Component::DeclareNamedIndividual(DeclareNamedIndividual(NamedIndividual(iri))),
+            _ => Component::DeclareDatatype(DeclareDatatype(Datatype(iri))),
+        }))
+    }
+
+    fn class_axiom(&mut self, subject: ClassExpression<ArcStr>, keyword: &str) -> PyResult<Component<ArcStr>> {
+        Ok(match keyword {
+            "SubClassOf" => Component::SubClassOf(SubClassOf {
+                sub: subject,
+                sup: self.class_expression()?,
+            }),
+            "EquivalentTo" | "DisjointWith" => {
+                let mut ces = vec![subject];
+                ces.extend(self.list(Self::class_expression)?);
+                if keyword == "EquivalentTo" {
+                    Component::EquivalentClasses(EquivalentClasses(ces))
+                } else {
+                    Component::DisjointClasses(DisjointClasses(ces))
+                }
+            }
+            "DisjointUnionOf" => match subject {
+                ClassExpression::Class(c) => {
+                    Component::DisjointUnion(DisjointUnion(c, self.list(Self::class_expression)?))
+                }
+                _ => return self.error("the subject of a disjoint union must be a class"),
+            },
+            "HasKey" => Component::HasKey(HasKey {
+                ce: subject,
+                vpe: self.list(Self::property_expression)?,
+            }),
+            _ => return self.error(&format!("'{}' cannot be used with a class expression", keyword)),
+        })
+    }
+
+    fn property_expression(&mut self) -> PyResult<PropertyExpression<ArcStr>> {
+        if self.peek_word() == Some("inverse") {
+            return Ok(PropertyExpression::ObjectPropertyExpression(
+                self.object_property_expression()?,
+            ));
+        }
+
+        let iri = self.name()?;
+        Ok(match self.resolver.property_kind(&iri) {
+            Some(PropertyKind::Data) => PropertyExpression::DataProperty(DataProperty(iri)),
+            Some(PropertyKind::Annotation) => PropertyExpression::AnnotationProperty(AnnotationProperty(iri)),
+            _ => PropertyExpression::ObjectPropertyExpression(ObjectPropertyExpression::ObjectProperty(
+                ObjectProperty(iri),
+            )),
+        })
+    }
+
+    fn object_property_axiom(&mut self, op: ObjectProperty<ArcStr>, keyword: &str) -> PyResult<Component<ArcStr>> {
+        let ope = ObjectPropertyExpression::ObjectProperty(op.clone());
+
+        Ok(match keyword {
+            "SubPropertyOf" => Component::SubObjectPropertyOf(SubObjectPropertyOf {
+                sub: SubObjectPropertyExpression::ObjectPropertyExpression(ope),
+                sup: self.object_property_expression()?,
+            }),
+            "SubPropertyChain" => {
+                let mut chain = vec![self.object_property_expression()?];
+                while self.accept_word("o") {
+                    chain.push(self.object_property_expression()?);
+                }
+                Component::SubObjectPropertyOf(SubObjectPropertyOf {
+                    sub: SubObjectPropertyExpression::ObjectPropertyChain(chain),
+                    sup: ope,
+                })
+            }
+            "EquivalentTo" | "DisjointWith" => {
+                let mut opes = vec![ope];
+                opes.extend(self.list(Self::object_property_expression)?);
+                if keyword == "EquivalentTo" {
+                    Component::EquivalentObjectProperties(EquivalentObjectProperties(opes))
+                } else {
+                    Component::DisjointObjectProperties(DisjointObjectProperties(opes))
+                }
+            }
+            "InverseOf" => Component::InverseObjectProperties(InverseObjectProperties(
+                op,
+                ObjectProperty(self.name()?),
+            )),
+            "Domain" => Component::ObjectPropertyDomain(ObjectPropertyDomain {
+                ope,
+                ce: self.class_expression()?,
+            }),
+            "Range" => Component::ObjectPropertyRange(ObjectPropertyRange {
+                ope,
+                ce: self.class_expression()?,
+            }),
+            "Characteristics" => match self.next() {
+                Some(Token::Word(c)) => match c.as_str() {
+                    "Functional" => Component::FunctionalObjectProperty(FunctionalObjectProperty(ope)),
+                    "InverseFunctional" => {
+                        Component::InverseFunctionalObjectProperty(InverseFunctionalObjectProperty(ope))
+                    }
+                    "Reflexive" => Component::ReflexiveObjectProperty(ReflexiveObjectProperty(ope)),
+                    "Irreflexive" => Component::IrreflexiveObjectProperty(IrreflexiveObjectProperty(ope)),
+                    "Symmetric" => Component::SymmetricObjectProperty(SymmetricObjectProperty(ope)),
+                    "Asymmetric" => Component::AsymmetricObjectProperty(AsymmetricObjectProperty(ope)),
+                    "Transitive" => Component::TransitiveObjectProperty(TransitiveObjectProperty(ope)),
+                    _ => return self.error(&format!("unknown characteristic '{}'", c)),
+                },
+                _ => return self.error("expected a characteristic"),
+            },
+            _ => return self.error(&format!("'{}' cannot be used with an object property", keyword)),
+        })
+    }
+
+    fn data_property(&mut self) -> PyResult<DataProperty<ArcStr>> {
+        Ok(DataProperty(self.name()?))
+    }
+
+    fn data_property_axiom(&mut self, dp: DataProperty<ArcStr>, keyword: &str) -> PyResult<Component<ArcStr>> {
+        Ok(match keyword {
+            "SubPropertyOf" => Component::SubDataPropertyOf(SubDataPropertyOf {
+                sub: dp,
+                sup: self.data_property()?,
+            }),
+            "EquivalentTo" | "DisjointWith" => {
+                let mut dps = vec![dp];
+                dps.extend(self.list(Self::data_property)?);
+                if keyword == "EquivalentTo" {
+                    Component::EquivalentDataProperties(EquivalentDataProperties(dps))
+                } else {
+                    Component::DisjointDataProperties(DisjointDataProperties(dps))
+                }
+            }
+            "Domain" => Component::DataPropertyDomain(DataPropertyDomain {
+                dp,
+                ce: self.class_expression()?,
+            }),
+            "Range" => Component::DataPropertyRange(DataPropertyRange {
+                dp,
+                dr: self.data_range()?,
+            }),
+            "Characteristics" if self.accept_word("Functional") => {
+                Component::FunctionalDataProperty(FunctionalDataProperty(dp))
+            }
+            _ => return self.error(&format!("'{}' cannot be used with a data property", keyword)),
+        })
+    }
+
+    fn annotation_property_axiom(
+        &mut self,
+        ap: AnnotationProperty<ArcStr>,
+        keyword: &str,
+    ) -> PyResult<Component<ArcStr>> {
+        Ok(match keyword {
+            "SubPropertyOf" => Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf {
+                sub: ap,
+                sup: AnnotationProperty(self.name()?),
+            }),
+            "Domain" => Component::AnnotationPropertyDomain(AnnotationPropertyDomain {
+                ap,
+                iri: self.name()?,
+            }),
+            "Range" => Component::AnnotationPropertyRange(AnnotationPropertyRange {
+                ap,
+                iri: self.name_with(|r, n| r.resolve_datatype(n))?,
+            }),
+            _ => return self.error(&format!("'{}' cannot be used with an annotation property", keyword)),
+        })
+    }
+
+    fn individual_axiom(&mut self, iri: IRI<ArcStr>, keyword: &str) -> PyResult<Component<ArcStr>> {
+        let individual = Individual::Named(NamedIndividual(iri));
+
+        Ok(match keyword {
+            "Types" => Component::ClassAssertion(ClassAssertion {
+                ce: self.class_expression()?,
+                i: individual,
+            }),
+            "SameAs" | "DifferentFrom" => {
+                let mut individuals = vec![individual];
+                individuals.extend(self.list(Self::individual)?);
+                if keyword == "SameAs" {
+                    Component::SameIndividual(SameIndividual(individuals))
+                } else {
+                    Component::DifferentIndividuals(DifferentIndividuals(individuals))
+                }
+            }
+            _ => {
+                let negative = self.accept_word("not");
+                let property = self.name()?;
+                if self.resolver.data_properties.contains(&property) {
+                    let (dp, to) = (DataProperty(property), self.literal()?);
+                    if negative {
+                        Component::NegativeDataPropertyAssertion(NegativeDataPropertyAssertion {
+                            dp,
+                            from: individual,
+                            to,
+                        })
+                    } else {
+                        Component::DataPropertyAssertion(DataPropertyAssertion {
+                            dp,
+                            from: individual,
+                            to,
+                        })
+                    }
+                } else {
+                    let ope = ObjectPropertyExpression::ObjectProperty(ObjectProperty(property));
+                    let to = self.individual()?;
+                    if negative {
+                        Component::NegativeObjectPropertyAssertion(NegativeObjectPropertyAssertion {
+                            ope,
+                            from: individual,
+                            to,
+                        })
+                    } else {
+                        Component::ObjectPropertyAssertion(ObjectPropertyAssertion {
+                            ope,
+                            from: individual,
+                            to,
+                        })
+                    }
+                }
+            }
+        })
+    }
+
+    fn annotation_assertion(&mut self, subject: IRI<ArcStr>) -> PyResult<Component<ArcStr>> {
+        let ap = AnnotationProperty(self.name()?);
+        let av = match self.peek() {
+            Some(Token::Str(_)) => AnnotationValue::Literal(self.literal()?),
+            Some(Token::Word(w)) if w.parse::<f64>().is_ok() || w == "true" || w == "false" => {
+                AnnotationValue::Literal(self.literal()?)
+            }
+            _ => AnnotationValue::IRI(self.name()?),
+        };
+
+        Ok(Component::AnnotationAssertion(AnnotationAssertion {
+            subject: AnnotationSubject::IRI(subject),
+            ann: Annotation { ap, av },
+        }))
+    }
+}
+
+/// Renders model objects in Manchester syntax.
+///
+/// Entities are written by their label if they have one, otherwise as CURIE if the prefix
+/// mapping allows it or as full IRI.
+pub struct Renderer<'a> {
+    pub labels: HashMap<IRI<ArcStr>, &'a str>,
+    pub mapping: &'a PrefixMapping,
+}
+
+/// Quotes a name unless it can be written as is.
+fn quote(name: &str) -> String {
+    let plain = !name.is_empty()
+        && !KEYWORDS.contains(&name)
+        && frame_keyword(name).is_none()
+        && name.parse::<f64>().is_err()
+        && !name
+            .chars()
+            .any(|c| c.is_whitespace() || "(){}[],'\"<>^@".contains(c));
+
+    if plain {
+        name.to_string()
+    } else {
+        format!("'{}'", name.replace('\\', "\\\\").replace('\'', "\\'"))
+    }
+}
+
+/// Binding strength of a class expression, used to decide where parentheses are needed.
+fn precedence(ce: &ClassExpression<ArcStr>) -> u8 {
+    match ce {
+        ClassExpression::ObjectUnionOf(_) => 0,
+        ClassExpression::ObjectIntersectionOf(_) => 1,
+        _ => 2,
+    }
+}
+
+impl<'a> Renderer<'a> {
+    pub fn iri(&self, iri: &IRI<ArcStr>) -> String {
+        if let Some(label) = self.labels.get(iri) {
+            return quote(label);
+        }
+
+        match self.mapping.shrink_iri(iri.as_ref()) {
+            Ok(curie) => curie.to_string(),
+            Err(_) => format!("<{}>", iri),
+        }
+    }
+
+    fn datatype(&self, iri: &IRI<ArcStr>) -> String {
+        let iri_str = iri.to_string();
+        match iri_str.strip_prefix(XSD_NS) {
+            Some(local) if !self.labels.contains_key(iri) => format!("xsd:{}", local),
+            _ if iri_str == RDFS_LITERAL => "Literal".to_string(),
+            _ => self.iri(iri),
+        }
+    }
+
+    fn join<T>(&self, items: &[T], separator: &str, render: impl Fn(&T) -> String) -> String {
+        items.iter().map(render).collect::<Vec<_>>().join(separator)
+    }
+
+    /// Renders an operand, adding parentheses if it binds weaker than `level`.
+    fn operand(&self, ce: &ClassExpression<ArcStr>, level: u8) -> String {
+        let rendered = self.class_expression(ce);
+        if precedence(ce) < level {
+            format!("({})", rendered)
+        } else {
+            rendered
+        }
+    }
+
+    pub fn class_expression(&self, ce: &ClassExpression<ArcStr>) -> String {
+        use ClassExpression::*;
+
+        match ce {
+            Class(c) => self.iri(&c.0),
+            ObjectIntersectionOf(ces) => self.join(ces, " and ", |ce| self.operand(ce, 2)),
+            ObjectUnionOf(ces) => self.join(ces, " or ", |ce| self.operand(ce, 1)),
+            ObjectComplementOf(ce) => format!("not {}", self.operand(ce, 2)),
+            ObjectOneOf(individuals) => format!("{{{}}}", self.join(individuals, ", ", |i| self.individual(i))),
+            ObjectSomeValuesFrom { ope, bce } => {
+                format!("{} some {}", self.object_property_expression(ope), self.operand(bce, 2))
+            }
+            ObjectAllValuesFrom { ope, bce } => {
+                format!("{} only {}", self.object_property_expression(ope), self.operand(bce, 2))
+            }
+            ObjectHasValue { ope, i } => {
+                format!("{} value {}", self.object_property_expression(ope), self.individual(i))
+            }
+            ObjectHasSelf(ope) => format!("{} Self", self.object_property_expression(ope)),
+            ObjectMinCardinality { n, ope, bce } => self.object_cardinality("min", *n, ope, bce),
+            ObjectMaxCardinality { n, ope, bce } => self.object_cardinality("max", *n, ope, bce),
+            ObjectExactCardinality { n, ope, bce } => self.object_cardinality("exactly", *n, ope, bce),
+            DataSomeValuesFrom { dp, dr } => {
+                format!("{} some {}", self.iri(&dp.0), self.data_operand(dr))
+            }
+            DataAllValuesFrom { dp, dr } => {
+                format!("{} only {}", self.iri(&dp.0), self.data_operand(dr))
+            }
+            DataHasValue { dp, l } => format!("{} value {}", self.iri(&dp.0), self.literal(l)),
+            DataMinCardinality { n, dp, dr } => self.data_cardinality("min", *n, dp, dr),
+            DataMaxCardinality { n, dp, dr } => self.data_cardinality("max", *n, dp, dr),
+            DataExactCardinality { n, dp, dr } => self.data_cardinality("exactly", *n, dp, dr),
+        }
+    }
+
+    fn object_cardinality(
+        &self,
+        keyword: &str,
+        n: u32,
+        ope: &ObjectPropertyExpression<ArcStr>,
+        bce: &ClassExpression<ArcStr>,
+    ) -> String {
+        let restriction = format!("{} {} {}", self.object_property_expression(ope), keyword, n);
+        match bce {
+            ClassExpression::Class(c) if c.0.to_string() == OWL::Thing.as_ref() => restriction,
+            _ => format!("{} {}", restriction, self.operand(bce, 2)),
+        }
+    }
+
+    fn data_cardinality(&self, keyword: &str, n: u32, dp: &DataProperty<ArcStr>, dr: &DataRange<ArcStr>) -> String {
+        let restriction = format!("{} {} {}", self.iri(&dp.0), keyword, n);
+        match dr {
+            DataRange::Datatype(dt) if dt.0.to_string() == RDFS_LITERAL => restriction,
+            _ => format!("{} {}", restriction, self.data_operand(dr)),
+        }
+    }
+
+    pub fn object_property_expression(&self, ope: &ObjectPropertyExpression<ArcStr>) -> String {
+        match ope {
+            ObjectPropertyExpression::ObjectProperty(op) => self.iri(&op.0),
+            ObjectPropertyExpression::InverseObjectProperty(op) => format!("inverse ({})", self.iri(&op.0)),
+        }
+    }
+
+    fn property_expression(&self, pe: &PropertyExpression<ArcStr>) -> String {
+        match pe {
+            PropertyExpression::ObjectPropertyExpression(ope) => self.object_property_expression(ope),
+            PropertyExpression::DataProperty(dp) => self.iri(&dp.0),
+            PropertyExpression::AnnotationProperty(ap) => self.iri(&ap.0),
+        }
+    }
+
+    pub fn individual(&self, individual: &Individual<ArcStr>) -> String {
+        match individual {
+            Individual::Named(i) => self.iri(&i.0),
+            Individual::Anonymous(a) => format!("_:{}", a.0),
+        }
+    }
+
+    pub fn literal(&self, literal: &Literal<ArcStr>) -> String {
+        let quoted = |literal: &str| format!("\"{}\"", literal.replace('\\', "\\\\").replace('"', "\\\""));
+
+        match literal {
+            Literal::Simple { literal } => quoted(literal),
+            Literal::Language { literal, lang } => format!("{}@{}", quoted(literal), lang),
+            Literal::Datatype { literal, datatype_iri } => {
+                let plain = match datatype_iri.to_string().strip_prefix(XSD_NS) {
+                    Some("integer") => literal.parse::<i64>().is_ok(),
+                    Some("decimal") => literal.contains('.') && literal.parse::<f64>().is_ok(),
+                    Some("boolean") => literal == "true" || literal == "false",
+                    _ => false,
+                };
+                if plain {
+                    literal.to_string()
+                } else {
+                    format!("{}^^{}", quoted(literal), self.datatype(datatype_iri))
+                }
+            }
+        }
+    }
+
+    fn data_operand(&self, dr: &DataRange<ArcStr>) -> String {
+        match dr {
+            DataRange::DataIntersectionOf(_) | DataRange::DataUnionOf(_) => format!("({})", self.data_range(dr)),
+            _ => self.data_range(dr),
+        }
+    }
+
+    pub fn data_range(&self, dr: &DataRange<ArcStr>) -> String {
+        match dr {
+            DataRange::Datatype(dt) => self.datatype(&dt.0),
+            DataRange::DataIntersectionOf(drs) => self.join(drs, " and ", |dr| self.data_operand(dr)),
+            DataRange::DataUnionOf(drs) => self.join(drs, " or ", |dr| self.data_operand(dr)),
+            DataRange::DataComplementOf(dr) => format!("not {}", self.data_operand(dr)),
+            DataRange::DataOneOf(literals) => format!("{{{}}}", self.join(literals, ", ", |l| self.literal(l))),
+            DataRange::DatatypeRestriction(dt, restrictions) => format!(
+                "{}[{}]",
+                self.datatype(&dt.0),
+                self.join(restrictions, ", ", |r| format!("{} {}", facet(&r.f), self.literal(&r.l)))
+            ),
+        }
+    }
+
+    fn annotation_value(&self, av: &AnnotationValue<ArcStr>) -> String {
+        match av {
+            AnnotationValue::Literal(l) => self.literal(l),
+            AnnotationValue::IRI(iri) => self.iri(iri),
+            AnnotationValue::AnonymousIndividual(a) => format!("_:{}", a.0),
+        }
+    }
+
+    /// Renders a component as a line of a Manchester syntax frame.
+    pub fn component(&self, component: &Component<ArcStr>) -> PyResult<String> {
+        use Component::*;
+
+        let axiom = |subject: String, keyword: &str, object: String| format!("{} {}: {}", subject, keyword, object);
+        let ces = |ces: &[ClassExpression<ArcStr>]| self.join(ces, ", ", |ce| self.class_expression(ce));
+        let opes = |opes: &[ObjectPropertyExpression<ArcStr>]| {
+            self.join(opes, ", ", |ope| self.object_property_expression(ope))
+        };
+        let dps = |dps: &[DataProperty<ArcStr>]| self.join(dps, ", ", |dp| self.iri(&dp.0));
+        let individuals = |is: &[Individual<ArcStr>]| self.join(is, ", ", |i| self.individual(i));
+        let characteristic = |ope: &ObjectPropertyExpression<ArcStr>, c: &str| {
+            axiom(self.object_property_expression(ope), "Characteristics", c.to_string())
+        };
+
+        Ok(match component {
+            DeclareClass(d) => format!("Class: {}", self.iri(&(d.0).0)),
+            DeclareObjectProperty(d) => format!("ObjectProperty: {}", self.iri(&(d.0).0)),
+            DeclareAnnotationProperty(d) => format!("AnnotationProperty: {}", self.iri(&(d.0).0)),
+            DeclareDataProperty(d) => format!("DataProperty: {}", self.iri(&(d.0).0)),
+            DeclareNamedIndividual(d) => format!("Individual: {}", self.iri(&(d.0).0)),
+            DeclareDatatype(d) => format!("Datatype: {}", self.iri(&(d.0).0)),
+            SubClassOf(ax) => axiom(self.class_expression(&ax.sub), "SubClassOf", self.class_expression(&ax.sup)),
+            EquivalentClasses(ax) if !ax.0.is_empty() => {
+                axiom(self.class_expression(&ax.0[0]), "EquivalentTo", ces(&ax.0[1..]))
+            }
+            DisjointClasses(ax) if !ax.0.is_empty() => {
+                axiom(self.class_expression(&ax.0[0]), "DisjointWith", ces(&ax.0[1..]))
+            }
+            DisjointUnion(ax) => axiom(self.iri(&(ax.0).0), "DisjointUnionOf", ces(&ax.1)),
+            SubObjectPropertyOf(ax) => match &ax.sub {
+                SubObjectPropertyExpression::ObjectPropertyExpression(sub) => axiom(
+                    self.object_property_expression(sub),
+                    "SubPropertyOf",
+                    self.object_property_expression(&ax.sup),
+                ),
+                SubObjectPropertyExpression::ObjectPropertyChain(chain) => axiom(
+                    self.object_property_expression(&ax.sup),
+                    "SubPropertyChain",
+                    self.join(chain, " o ", |ope| self.object_property_expression(ope)),
+                ),
+            },
+            EquivalentObjectProperties(ax) if !ax.0.is_empty() => {
+                axiom(self.object_property_expression(&ax.0[0]), "EquivalentTo", opes(&ax.0[1..]))
+            }
+            DisjointObjectProperties(ax) if !ax.0.is_empty() => {
+                axiom(self.object_property_expression(&ax.0[0]), "DisjointWith", opes(&ax.0[1..]))
+            }
+            InverseObjectProperties(ax) => axiom(self.iri(&(ax.0).0), "InverseOf", self.iri(&(ax.1).0)),
+            ObjectPropertyDomain(ax) => {
+                axiom(self.object_property_expression(&ax.ope), "Domain", self.class_expression(&ax.ce))
+            }
+            ObjectPropertyRange(ax) => {
+                axiom(self.object_property_expression(&ax.ope), "Range", self.class_expression(&ax.ce))
+            }
+            FunctionalObjectProperty(ax) => characteristic(&ax.0, "Functional"),
+            InverseFunctionalObjectProperty(ax) => characteristic(&ax.0, "InverseFunctional"),
+            ReflexiveObjectProperty(ax) => characteristic(&ax.0, "Reflexive"),
+            IrreflexiveObjectProperty(ax) => characteristic(&ax.0, "Irreflexive"),
+            SymmetricObjectProperty(ax) => characteristic(&ax.0, "Symmetric"),
+            AsymmetricObjectProperty(ax) => characteristic(&ax.0, "Asymmetric"),
+            TransitiveObjectProperty(ax) => characteristic(&ax.0, "Transitive"),
+            SubDataPropertyOf(ax) => axiom(self.iri(&ax.sub.0), "SubPropertyOf", self.iri(&ax.sup.0)),
+            EquivalentDataProperties(ax) if !ax.0.is_empty() => {
+                axiom(self.iri(&ax.0[0].0), "EquivalentTo", dps(&ax.0[1..]))
+            }
+            DisjointDataProperties(ax) if !ax.0.is_empty() => {
+                axiom(self.iri(&ax.0[0].0), "DisjointWith", dps(&ax.0[1..]))
+            }
+            DataPropertyDomain(ax) => axiom(self.iri(&ax.dp.0), "Domain", self.class_expression(&ax.ce)),
+            DataPropertyRange(ax) => axiom(self.iri(&ax.dp.0), "Range", self.data_range(&ax.dr)),
+            FunctionalDataProperty(ax) => axiom(self.iri(&(ax.0).0), "Characteristics", "Functional".to_string()),
+            DatatypeDefinition(ax) => axiom(self.iri(&ax.kind.0), "EquivalentTo", self.data_range(&ax.range)),
+            HasKey(ax) => axiom(
+                self.class_expression(&ax.ce),
+                "HasKey",
+                self.join(&ax.vpe, ", ", |pe| self.property_expression(pe)),
+            ),
+            SameIndividual(ax) if !ax.0.is_empty() => {
+                axiom(self.individual(&ax.0[0]), "SameAs", individuals(&ax.0[1..]))
+            }
+            DifferentIndividuals(ax) if !ax.0.is_empty() => {
+                axiom(self.individual(&ax.0[0]), "DifferentFrom", individuals(&ax.0[1..]))
+            }
+            ClassAssertion(ax) => axiom(self.individual(&ax.i), "Types", self.class_expression(&ax.ce)),
+            ObjectPropertyAssertion(ax) => axiom(
+                self.individual(&ax.from),
+                "Facts",
+                format!("{} {}", self.object_property_expression(&ax.ope), self.individual(&ax.to)),
+            ),
+            NegativeObjectPropertyAssertion(ax) => axiom(
+                self.individual(&ax.from),
+                "Facts",
+                format!("not {} {}", self.object_property_expression(&ax.ope), self.individual(&ax.to)),
+            ),
+            DataPropertyAssertion(ax) => axiom(
+                self.individual(&ax.from),
+                "Facts",
+                format!("{} {}", self.iri(&ax.dp.0), self.literal(&ax.to)),
+            ),
+            NegativeDataPropertyAssertion(ax) => axiom(
+                self.individual(&ax.from),
+                "Facts",
+                format!("not {} {}", self.iri(&ax.dp.0), self.literal(&ax.to)),
+            ),
+            AnnotationAssertion(ax) => {
+                let subject = match &ax.subject {
+                    AnnotationSubject::IRI(iri) => self.iri(iri),
+                    AnnotationSubject::AnonymousIndividual(a) => format!("_:{}", a.0),
+                };
+                axiom(
+                    subject,
+                    "Annotations",
+                    format!("{} {}", self.iri(&ax.ann.ap.0), self.annotation_value(&ax.ann.av)),
+                )
+            }
+            SubAnnotationPropertyOf(ax) => axiom(self.iri(&ax.sub.0), "SubPropertyOf", self.iri(&ax.sup.0)),
+            AnnotationPropertyDomain(ax) => axiom(self.iri(&ax.ap.0), "Domain", self.iri(&ax.iri)),
+            AnnotationPropertyRange(ax) => axiom(self.iri(&ax.ap.0), "Range", self.datatype(&ax.iri)),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "{:?} components cannot be rendered in Manchester syntax.",
+                    component.kind()
+                )))
+            }
+        })
+    }
+}
+
+fn facet(f: &Facet) -> &'static str {
+    match f {
+        Facet::Length => "length",
+        Facet::MinLength => "minLength",
+        Facet::MaxLength => "maxLength",
+        Facet::Pattern => "pattern",
+        Facet::MinInclusive => ">=",
+        Facet::MinExclusive => ">",
+        Facet::MaxInclusive => "<=",
+        Facet::MaxExclusive => "<",
+        Facet::TotalDigits => "totalDigits",
+        Facet::FractionDigits => "fractionDigits",
+        Facet::LangRange => "langRange",
+    }
+}
+
+/// Parses a class expression in Manchester syntax.
+pub fn parse_class_expression(text: &str, resolver: &Resolver) -> PyResult<ClassExpression<ArcStr>> {
+    let mut parser = Parser::new(text, resolver)?;
+    let ce = parser.class_expression()?;
+    parser.finish()?;
+    Ok(ce)
+}
+
+/// Parses an axiom or declaration in Manchester syntax.
+pub fn parse_component(text: &str, resolver: &Resolver) -> PyResult<Component<ArcStr>> {
+    let mut parser = Parser::new(text, resolver)?;
+    let component = parser.component()?;
+    parser.finish()?;
+    Ok(component)
+}
@@ -1,8 +1,10 @@
-use std::{borrow::Borrow, collections::BTreeSet, sync::Arc};
+use std::{borrow::Borrow, collections::{hash_map::DefaultHasher, BTreeSet, HashMap}, hash::{Hash, Hasher}, sync::Arc};
 
+use curie::PrefixMapping;
+use horned_owl::io::ofn::writer::AsFunctional;
 use horned_owl::model::{ArcStr};
 
-use pyo3::{exceptions::PyKeyError, prelude::*, types::{PyType, IntoPyDict}, PyObject};
+use pyo3::{basic::CompareOp, exceptions::{PyKeyError, PyValueError}, prelude::*, types::{IntoPyDict, PyBool, PyDict, PyList, PyLong, PyString, PyTuple, PyType}, PyObject};
 
 use paste::paste;
 use regex::Regex;
@@ -331,6 +333,81 @@ macro_rules! wrapped_enum {
                         }
                     }
 
+                    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+                        let value = horned_owl::model::$name::<ArcStr>::from(&$name([<$name _ Inner>]::$v_name(self.clone())));
+                        let other = horned_owl::model::$name::<ArcStr>::from(&$name([<$name _ Inner>]::$v_name(other.clone())));
+                        op.matches(value.cmp(&other))
+                    }
+
+                    fn __hash__(&self) -> u64 {
+                        let mut hasher = DefaultHasher::new();
+                        horned_owl::model::$name::<ArcStr>::from(&$name([<$name _ Inner>]::$v_name(self.clone()))).hash(&mut hasher);
+                        hasher.finish()
+                    }
+
+                    fn __reduce__(&self, py: Python<'_>) -> (PyObject, PyObject) {
+                        (py.get_type::<Self>().into_py(py), ($(cond!(self.0.clone(), $field_t0), $(cond!(self.1.clone(), $field_t1),)?)? $($(self.$field_s.clone(),)*)?).into_py(py))
+                    }
+
+                    fn __copy__(&self) -> Self {
+                        self.clone()
+                    }
+
+                    fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+                        self.clone()
+                    }
+
+                    fn __repr__(&self) -> String {
+                        horned_owl::model::$name::<ArcStr>::from(&$name([<$name _ Inner>]::$v_name(self.clone()))).as_functional().to_string()
+                    }
+
+                    fn __str__(&self) -> String {
+                        self.__repr__()
+                    }
+
+                    /// to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str
+                    ///
+                    /// Renders the object in OWL Functional Syntax, abbreviating IRIs with `prefixes` if given.
+                    fn to_functional(&self, prefixes: Option<HashMap<String, String>>) -> PyResult<String> {
+                        let value = horned_owl::model::$name::<ArcStr>::from(&$name([<$name _ Inner>]::$v_name(self.clone())));
+                        match prefixes {
+                            Some(prefixes) => Ok(value.as_functional_with_prefixes(&prefix_mapping(prefixes)?).to_string()),
+                            None => Ok(value.as_functional().to_string()),
+                        }
+                    }
+
+                    /// to_dict(self) -> typing.Dict[str, typing.Any]
+                    ///
+                    /// Converts the object to a dict of JSON-compatible values, see `to_json`.
+                    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+                        self.to_json_value(py)
+                    }
+
+                    /// from_dict(cls, value: typing.Dict[str, typing.Any]) -> Self
+                    ///
+                    /// Builds the object from a dict as returned by `to_dict`.
+                    #[classmethod]
+                    fn from_dict(_: &PyType, value: &PyAny) -> PyResult<Self> {
+                        Self::from_json_value(value)
+                    }
+
+                    /// to_json(self, indent: typing.Optional[int] = None) -> str
+                    ///
+                    /// Serialises the object as JSON. Objects are encoded as JSON objects with their class name
+                    /// under `"type"` and their fields under the attribute names, IRIs as strings and lists, sets
+                    /// and pairs as arrays. `from_json` restores an equal object.
+                    fn to_json(&self, py: Python<'_>, indent: Option<usize>) -> PyResult<String> {
+                        json_dumps(py, self.to_json_value(py)?, indent)
+                    }
+
+                    /// from_json(cls, text: str) -> Self
+                    ///
+                    /// Parses an object serialised with `to_json`.
+                    #[classmethod]
+                    fn from_json(cls: &PyType, text: &str) -> PyResult<Self> {
+                        Self::from_json_value(json_loads(cls.py(), text)?)
+                    }
+
                     #[classmethod]
                     fn __pyi__(_: &PyType) -> String {
                         let mut res = String::new();
@@ -355,11 +432,48 @@ macro_rules! wrapped_enum {
                             write!(&mut res, ", second: {}", to_py_type::<$field_t1>(String::new())).unwrap();
                         )?)?
                         write!(&mut res, "):\n        ...\n").unwrap();
+                        write!(&mut res, "    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:\n        ...\n").unwrap();
+                        write!(&mut res, "    def to_dict(self) -> typing.Dict[str, typing.Any]:\n        ...\n").unwrap();
+                        write!(&mut res, "    @classmethod\n    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> {}:\n        ...\n", stringify!($v_name_full)).unwrap();
+                        write!(&mut res, "    def to_json(self, indent: typing.Optional[int] = None) -> str:\n        ...\n").unwrap();
+                        write!(&mut res, "    @classmethod\n    def from_json(cls, text: str) -> {}:\n        ...\n", stringify!($v_name_full)).unwrap();
                         write!(&mut res, "    ...\n").unwrap();
 
                         res
                     }
                 }
+
+                impl JsonValue for [<$v_name_full>] {
+                    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+                        let object = PyDict::new(py);
+                        object.set_item("type", stringify!($v_name_full))?;
+                        $(
+                            object.set_item("first", cond!(self.0.to_json_value(py)?, $field_t0))?;
+                            $(object.set_item("second", cond!(self.1.to_json_value(py)?, $field_t1))?;)?
+                        )?
+                        $($(
+                            object.set_item(stringify!($field_s), self.$field_s.to_json_value(py)?)?;
+                        )*)?
+                        Ok(object.into_py(py))
+                    }
+
+                    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+                        let object = json_object(value, stringify!($v_name_full))?;
+                        Ok([<$v_name_full>]
+                            $((
+                                <$field_t0 as JsonValue>::from_json_value(json_field(object, "first", stringify!($v_name_full))?)?,
+                                $(<$field_t1 as JsonValue>::from_json_value(json_field(object, "second", stringify!($v_name_full))?)?,)?
+                            ))?
+                            $({
+                                $($field_s: <$type_s as JsonValue>::from_json_value(json_field(object, stringify!($field_s), stringify!($v_name_full))?)?,)*
+                            })?
+                        )
+                    }
+
+                    fn json_matches(value: &PyAny) -> bool {
+                        json_type(value).as_deref() == Some(stringify!($v_name_full))
+                    }
+                }
             )?)*
 
             impl From<&horned_owl::model::$name<ArcStr>> for $name {
@@ -453,6 +567,39 @@ macro_rules! wrapped_enum {
                 }
             }
 
+            impl JsonValue for $name {
+                fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+                    match &self.0 {
+                        $($(
+                            [<$name _ Inner>]::$v_name(val) => val.to_json_value(py),
+                        )?)*
+                        $($(
+                            [<$name _ Inner>]::$v_name_transparent(val) => val.to_json_value(py),
+                        )?)*
+                    }
+                }
+
+                fn from_json_value(value: &PyAny) -> PyResult<Self> {
+                    $($(
+                        if [<$v_name_full>]::json_matches(value) {
+                            return Ok($name([<$name _ Inner>]::$v_name([<$v_name_full>]::from_json_value(value)?)));
+                        }
+                    )?)*
+                    $($(
+                        if <$field_transparent as JsonValue>::json_matches(value) {
+                            return Ok($name([<$name _ Inner>]::$v_name_transparent(<$field_transparent as JsonValue>::from_json_value(value)?)));
+                        }
+                    )?)*
+                    Err(PyValueError::new_err(format!("Cannot convert {} to {}.", value, stringify!($name))))
+                }
+
+                fn json_matches(value: &PyAny) -> bool {
+                    false
+                        $($(|| [<$v_name_full>]::json_matches(value))?)*
+                        $($(|| <$field_transparent as JsonValue>::json_matches(value))?)*
+                }
+            }
+
             wrapped_base! {$name}
         }
     };
@@ -505,6 +652,81 @@ macro_rules! wrapped {
                     }
                 }
 
+                fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+                    let value = horned_owl::model::$name::<ArcStr>::from(self);
+                    let other = horned_owl::model::$name::<ArcStr>::from(other);
+                    op.matches(value.cmp(&other))
+                }
+
+                fn __hash__(&self) -> u64 {
+                    let mut hasher = DefaultHasher::new();
+                    horned_owl::model::$name::<ArcStr>::from(self).hash(&mut hasher);
+                    hasher.finish()
+                }
+
+                fn __reduce__(&self, py: Python<'_>) -> (PyObject, PyObject) {
+                    (py.get_type::<Self>().into_py(py), ($(self.$field.clone(),)*).into_py(py))
+                }
+
+                fn __copy__(&self) -> Self {
+                    self.clone()
+                }
+
+                fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+                    self.clone()
+                }
+
+                fn __repr__(&self) -> String {
+                    horned_owl::model::$name::<ArcStr>::from(self).as_functional().to_string()
+                }
+
+                fn __str__(&self) -> String {
+                    self.__repr__()
+                }
+
+                /// to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str
+                ///
+                /// Renders the object in OWL Functional Syntax, abbreviating IRIs with `prefixes` if given.
+                fn to_functional(&self, prefixes: Option<HashMap<String, String>>) -> PyResult<String> {
+                    let value = horned_owl::model::$name::<ArcStr>::from(self);
+                    match prefixes {
+                        Some(prefixes) => Ok(value.as_functional_with_prefixes(&prefix_mapping(prefixes)?).to_string()),
+                        None => Ok(value.as_functional().to_string()),
+                    }
+                }
+
+                /// to_dict(self) -> typing.Dict[str, typing.Any]
+                ///
+                /// Converts the object to a dict of JSON-compatible values, see `to_json`.
+                fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+                    self.to_json_value(py)
+                }
+
+                /// from_dict(cls, value: typing.Dict[str, typing.Any]) -> Self
+                ///
+                /// Builds the object from a dict as returned by `to_dict`.
+                #[classmethod]
+                fn from_dict(_: &PyType, value: &PyAny) -> PyResult<Self> {
+                    Self::from_json_value(value)
+                }
+
+                /// to_json(self, indent: typing.Optional[int] = None) -> str
+                ///
+                /// Serialises the object as JSON. Objects are encoded as JSON objects with their class name
+                /// under `"type"` and their fields under the attribute names, IRIs as strings and lists, sets
+                /// and pairs as arrays. `from_json` restores an equal object.
+                fn to_json(&self, py: Python<'_>, indent: Option<usize>) -> PyResult<String> {
+                    json_dumps(py, self.to_json_value(py)?, indent)
+                }
+
+                /// from_json(cls, text: str) -> Self
+                ///
+                /// Parses an object serialised with `to_json`.
+                #[classmethod]
+                fn from_json(cls: &PyType, text: &str) -> PyResult<Self> {
+                    Self::from_json_value(json_loads(cls.py(), text)?)
+                }
+
                 #[classmethod]
                 fn __pyi__(_: &PyType) -> String {
                     let mut res = String::new();
@@ -520,6 +742,11 @@ macro_rules! wrapped {
                         write!(&mut res, ", {}: {}", stringify!($field), to_py_type::<$type>(String::new())).unwrap();
                     )*
                     write!(&mut res, "):\n        ...\n").unwrap();
+                    write!(&mut res, "    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:\n        ...\n").unwrap();
+                    write!(&mut res, "    def to_dict(self) -> typing.Dict[str, typing.Any]:\n        ...\n").unwrap();
+                    write!(&mut res, "    @classmethod\n    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> {}:\n        ...\n", stringify!($name)).unwrap();
+                    write!(&mut res, "    def to_json(self, indent: typing.Optional[int] = None) -> str:\n        ...\n").unwrap();
+                    write!(&mut res, "    @classmethod\n    def from_json(cls, text: str) -> {}:\n        ...\n", stringify!($name)).unwrap();
                     write!(&mut res, "    ...\n").unwrap();
 
                     res
@@ -542,6 +769,26 @@ macro_rules! wrapped {
                 }
             }
 
+            impl JsonValue for $name {
+                fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+                    let object = PyDict::new(py);
+                    object.set_item("type", stringify!($name))?;
+                    $(object.set_item(stringify!($field), self.$field.to_json_value(py)?)?;)*
+                    Ok(object.into_py(py))
+                }
+
+                fn from_json_value(value: &PyAny) -> PyResult<Self> {
+                    let object = json_object(value, stringify!($name))?;
+                    Ok($name {
+                        $($field: <$type as JsonValue>::from_json_value(json_field(object, stringify!($field), stringify!($name))?)?,)*
+                    })
+                }
+
+                fn json_matches(value: &PyAny) -> bool {
+                    json_type(value).as_deref() == Some(stringify!($name))
+                }
+            }
+
             wrapped_base! {$name}
         }
 
@@ -578,6 +825,81 @@ macro_rules! wrapped {
                 )
             }
 
+            fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+                let value = horned_owl::model::$name::<ArcStr>::from(self);
+                let other = horned_owl::model::$name::<ArcStr>::from(other);
+                op.matches(value.cmp(&other))
+            }
+
+            fn __hash__(&self) -> u64 {
+                let mut hasher = DefaultHasher::new();
+                horned_owl::model::$name::<ArcStr>::from(self).hash(&mut hasher);
+                hasher.finish()
+            }
+
+            fn __reduce__(&self, py: Python<'_>) -> (PyObject, PyObject) {
+                (py.get_type::<Self>().into_py(py), (self.0.clone(), $(cond!(self.1.clone(), $type1),)?).into_py(py))
+            }
+
+            fn __copy__(&self) -> Self {
+                self.clone()
+            }
+
+            fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+                self.clone()
+            }
+
+            fn __repr__(&self) -> String {
+                horned_owl::model::$name::<ArcStr>::from(self).as_functional().to_string()
+            }
+
+            fn __str__(&self) -> String {
+                self.__repr__()
+            }
+
+            /// to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str
+            ///
+            /// Renders the object in OWL Functional Syntax, abbreviating IRIs with `prefixes` if given.
+            fn to_functional(&self, prefixes: Option<HashMap<String, String>>) -> PyResult<String> {
+                let value = horned_owl::model::$name::<ArcStr>::from(self);
+                match prefixes {
+                    Some(prefixes) => Ok(value.as_functional_with_prefixes(&prefix_mapping(prefixes)?).to_string()),
+                    None => Ok(value.as_functional().to_string()),
+                }
+            }
+
+            /// to_dict(self) -> typing.Dict[str, typing.Any]
+            ///
+            /// Converts the object to a dict of JSON-compatible values, see `to_json`.
+            fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+                self.to_json_value(py)
+            }
+
+            /// from_dict(cls, value: typing.Dict[str, typing.Any]) -> Self
+            ///
+            /// Builds the object from a dict as returned by `to_dict`.
+            #[classmethod]
+            fn from_dict(_: &PyType, value: &PyAny) -> PyResult<Self> {
+                Self::from_json_value(value)
+            }
+
+            /// to_json(self, indent: typing.Optional[int] = None) -> str
+            ///
+            /// Serialises the object as JSON. Objects are encoded as JSON objects with their class name
+            /// under `"type"` and their fields under the attribute names, IRIs as strings and lists, sets
+            /// and pairs as arrays. `from_json` restores an equal object.
+            fn to_json(&self, py: Python<'_>, indent: Option<usize>) -> PyResult<String> {
+                json_dumps(py, self.to_json_value(py)?, indent)
+            }
+
+            /// from_json(cls, text: str) -> Self
+            ///
+            /// Parses an object serialised with `to_json`.
+            #[classmethod]
+            fn from_json(cls: &PyType, text: &str) -> PyResult<Self> {
+                Self::from_json_value(json_loads(cls.py(), text)?)
+            }
+
             #[classmethod]
             fn __pyi__(_: &PyType) -> String {
                 let mut res = String::new();
@@ -594,6 +916,11 @@ macro_rules! wrapped {
                     write!(&mut res, ", second: {}", to_py_type::<$type1>(String::new())).unwrap();
                 )?
                 write!(&mut res, "):\n        ...\n").unwrap();
+                write!(&mut res, "    def to_functional(self, prefixes: typing.Optional[typing.Dict[str, str]] = None) -> str:\n        ...\n").unwrap();
+                write!(&mut res, "    def to_dict(self) -> typing.Dict[str, typing.Any]:\n        ...\n").unwrap();
+                write!(&mut res, "    @classmethod\n    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> {}:\n        ...\n", stringify!($name)).unwrap();
+                write!(&mut res, "    def to_json(self, indent: typing.Optional[int] = None) -> str:\n        ...\n").unwrap();
+                write!(&mut res, "    @classmethod\n    def from_json(cls, text: str) -> {}:\n        ...\n", stringify!($name)).unwrap();
                 write!(&mut res, "    ...\n").unwrap();
 
                 res
@@ -619,6 +946,28 @@ macro_rules! wrapped {
             }
         }
 
+        impl JsonValue for $name {
+            fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+                let object = PyDict::new(py);
+                object.set_item("type", stringify!($name))?;
+                object.set_item("first", self.0.to_json_value(py)?)?;
+                $(object.set_item("second", cond!(self.1.to_json_value(py)?, $type1))?;)?
+                Ok(object.into_py(py))
+            }
+
+            fn from_json_value(value: &PyAny) -> PyResult<Self> {
+                let object = json_object(value, stringify!($name))?;
+                Ok($name(
+                    <$type0 as JsonValue>::from_json_value(json_field(object, "first", stringify!($name))?)?,
+                    $(<$type1 as JsonValue>::from_json_value(json_field(object, "second", stringify!($name))?)?,)?
+                ))
+            }
+
+            fn json_matches(value: &PyAny) -> bool {
+                json_type(value).as_deref() == Some(stringify!($name))
+            }
+        }
+
         wrapped_base! {$name}
 
     }};
@@ -683,6 +1032,27 @@ macro_rules! wrapped {
             }
         }
 
+        impl JsonValue for $name {
+            fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+                match self {
+                    $($name::$v_name(inner) => inner.to_json_value(py),)*
+                }
+            }
+
+            fn from_json_value(value: &PyAny) -> PyResult<Self> {
+                $(
+                    if <$field as JsonValue>::json_matches(value) {
+                        return Ok($name::$v_name(<$field as JsonValue>::from_json_value(value)?));
+                    }
+                )*
+                Err(PyValueError::new_err(format!("Cannot convert {} to {}.", value, stringify!($name))))
+            }
+
+            fn json_matches(value: &PyAny) -> bool {
+                $(<$field as JsonValue>::json_matches(value))||*
+            }
+        }
+
         wrapped_base! {$name}
     };
     (pub enum $name:ident {
@@ -721,6 +1091,280 @@ macro_rules! wrapped {
     }
 }
 
+/// Builds a prefix mapping from a dict of prefixes to IRI prefixes, the empty prefix being the default namespace.
+fn prefix_mapping(prefixes: HashMap<String, String>) -> PyResult<PrefixMapping> {
+    let mut mapping = PrefixMapping::default();
+    for (prefix, iri) in prefixes {
+        if prefix.is_empty() {
+            mapping.set_default(&iri);
+        } else {
+            mapping
+                .add_prefix(&prefix, &iri)
+                .map_err(|_| PyValueError::new_err(format!("Invalid prefix '{}'.", prefix)))?;
+        }
+    }
+    Ok(mapping)
+}
+
+/// Conversion between model values and plain JSON-compatible Python values.
+///
+/// The schema is derived from the model classes: every class becomes a dict holding its name
+/// under `"type"` and its fields under their attribute names (`"first"` and `"second"` for
+/// positional fields). IRIs and strings become strings, `u32` an int, a `Facet` its variant
+/// name, an `Option` either the value or `None`, and lists, annotation sets and pairs become
+/// lists. Union types such as `ClassExpression` are encoded as the chosen member, so
+/// `from_json_value(to_json_value(x)) == x` holds for every value.
+trait JsonValue: Sized {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject>;
+    fn from_json_value(value: &PyAny) -> PyResult<Self>;
+
+    /// Whether `value` has the shape of an encoded `Self`, used to pick the member of a union.
+    fn json_matches(value: &PyAny) -> bool;
+}
+
+/// Returns the `"type"` tag of an encoded model object.
+fn json_type(value: &PyAny) -> Option<String> {
+    value
+        .downcast::<PyDict>()
+        .ok()?
+        .get_item("type")
+        .ok()??
+        .extract()
+        .ok()
+}
+
+/// Checks that `value` is an encoded object of type `name` and returns it as a dict.
+fn json_object<'a>(value: &'a PyAny, name: &str) -> PyResult<&'a PyDict> {
+    match json_type(value) {
+        Some(t) if t == name => Ok(value.downcast::<PyDict>()?),
+        Some(t) => Err(PyValueError::new_err(format!(
+            "Expected an object of type '{}' but got '{}'.",
+            name, t
+        ))),
+        None => Err(PyValueError::new_err(format!(
+            "Expected an object of type '{}' but got {}.",
+            name, value
+        ))),
+    }
+}
+
+fn json_field<'a>(object: &'a PyDict, field: &str, name: &str) -> PyResult<&'a PyAny> {
+    object
+        .get_item(field)?
+        .ok_or_else(|| PyValueError::new_err(format!("Missing field '{}' of '{}'.", field, name)))
+}
+
+fn is_json_list(value: &PyAny) -> bool {
+    value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>()
+}
+
+fn json_list(value: &PyAny) -> PyResult<Vec<&PyAny>> {
+    if !is_json_list(value) {
+        return Err(PyValueError::new_err(format!("Expected a list but got {}.", value)));
+    }
+    value.extract()
+}
+
+fn json_dumps(py: Python<'_>, value: PyObject, indent: Option<usize>) -> PyResult<String> {
+    let kwargs = [("indent", indent)].into_py_dict(py);
+    py.import("json")?
+        .getattr("dumps")?
+        .call((value,), Some(kwargs))?
+        .extract()
+}
+
+fn json_loads<'a>(py: Python<'a>, text: &str) -> PyResult<&'a PyAny> {
+    py.import("json")?.getattr("loads")?.call1((text,))
+}
+
+impl JsonValue for IRI {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.0.to_string().into_py(py))
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        let iri: String = String::from_json_value(value)?;
+        Ok(IRI::new(iri, &horned_owl::model::Build::new_arc()))
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        value.is_instance_of::<PyString>()
+    }
+}
+
+impl JsonValue for String {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.to_object(py))
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        if !Self::json_matches(value) {
+            return Err(PyValueError::new_err(format!("Expected a string but got {}.", value)));
+        }
+        value.extract()
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        value.is_instance_of::<PyString>()
+    }
+}
+
+impl JsonValue for StringWrapper {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.0.to_json_value(py)
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        String::from_json_value(value).map(StringWrapper)
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        String::json_matches(value)
+    }
+}
+
+impl JsonValue for u32 {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.to_object(py))
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        if !Self::json_matches(value) {
+            return Err(PyValueError::new_err(format!("Expected an integer but got {}.", value)));
+        }
+        value.extract()
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        value.is_instance_of::<PyLong>() && !value.is_instance_of::<PyBool>()
+    }
+}
+
+impl JsonValue for Facet {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(format!("{:?}", self).into_py(py))
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        match String::from_json_value(value)?.as_str() {
+            "Length" => Ok(Facet::Length),
+            "MinLength" => Ok(Facet::MinLength),
+            "MaxLength" => Ok(Facet::MaxLength),
+            "Pattern" => Ok(Facet::Pattern),
+            "MinInclusive" => Ok(Facet::MinInclusive),
+            "MinExclusive" => Ok(Facet::MinExclusive),
+            "MaxInclusive" => Ok(Facet::MaxInclusive),
+            "MaxExclusive" => Ok(Facet::MaxExclusive),
+            "TotalDigits" => Ok(Facet::TotalDigits),
+            "FractionDigits" => Ok(Facet::FractionDigits),
+            "LangRange" => Ok(Facet::LangRange),
+            other => Err(PyValueError::new_err(format!("Unknown facet '{}'.", other))),
+        }
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        String::json_matches(value)
+    }
+}
+
+impl<T: JsonValue> JsonValue for Option<T> {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        match self {
+            Some(inner) => inner.to_json_value(py),
+            None => Ok(py.None()),
+        }
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        if value.is_none() {
+            Ok(None)
+        } else {
+            T::from_json_value(value).map(Some)
+        }
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        value.is_none() || T::json_matches(value)
+    }
+}
+
+impl<T: JsonValue> JsonValue for VecWrap<T> {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let items = self
+            .0
+            .iter()
+            .map(|item| item.to_json_value(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(items.into_py(py))
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        json_list(value)?
+            .into_iter()
+            .map(T::from_json_value)
+            .collect::<PyResult<Vec<_>>>()
+            .map(VecWrap)
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        is_json_list(value)
+    }
+}
+
+impl<T: JsonValue + Ord> JsonValue for BTreeSetWrap<T> {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let items = self
+            .0
+            .iter()
+            .map(|item| item.to_json_value(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(items.into_py(py))
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        json_list(value)?
+            .into_iter()
+            .map(T::from_json_value)
+            .collect::<PyResult<BTreeSet<_>>>()
+            .map(BTreeSetWrap)
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        is_json_list(value)
+    }
+}
+
+impl<T: JsonValue> JsonValue for BoxWrap<T> {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.0.to_json_value(py)
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        T::from_json_value(value).map(|inner| BoxWrap(Box::new(inner)))
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        T::json_matches(value)
+    }
+}
+
+impl<A: JsonValue, B: JsonValue> JsonValue for (A, B) {
+    fn to_json_value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(vec![self.0.to_json_value(py)?, self.1.to_json_value(py)?].into_py(py))
+    }
+
+    fn from_json_value(value: &PyAny) -> PyResult<Self> {
+        match json_list(value)?.as_slice() {
+            [a, b] => Ok((A::from_json_value(a)?, B::from_json_value(b)?)),
+            _ => Err(PyValueError::new_err(format!("Expected a pair but got {}.", value))),
+        }
+    }
+
+    fn json_matches(value: &PyAny) -> bool {
+        is_json_list(value)
+    }
+}
+
 trait FromCompatible<T> {
     fn from_c(value: T) -> Self;
 }
@@ -989,6 +1633,28 @@ impl IRI {
         self.0.to_string()
     }
 
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        Ok((py.get_type::<IRI>().getattr("parse")?.into_py(py), (self.0.to_string(),)))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+        self.clone()
+    }
+
     #[classmethod]
     pub fn parse(_: &PyType, value: String) -> Self {
         let builder = horned_owl::model::Build::new_arc();
@@ -1049,6 +1715,13 @@ pub enum Facet {
 
 #[pymethods]
 impl Facet {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (PyObject, String))> {
+        Ok((
+            py.import("builtins")?.getattr("getattr")?.into_py(py),
+            (py.get_type::<Self>().into_py(py), format!("{:?}", self)),
+        ))
+    }
+
     #[classmethod]
     fn __pyi__(_: &PyType) -> String {
         "class Facet:
@@ -1115,6 +1788,117 @@ impl From<horned_owl::vocab::Facet> for Facet {
     }
 }
 
+macro_rules! component_kind {
+    ($($kind:ident),*) => {
+        /// The kind of a `Component`, e.g. to select components with `PyIndexedOntology.get_components`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        #[pyclass(module = "pyhornedowl.model")]
+        pub enum ComponentKind {
+            $($kind,)*
+        }
+
+        #[pymethods]
+        impl ComponentKind {
+            fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (PyObject, String))> {
+                Ok((
+                    py.import("builtins")?.getattr("getattr")?.into_py(py),
+                    (py.get_type::<Self>().into_py(py), format!("{:?}", self)),
+                ))
+            }
+
+            #[classmethod]
+            fn __pyi__(_: &PyType) -> String {
+                let mut res = String::new();
+
+                write!(&mut res, "class ComponentKind:\n").unwrap();
+                $(
+                    write!(&mut res, "    {}: ComponentKind\n", stringify!($kind)).unwrap();
+                )*
+
+                res
+            }
+        }
+
+        impl From<&ComponentKind> for horned_owl::model::ComponentKind {
+            fn from(value: &ComponentKind) -> Self {
+                match value {
+                    $(ComponentKind::$kind => horned_owl::model::ComponentKind::$kind,)*
+                }
+            }
+        }
+
+        impl From<&horned_owl::model::ComponentKind> for ComponentKind {
+            fn from(value: &horned_owl::model::ComponentKind) -> Self {
+                match value {
+                    $(horned_owl::model::ComponentKind::$kind => ComponentKind::$kind,)*
+                }
+            }
+        }
+    };
+}
+
+component_kind!(
+    Import,
+    OntologyAnnotation,
+    DocIRI,
+    OntologyID,
+    DeclareClass,
+    DeclareObjectProperty,
+    DeclareAnnotationProperty,
+    DeclareDataProperty,
+    DeclareNamedIndividual,
+    DeclareDatatype,
+    SubClassOf,
+    EquivalentClasses,
+    DisjointClasses,
+    DisjointUnion,
+    SubObjectPropertyOf,
+    EquivalentObjectProperties,
+    DisjointObjectProperties,
+    InverseObjectProperties,
+    ObjectPropertyDomain,
+    ObjectPropertyRange,
+    FunctionalObjectProperty,
+    InverseFunctionalObjectProperty,
+    ReflexiveObjectProperty,
+    IrreflexiveObjectProperty,
+    SymmetricObjectProperty,
+    AsymmetricObjectProperty,
+    TransitiveObjectProperty,
+    SubDataPropertyOf,
+    EquivalentDataProperties,
+    DisjointDataProperties,
+    DataPropertyDomain,
+    DataPropertyRange,
+    FunctionalDataProperty,
+    DatatypeDefinition,
+    HasKey,
+    SameIndividual,
+    DifferentIndividuals,
+    ClassAssertion,
+    ObjectPropertyAssertion,
+    NegativeObjectPropertyAssertion,
+    DataPropertyAssertion,
+    NegativeDataPropertyAssertion,
+    AnnotationAssertion,
+    SubAnnotationPropertyOf,
+    AnnotationPropertyDomain,
+    AnnotationPropertyRange,
+    Rule
+);
+
+impl From<ComponentKind> for horned_owl::model::ComponentKind {
+    fn from(value: ComponentKind) -> Self {
+        value.borrow().into()
+    }
+}
+
+impl From<horned_owl::model::ComponentKind> for ComponentKind {
+    fn from(value: horned_owl::model::ComponentKind) -> Self {
+        value.borrow().into()
+    }
+}
+
 wrapped! { pub struct Class(pub IRI) }
 wrapped! { pub struct AnonymousIndividual(pub StringWrapper) }
 wrapped! { pub struct NamedIndividual(pub IRI) }
@@ -1772,6 +2556,7 @@ pub fn py_module(py: Python<'_>) -> PyResult<&PyModule> {
     module.add_class::<SymmetricObjectProperty>()?;
     module.add_class::<TransitiveObjectProperty>()?;
     module.add_class::<Facet>()?;
+    module.add_class::<ComponentKind>()?;
     module.add_class::<Rule>()?;
     module.add_class::<Variable>()?;
     module.add_class::<BuiltInAtom>()?;
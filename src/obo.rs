@@ -0,0 +1,1300 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Write;
+
+use curie::PrefixMapping;
+use horned_owl::model::*;
+use horned_owl::vocab::AnnotationBuiltIn;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    is_true_literal, IAO_DEFINITION, IAO_TERM_REPLACED_BY, OBO_IN_OWL_CONSIDER,
+    OBO_IN_OWL_HAS_DBXREF, OBO_IN_OWL_HAS_SYNONYM_TYPE, OBO_IN_OWL_IN_SUBSET, OBO_IN_OWL_NS,
+    OBO_NS, XSD_BOOLEAN, XSD_NS,
+};
+
+const SUBSET_PROPERTY: &str = "http://www.geneontology.org/formats/oboInOwl#SubsetProperty";
+const SYNONYM_TYPE_PROPERTY: &str =
+    "http://www.geneontology.org/formats/oboInOwl#SynonymTypeProperty";
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+const SHORTHAND_PROPERTY: &str = "http://www.geneontology.org/formats/oboInOwl#shorthand";
+const HAS_SCOPE: &str = "http://www.geneontology.org/formats/oboInOwl#hasScope";
+
+/// Synonym scopes and the oboInOwl properties they map to.
+const SYNONYM_SCOPES: [(&str, &str); 4] = [
+    ("EXACT", "hasExactSynonym"),
+    ("NARROW", "hasNarrowSynonym"),
+    ("BROAD", "hasBroadSynonym"),
+    ("RELATED", "hasRelatedSynonym"),
+];
+
+/// Annotation tags translated to an oboInOwl property of the same name holding a plain literal.
+const LITERAL_TAGS: [(&str, &str); 4] = [
+    ("namespace", "hasOBONamespace"),
+    ("alt_id", "hasAlternativeId"),
+    ("created_by", "created_by"),
+    ("creation_date", "creation_date"),
+];
+
+/// The order of tags within a stanza given by the OBO 1.4 specification.
+const TAG_ORDER: [&str; 40] = [
+    "id",
+    "is_anonymous",
+    "name",
+    "namespace",
+    "alt_id",
+    "def",
+    "comment",
+    "subset",
+    "synonym",
+    "xref",
+    "builtin",
+    "property_value",
+    "instance_of",
+    "domain",
+    "range",
+    "holds_over_chain",
+    "is_anti_symmetric",
+    "is_cyclic",
+    "is_reflexive",
+    "is_symmetric",
+    "is_asymmetric",
+    "is_transitive",
+    "is_functional",
+    "is_inverse_functional",
+    "is_a",
+    "intersection_of",
+    "union_of",
+    "equivalent_to",
+    "disjoint_from",
+    "inverse_of",
+    "transitive_over",
+    "equivalent_to_chain",
+    "disjoint_over",
+    "relationship",
+    "is_obsolete",
+    "replaced_by",
+    "consider",
+    "created_by",
+    "creation_date",
+    "is_metadata_tag",
+];
+
+/// Tags whose value ends with an identifier that is followed by its name in a comment.
+const REFERENCE_TAGS: [&str; 10] = [
+    "is_a",
+    "intersection_of",
+    "union_of",
+    "equivalent_to",
+    "disjoint_from",
+    "relationship",
+    "instance_of",
+    "inverse_of",
+    "domain",
+    "range",
+];
+
+/// Translates OBO identifiers to IRIs and back following the OBO 1.4 specification.
+///
+/// `PREFIX:LOCAL` identifiers map to `http://purl.obolibrary.org/obo/PREFIX_LOCAL` unless an
+/// `idspace` declares another namespace, unprefixed identifiers such as `part_of` to
+/// `http://purl.obolibrary.org/obo/ONTOLOGY#part_of` and URLs are used as they are.
+///
+/// An unprefixed `[Typedef]` identifier with an `xref` to a prefixed identifier, such as
+/// `part_of` with `xref: BFO:0000050`, is a shorthand for that identifier and maps to its IRI.
+#[derive(Default)]
+struct Ids {
+    ontology: String,
+    idspaces: HashMap<String, String>,
+    /// Shorthand identifiers by the IRI they stand for.
+    shorthands: HashMap<String, String>,
+    /// IRIs by the shorthand identifier standing for them.
+    shorthand_iris: HashMap<String, String>,
+}
+
+impl Ids {
+    fn add_shorthand(&mut self, id: &str, iri: String) {
+        self.shorthands.insert(iri.clone(), id.to_string());
+        self.shorthand_iris.insert(id.to_string(), iri);
+    }
+
+    fn expand(&self, id: &str) -> String {
+        if let Some(iri) = self.shorthand_iris.get(id) {
+            return iri.clone();
+        }
+        if is_url(id) {
+            return id.to_string();
+        }
+        match id.split_once(':') {
+            Some((prefix, local)) if !prefix.is_empty() => match self.idspaces.get(prefix) {
+                Some(namespace) => format!("{}{}", namespace, local),
+                None if prefix == "xsd" => format!("{}{}", XSD_NS, local),
+                None if prefix == "oboInOwl" => format!("{}{}", OBO_IN_OWL_NS, local),
+                None => format!("{}{}_{}", OBO_NS, prefix, local),
+            },
+            _ => format!("{}{}#{}", OBO_NS, self.ontology, id),
+        }
+    }
+
+    fn contract(&self, iri: &str) -> String {
+        match self.shorthands.get(iri) {
+            Some(id) => id.clone(),
+            None => self.contract_without_shorthand(iri),
+        }
+    }
+
+    /// Contracts `iri` to its prefixed identifier even if a shorthand stands for it.
+    fn contract_without_shorthand(&self, iri: &str) -> String {
+        if let Some(id) = iri.strip_prefix(&format!("{}{}#", OBO_NS, self.ontology)) {
+            return id.to_string();
+        }
+        for (prefix, namespace) in &self.idspaces {
+            if let Some(local) = iri.strip_prefix(namespace.as_str()) {
+                return format!("{}:{}", prefix, local);
+            }
+        }
+        if let Some(local) = iri.strip_prefix(XSD_NS) {
+            return format!("xsd:{}", local);
+        }
+        if let Some((prefix, local)) = iri.strip_prefix(OBO_NS).and_then(|id| id.split_once('_')) {
+            if !prefix.is_empty() && !prefix.contains(['/', '#']) {
+                return format!("{}:{}", prefix, local);
+            }
+        }
+        iri.to_string()
+    }
+}
+
+fn is_url(id: &str) -> bool {
+    id.starts_with("http://") || id.starts_with("https://") || id.starts_with("urn:")
+}
+
+/// Whether `id` has a prefix, as in `BFO:0000050`, rather than being unprefixed or a URL.
+fn is_prefixed(id: &str) -> bool {
+    !is_url(id) && id.split_once(':').is_some_and(|(prefix, _)| !prefix.is_empty())
+}
+
+/// Splits a tag value into the value and the content of its trailing `{qualifier}` block,
+/// removing a trailing `! comment` outside of quotes.
+fn split_value(value: &str) -> (&str, &str) {
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut end = value.len();
+    let mut qualifier = None;
+    for (i, c) in value.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            '!' if !in_quotes => {
+                end = i;
+                break;
+            }
+            '{' if !in_quotes && qualifier.is_none() => qualifier = Some(i),
+            _ => {}
+        }
+    }
+    let value = value[..end].trim_end();
+    match qualifier {
+        Some(i) if i < value.len() && value.ends_with('}') => {
+            (value[..i].trim_end(), value[i + 1..value.len() - 1].trim())
+        }
+        _ => (value, ""),
+    }
+}
+
+/// Splits the content of a `{qualifier}` block into its `name=value` pairs.
+fn qualifier_list(qualifiers: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut rest = qualifiers.trim();
+    while let Some((name, value)) = rest.split_once('=') {
+        let (value, remainder) = match quoted(value) {
+            Some((value, remainder)) => (value, remainder),
+            None => {
+                let value = value.trim_start();
+                let end = value.find(',').unwrap_or(value.len());
+                (unescape(value[..end].trim()), &value[end..])
+            }
+        };
+        pairs.push((name.trim().to_string(), value));
+        rest = remainder.trim_start().trim_start_matches(',').trim_start();
+    }
+    pairs
+}
+
+/// Whether `iri` is part of the vocabulary the OBO tags themselves map to.
+fn is_builtin(iri: &str) -> bool {
+    iri.starts_with(OBO_IN_OWL_NS)
+        || iri.starts_with("http://www.w3.org/")
+        || iri == IAO_DEFINITION
+        || iri == IAO_TERM_REPLACED_BY
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => result.push('\n'),
+                Some('t') => result.push('\t'),
+                Some('W') => result.push(' '),
+                Some(other) => result.push(other),
+                None => {}
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn escape(value: &str, quoted: bool) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '"' if quoted => result.push_str("\\\""),
+            '!' | '{' if !quoted => {
+                result.push('\\');
+                result.push(c);
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Splits a value starting with a quoted string into the unescaped string and the remainder.
+fn quoted(value: &str) -> Option<(String, &str)> {
+    let rest = value.trim_start().strip_prefix('"')?;
+    let mut escaped = false;
+    for (i, c) in rest.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            return Some((unescape(&rest[..i]), &rest[i + 1..]));
+        }
+    }
+    None
+}
+
+/// Returns the identifiers of a `[xref, ...]` list, ignoring their descriptions.
+fn xref_list(value: &str) -> Vec<String> {
+    let inner = match (value.find('['), value.rfind(']')) {
+        (Some(start), Some(end)) if start < end => &value[start + 1..end],
+        _ => return Vec::new(),
+    };
+    inner
+        .split(',')
+        .filter_map(|xref| xref.split_whitespace().next())
+        .map(unescape)
+        .collect()
+}
+
+/// A tag, its value and the content of its trailing qualifier block.
+type Clause = (String, String, String);
+
+struct Reader<'a> {
+    build: &'a Build<ArcStr>,
+    ids: Ids,
+    components: Vec<AnnotatedComponent<ArcStr>>,
+}
+
+impl<'a> Reader<'a> {
+    fn add(&mut self, component: Component<ArcStr>, ann: BTreeSet<Annotation<ArcStr>>) {
+        self.components.push(AnnotatedComponent { component, ann });
+    }
+
+    fn iri(&self, id: &str) -> IRI<ArcStr> {
+        self.build.iri(self.ids.expand(id))
+    }
+
+    fn class(&self, id: &str) -> ClassExpression<ArcStr> {
+        ClassExpression::Class(Class(self.iri(id)))
+    }
+
+    fn property(&self, id: &str) -> ObjectPropertyExpression<ArcStr> {
+        ObjectPropertyExpression::ObjectProperty(ObjectProperty(self.iri(id)))
+    }
+
+    fn individual(&self, id: &str) -> Individual<ArcStr> {
+        Individual::Named(NamedIndividual(self.iri(id)))
+    }
+
+    fn literal(&self, value: &str) -> AnnotationValue<ArcStr> {
+        AnnotationValue::Literal(Literal::Simple {
+            literal: value.to_string(),
+        })
+    }
+
+    fn annotation(&self, ap: &str, av: AnnotationValue<ArcStr>) -> Annotation<ArcStr> {
+        Annotation {
+            ap: AnnotationProperty(self.build.iri(ap)),
+            av,
+        }
+    }
+
+    fn xrefs(&self, value: &str) -> BTreeSet<Annotation<ArcStr>> {
+        xref_list(value)
+            .iter()
+            .map(|x| self.annotation(OBO_IN_OWL_HAS_DBXREF, self.literal(x)))
+            .collect()
+    }
+
+    /// Translates the `name=value` pairs of a qualifier block to axiom annotations, using the
+    /// oboInOwl property of the same name for unprefixed names.
+    fn qualifiers(&self, qualifiers: &str) -> BTreeSet<Annotation<ArcStr>> {
+        qualifier_list(qualifiers)
+            .into_iter()
+            .map(|(name, value)| {
+                let ap = if is_url(&name) || is_prefixed(&name) {
+                    self.ids.expand(&name)
+                } else {
+                    format!("{}{}", OBO_IN_OWL_NS, name)
+                };
+                self.annotation(&ap, self.literal(&value))
+            })
+            .collect()
+    }
+
+    fn assert(&mut self, subject: &IRI<ArcStr>, ap: &str, av: AnnotationValue<ArcStr>, ann: BTreeSet<Annotation<ArcStr>>) {
+        let ann_assertion = AnnotationAssertion {
+            subject: AnnotationSubject::IRI(subject.clone()),
+            ann: self.annotation(ap, av),
+        };
+        self.add(Component::AnnotationAssertion(ann_assertion), ann);
+    }
+
+    fn sub_class_of(&mut self, sub: ClassExpression<ArcStr>, sup: ClassExpression<ArcStr>, ann: BTreeSet<Annotation<ArcStr>>) {
+        self.add(Component::SubClassOf(SubClassOf { sub, sup }), ann);
+    }
+
+    fn sub_property_of(&mut self, sub: SubObjectPropertyExpression<ArcStr>, sup: ObjectPropertyExpression<ArcStr>) {
+        self.add(Component::SubObjectPropertyOf(SubObjectPropertyOf { sub, sup }), BTreeSet::new());
+    }
+
+    /// Translates an `is_a: X` or `relationship: R X` target to a class expression.
+    fn restriction(&self, value: &str) -> Option<ClassExpression<ArcStr>> {
+        let tokens: Vec<&str> = value.split_whitespace().collect();
+        match tokens.as_slice() {
+            [class] => Some(self.class(class)),
+            [property, class, ..] => Some(ClassExpression::ObjectSomeValuesFrom {
+                ope: self.property(property),
+                bce: Box::new(self.class(class)),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Translates a `property_value: R V` or `property_value: R "V" xsd:type` clause.
+    fn property_value(&mut self, subject: &IRI<ArcStr>, value: &str) {
+        let (property, rest) = match value.split_once(char::is_whitespace) {
+            Some(split) => split,
+            None => return,
+        };
+        let av = match quoted(rest) {
+            Some((literal, datatype)) => match datatype.split_whitespace().next() {
+                Some(datatype) if self.ids.expand(datatype) != XSD_STRING => {
+                    AnnotationValue::Literal(Literal::Datatype {
+                        literal,
+                        datatype_iri: self.iri(datatype),
+                    })
+                }
+                _ => AnnotationValue::Literal(Literal::Simple { literal }),
+            },
+            None => AnnotationValue::IRI(self.iri(rest.trim())),
+        };
+        let property = self.ids.expand(property);
+        self.assert(subject, &property, av, BTreeSet::new());
+    }
+
+    /// Translates the tags shared by all stanza kinds, returning whether `tag` was one of them.
+    fn common(&mut self, subject: &IRI<ArcStr>, tag: &str, value: &str) -> bool {
+        match tag {
+            "name" => {
+                let label = self.literal(&unescape(value));
+                self.assert(subject, AnnotationBuiltIn::Label.as_ref(), label, BTreeSet::new());
+            }
+            "def" => {
+                if let Some((definition, rest)) = quoted(value) {
+                    let ann = self.xrefs(rest);
+                    let definition = self.literal(&definition);
+                    self.assert(subject, IAO_DEFINITION, definition, ann);
+                }
+            }
+            "comment" => {
+                let comment = self.literal(&unescape(value));
+                self.assert(subject, AnnotationBuiltIn::Comment.as_ref(), comment, BTreeSet::new());
+            }
+            "subset" => {
+                let subset = AnnotationValue::IRI(self.iri(value));
+                self.assert(subject, OBO_IN_OWL_IN_SUBSET, subset, BTreeSet::new());
+            }
+            "synonym" => {
+                if let Some((synonym, rest)) = quoted(value) {
+                    let mut ann = self.xrefs(rest);
+                    let before_xrefs = rest.split('[').next().unwrap_or_default();
+                    let mut words = before_xrefs.split_whitespace();
+                    let scope = words.next().unwrap_or("RELATED");
+                    if let Some(synonym_type) = words.next() {
+                        ann.insert(self.annotation(
+                            OBO_IN_OWL_HAS_SYNONYM_TYPE,
+                            AnnotationValue::IRI(self.iri(synonym_type)),
+                        ));
+                    }
+                    let property = SYNONYM_SCOPES
+                        .iter()
+                        .find(|(s, _)| *s == scope)
+                        .map(|(_, p)| *p)
+                        .unwrap_or("hasRelatedSynonym");
+                    let synonym = self.literal(&synonym);
+                    self.assert(subject, &format!("{}{}", OBO_IN_OWL_NS, property), synonym, ann);
+                }
+            }
+            "xref" => {
+                let xref = value.split_whitespace().next().unwrap_or_default();
+                let xref = self.literal(&unescape(xref));
+                self.assert(subject, OBO_IN_OWL_HAS_DBXREF, xref, BTreeSet::new());
+            }
+            "property_value" => self.property_value(subject, value),
+            "is_obsolete" => {
+                if value == "true" {
+                    let deprecated = AnnotationValue::Literal(Literal::Datatype {
+                        literal: "true".to_string(),
+                        datatype_iri: self.build.iri(XSD_BOOLEAN),
+                    });
+                    self.assert(subject, AnnotationBuiltIn::Deprecated.as_ref(), deprecated, BTreeSet::new());
+                }
+            }
+            "replaced_by" => {
+                let replaced_by = AnnotationValue::IRI(self.iri(value));
+                self.assert(subject, IAO_TERM_REPLACED_BY, replaced_by, BTreeSet::new());
+            }
+            "consider" => {
+                let consider = AnnotationValue::IRI(self.iri(value));
+                self.assert(subject, OBO_IN_OWL_CONSIDER, consider, BTreeSet::new());
+            }
+            _ => match LITERAL_TAGS.iter().find(|(t, _)| *t == tag) {
+                Some((_, property)) => {
+                    let literal = self.literal(&unescape(value));
+                    self.assert(subject, &format!("{}{}", OBO_IN_OWL_NS, property), literal, BTreeSet::new());
+                }
+                None => return false,
+            },
+        }
+        true
+    }
+
+    fn term(&mut self, iri: IRI<ArcStr>, clauses: &[Clause]) {
+        self.add(Component::DeclareClass(DeclareClass(Class(iri.clone()))), BTreeSet::new());
+        let this = ClassExpression::Class(Class(iri.clone()));
+
+        let mut intersection = Vec::new();
+        let mut union = Vec::new();
+        for (tag, value, qualifiers) in clauses {
+            if self.common(&iri, tag, value) {
+                continue;
+            }
+            match tag.as_str() {
+                "is_a" | "relationship" => {
+                    if let Some(sup) = self.restriction(value) {
+                        let ann = self.qualifiers(qualifiers);
+                        self.sub_class_of(this.clone(), sup, ann);
+                    }
+                }
+                "intersection_of" => intersection.extend(self.restriction(value)),
+                "union_of" => union.push(self.class(value)),
+                "equivalent_to" => {
+                    let other = self.class(value);
+                    self.add(Component::EquivalentClasses(EquivalentClasses(vec![this.clone(), other])), BTreeSet::new());
+                }
+                "disjoint_from" => {
+                    let other = self.class(value);
+                    self.add(Component::DisjointClasses(DisjointClasses(vec![this.clone(), other])), BTreeSet::new());
+                }
+                _ => {}
+            }
+        }
+
+        if !intersection.is_empty() {
+            let definition = ClassExpression::ObjectIntersectionOf(intersection);
+            self.add(Component::EquivalentClasses(EquivalentClasses(vec![this.clone(), definition])), BTreeSet::new());
+        }
+        if !union.is_empty() {
+            let definition = ClassExpression::ObjectUnionOf(union);
+            self.add(Component::EquivalentClasses(EquivalentClasses(vec![this, definition])), BTreeSet::new());
+        }
+    }
+
+    fn typedef(&mut self, iri: IRI<ArcStr>, clauses: &[Clause]) {
+        let is_metadata_tag = clauses
+            .iter()
+            .any(|(tag, value, _)| tag == "is_metadata_tag" && value == "true");
+        if is_metadata_tag {
+            self.add(Component::DeclareAnnotationProperty(DeclareAnnotationProperty(AnnotationProperty(iri.clone()))), BTreeSet::new());
+            for (tag, value, _) in clauses {
+                if !self.common(&iri, tag, value) && tag == "is_a" {
+                    let sub_property_of = SubAnnotationPropertyOf {
+                        sub: AnnotationProperty(iri.clone()),
+                        sup: AnnotationProperty(self.iri(value)),
+                    };
+                    self.add(Component::SubAnnotationPropertyOf(sub_property_of), BTreeSet::new());
+                }
+            }
+            return;
+        }
+
+        self.add(Component::DeclareObjectProperty(DeclareObjectProperty(ObjectProperty(iri.clone()))), BTreeSet::new());
+        let this = ObjectPropertyExpression::ObjectProperty(ObjectProperty(iri.clone()));
+
+        for (tag, value, _) in clauses {
+            if self.common(&iri, tag, value) {
+                continue;
+            }
+            let component = match tag.as_str() {
+                "is_a" => {
+                    let sup = self.property(value);
+                    self.sub_property_of(SubObjectPropertyExpression::ObjectPropertyExpression(this.clone()), sup);
+                    continue;
+                }
+                "domain" => Component::ObjectPropertyDomain(ObjectPropertyDomain {
+                    ope: this.clone(),
+                    ce: self.class(value),
+                }),
+                "range" => Component::ObjectPropertyRange(ObjectPropertyRange {
+                    ope: this.clone(),
+                    ce: self.class(value),
+                }),
+                "inverse_of" => Component::InverseObjectProperties(InverseObjectProperties(
+                    ObjectProperty(iri.clone()),
+                    ObjectProperty(self.iri(value)),
+                )),
+                "disjoint_from" => Component::DisjointObjectProperties(DisjointObjectProperties(vec![
+                    this.clone(),
+                    self.property(value),
+                ])),
+                "equivalent_to" => Component::EquivalentObjectProperties(EquivalentObjectProperties(vec![
+                    this.clone(),
+                    self.property(value),
+                ])),
+                "transitive_over" => {
+                    let chain = vec![this.clone(), self.property(value)];
+                    self.sub_property_of(SubObjectPropertyExpression::ObjectPropertyChain(chain), this.clone());
+                    continue;
+                }
+                "holds_over_chain" => {
+                    let chain = value.split_whitespace().map(|id| self.property(id)).collect();
+                    self.sub_property_of(SubObjectPropertyExpression::ObjectPropertyChain(chain), this.clone());
+                    continue;
+                }
+                "is_transitive" if value == "true" => Component::TransitiveObjectProperty(TransitiveObjectProperty(this.clone())),
+                "is_symmetric" if value == "true" => Component::SymmetricObjectProperty(SymmetricObjectProperty(this.clone())),
+                "is_asymmetric" if value == "true" => Component::AsymmetricObjectProperty(AsymmetricObjectProperty(this.clone())),
+                "is_reflexive" if value == "true" => Component::ReflexiveObjectProperty(ReflexiveObjectProperty(this.clone())),
+                "is_functional" if value == "true" => Component::FunctionalObjectProperty(FunctionalObjectProperty(this.clone())),
+                "is_inverse_functional" if value == "true" => {
+                    Component::InverseFunctionalObjectProperty(InverseFunctionalObjectProperty(this.clone()))
+                }
+                _ => continue,
+            };
+            self.add(component, BTreeSet::new());
+        }
+    }
+
+    fn instance(&mut self, iri: IRI<ArcStr>, clauses: &[Clause]) {
+        self.add(Component::DeclareNamedIndividual(DeclareNamedIndividual(NamedIndividual(iri.clone()))), BTreeSet::new());
+        let this = Individual::Named(NamedIndividual(iri.clone()));
+
+        for (tag, value, qualifiers) in clauses {
+            if self.common(&iri, tag, value) {
+                continue;
+            }
+            match tag.as_str() {
+                "instance_of" => {
+                    let ce = self.class(value);
+                    self.add(Component::ClassAssertion(ClassAssertion { ce, i: this.clone() }), BTreeSet::new());
+                }
+                "relationship" => {
+                    if let Some((property, target)) = value.split_once(char::is_whitespace) {
+                        let assertion = ObjectPropertyAssertion {
+                            ope: self.property(property),
+                            from: this.clone(),
+                            to: self.individual(target.trim()),
+                        };
+                        let ann = self.qualifiers(qualifiers);
+                        self.add(Component::ObjectPropertyAssertion(assertion), ann);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn header(&mut self, clauses: &[Clause]) {
+        let ontology = self.ids.ontology.clone();
+        let mut viri = None;
+        let iri = if ontology.is_empty() {
+            None
+        } else if ontology.contains("://") {
+            Some(self.build.iri(ontology.as_str()))
+        } else {
+            Some(self.build.iri(format!("{}{}.owl", OBO_NS, ontology)))
+        };
+
+        for (tag, value, _) in clauses {
+            match tag.as_str() {
+                "data-version" => {
+                    viri = Some(self.build.iri(format!("{}{}/{}/{}.owl", OBO_NS, ontology, value, ontology)));
+                }
+                "import" => {
+                    let import = Import(self.build.iri(self.ids.expand(value)));
+                    self.add(Component::Import(import), BTreeSet::new());
+                }
+                "subsetdef" | "synonymtypedef" => {
+                    let (id, rest) = value.split_once(char::is_whitespace).unwrap_or((value, ""));
+                    let iri = self.iri(id);
+                    let parent = if tag == "subsetdef" { SUBSET_PROPERTY } else { SYNONYM_TYPE_PROPERTY };
+                    self.add(Component::DeclareAnnotationProperty(DeclareAnnotationProperty(AnnotationProperty(iri.clone()))), BTreeSet::new());
+                    let sub_property_of = SubAnnotationPropertyOf {
+                        sub: AnnotationProperty(iri.clone()),
+                        sup: AnnotationProperty(self.build.iri(parent)),
+                    };
+                    self.add(Component::SubAnnotationPropertyOf(sub_property_of), BTreeSet::new());
+                    if let Some((description, rest)) = quoted(rest) {
+                        let ap = if tag == "subsetdef" { AnnotationBuiltIn::Comment } else { AnnotationBuiltIn::Label };
+                        let description = self.literal(&description);
+                        self.assert(&iri, ap.as_ref(), description, BTreeSet::new());
+                        let scope = rest.split_whitespace().next();
+                        if let Some(scope) = scope.filter(|scope| SYNONYM_SCOPES.iter().any(|(s, _)| s == scope)) {
+                            let scope = self.literal(scope);
+                            self.assert(&iri, HAS_SCOPE, scope, BTreeSet::new());
+                        }
+                    }
+                }
+                "remark" => {
+                    let remark = self.annotation(AnnotationBuiltIn::Comment.as_ref(), self.literal(&unescape(value)));
+                    self.add(Component::OntologyAnnotation(OntologyAnnotation(remark)), BTreeSet::new());
+                }
+                "property_value" => {
+                    if let Some((property, rest)) = value.split_once(char::is_whitespace) {
+                        let av = match quoted(rest) {
+                            Some((literal, _)) => self.literal(&literal),
+                            None => AnnotationValue::IRI(self.iri(rest.trim())),
+                        };
+                        let ann = self.annotation(&self.ids.expand(property), av);
+                        self.add(Component::OntologyAnnotation(OntologyAnnotation(ann)), BTreeSet::new());
+                    }
+                }
+                "format-version" | "ontology" | "idspace" => {}
+                _ => {
+                    let ann = self.annotation(&format!("{}{}", OBO_IN_OWL_NS, tag), self.literal(&unescape(value)));
+                    self.add(Component::OntologyAnnotation(OntologyAnnotation(ann)), BTreeSet::new());
+                }
+            }
+        }
+
+        if iri.is_some() {
+            self.add(Component::OntologyID(OntologyID { iri, viri }), BTreeSet::new());
+        }
+    }
+}
+
+/// Reads an OBO 1.4 document into components and the prefixes declared with `idspace`.
+///
+/// Stanzas are translated following the OBO to OWL mapping: `[Term]` stanzas become classes,
+/// `[Typedef]` stanzas object properties, or annotation properties if marked with
+/// `is_metadata_tag`, and `[Instance]` stanzas named individuals. `is_a` and `relationship`
+/// become `SubClassOf` axioms, `intersection_of` and `union_of` equivalences and the metadata
+/// tags annotation assertions using the oboInOwl vocabulary.
+pub fn read(text: &str, build: &Build<ArcStr>) -> PyResult<(Vec<AnnotatedComponent<ArcStr>>, PrefixMapping)> {
+    let mut header: Vec<Clause> = Vec::new();
+    let mut stanzas: Vec<(String, Vec<Clause>)> = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('!') {
+            continue;
+        }
+        if line.starts_with('[') && line.ends_with(']') {
+            stanzas.push((line[1..line.len() - 1].trim().to_string(), Vec::new()));
+            continue;
+        }
+        let (tag, value) = line
+            .split_once(':')
+            .ok_or_else(|| PyValueError::new_err(format!("Invalid OBO line: {}", line)))?;
+        let (value, qualifiers) = split_value(value.trim());
+        let clause = (tag.trim().to_string(), value.to_string(), qualifiers.to_string());
+        match stanzas.last_mut() {
+            Some((_, clauses)) => clauses.push(clause),
+            None => header.push(clause),
+        }
+    }
+
+    let mut mapping = PrefixMapping::default();
+    let mut ids = Ids::default();
+    for (tag, value, _) in &header {
+        match tag.as_str() {
+            "ontology" => ids.ontology = value.clone(),
+            "idspace" => {
+                let mut parts = value.split_whitespace();
+                if let (Some(prefix), Some(namespace)) = (parts.next(), parts.next()) {
+                    let _ = mapping.add_prefix(prefix, namespace);
+                    ids.idspaces.insert(prefix.to_string(), namespace.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let stanza_id = |kind: &str, clauses: &[Clause]| {
+        clauses
+            .iter()
+            .find(|(tag, _, _)| tag == "id")
+            .map(|(_, value, _)| value.clone())
+            .ok_or_else(|| PyValueError::new_err(format!("OBO [{}] stanza without an id.", kind)))
+    };
+    for (kind, clauses) in &stanzas {
+        let id = stanza_id(kind, clauses)?;
+        if kind != "Typedef" || is_url(&id) || is_prefixed(&id) {
+            continue;
+        }
+        let xref = clauses
+            .iter()
+            .filter(|(tag, _, _)| tag == "xref")
+            .filter_map(|(_, value, _)| value.split_whitespace().next())
+            .find(|xref| is_prefixed(xref));
+        if let Some(xref) = xref {
+            let iri = ids.expand(xref);
+            ids.add_shorthand(&id, iri);
+        }
+    }
+
+    let mut reader = Reader {
+        build,
+        ids,
+        components: Vec::new(),
+    };
+    reader.header(&header);
+
+    for (kind, clauses) in &stanzas {
+        let id = stanza_id(kind, clauses)?;
+        let iri = reader.iri(&id);
+        match kind.as_str() {
+            "Term" => reader.term(iri, clauses),
+            "Typedef" if reader.ids.shorthand_iris.contains_key(&id) => {
+                let shorthand = reader.literal(&id);
+                reader.assert(&iri, SHORTHAND_PROPERTY, shorthand, BTreeSet::new());
+                let clauses: Vec<Clause> = clauses
+                    .iter()
+                    .filter(|(tag, value, _)| {
+                        tag != "xref"
+                            || value.split_whitespace().next().map(|xref| reader.ids.expand(xref))
+                                != Some(iri.to_string())
+                    })
+                    .cloned()
+                    .collect();
+                reader.typedef(iri, &clauses)
+            }
+            "Typedef" => reader.typedef(iri, clauses),
+            "Instance" => reader.instance(iri, clauses),
+            _ => {}
+        }
+    }
+
+    Ok((reader.components, mapping))
+}
+
+#[derive(Default)]
+struct Stanza {
+    kind: Option<&'static str>,
+    clauses: BTreeSet<(usize, String, String, String)>,
+}
+
+impl Stanza {
+    fn add(&mut self, tag: &str, value: String) {
+        self.add_qualified(tag, value, String::new());
+    }
+
+    /// Adds a clause with the content of its trailing qualifier block.
+    fn add_qualified(&mut self, tag: &str, value: String, qualifiers: String) {
+        let rank = TAG_ORDER.iter().position(|t| *t == tag).unwrap_or(TAG_ORDER.len());
+        self.clauses.insert((rank, tag.to_string(), value, qualifiers));
+    }
+
+    /// Sets the kind of stanza unless it is already known from a declaration.
+    fn default_kind(&mut self, kind: &'static str) {
+        self.kind.get_or_insert(kind);
+    }
+}
+
+struct Writer<'a> {
+    ids: &'a Ids,
+    stanzas: BTreeMap<String, Stanza>,
+    labels: HashMap<String, String>,
+}
+
+impl<'a> Writer<'a> {
+    fn stanza(&mut self, iri: &IRI<ArcStr>) -> &mut Stanza {
+        let id = self.ids.contract(iri.as_ref());
+        self.stanzas.entry(id).or_default()
+    }
+
+    fn id(&self, iri: &IRI<ArcStr>) -> String {
+        self.ids.contract(iri.as_ref())
+    }
+
+    fn class(&self, ce: &ClassExpression<ArcStr>) -> Option<String> {
+        match ce {
+            ClassExpression::Class(c) => Some(self.id(&c.0)),
+            _ => None,
+        }
+    }
+
+    fn property(&self, ope: &ObjectPropertyExpression<ArcStr>) -> Option<String> {
+        match ope {
+            ObjectPropertyExpression::ObjectProperty(op) => Some(self.id(&op.0)),
+            _ => None,
+        }
+    }
+
+    /// Translates a named class or an existential restriction to the `X` or `R X` tag value.
+    fn restriction(&self, ce: &ClassExpression<ArcStr>) -> Option<String> {
+        match ce {
+            ClassExpression::ObjectSomeValuesFrom { ope, bce } => {
+                Some(format!("{} {}", self.property(ope)?, self.class(bce)?))
+            }
+            _ => self.class(ce),
+        }
+    }
+
+    fn xrefs(&self, ann: &BTreeSet<Annotation<ArcStr>>) -> String {
+        let xrefs: Vec<String> = ann
+            .iter()
+            .filter(|a| a.ap.0.to_string() == OBO_IN_OWL_HAS_DBXREF)
+            .map(|a| escape(&self.value(&a.av), false).replace(',', "\\,"))
+            .collect();
+        format!("[{}]", xrefs.join(", "))
+    }
+
+    /// Translates axiom annotations to the content of a qualifier block, the inverse of
+    /// `Reader::qualifiers`.
+    fn qualifiers(&self, ann: &BTreeSet<Annotation<ArcStr>>) -> String {
+        let qualifiers: Vec<String> = ann
+            .iter()
+            .map(|a| {
+                let ap = a.ap.0.to_string();
+                let name = match ap.strip_prefix(OBO_IN_OWL_NS) {
+                    Some(name) => name.to_string(),
+                    None => self.id(&a.ap.0),
+                };
+                format!("{}=\"{}\"", name, escape(&self.value(&a.av), true))
+            })
+            .collect();
+        qualifiers.join(", ")
+    }
+
+    fn value(&self, av: &AnnotationValue<ArcStr>) -> String {
+        match av {
+            AnnotationValue::Literal(Literal::Simple { literal })
+            | AnnotationValue::Literal(Literal::Language { literal, .. })
+            | AnnotationValue::Literal(Literal::Datatype { literal, .. }) => literal.clone(),
+            AnnotationValue::IRI(iri) => self.id(iri),
+            AnnotationValue::AnonymousIndividual(ai) => ai.0.to_string(),
+        }
+    }
+
+    fn annotation(&mut self, subject: &IRI<ArcStr>, ann: &Annotation<ArcStr>, axiom_ann: &BTreeSet<Annotation<ArcStr>>) {
+        let ap = ann.ap.0.to_string();
+        let value = self.value(&ann.av);
+        let synonym = ap
+            .strip_prefix(OBO_IN_OWL_NS)
+            .and_then(|p| SYNONYM_SCOPES.iter().find(|(_, property)| *property == p))
+            .map(|(scope, _)| *scope);
+        let literal_tag = ap
+            .strip_prefix(OBO_IN_OWL_NS)
+            .and_then(|p| LITERAL_TAGS.iter().find(|(_, property)| *property == p))
+            .map(|(tag, _)| *tag);
+
+        let (tag, value) = if ap == AnnotationBuiltIn::Label.as_ref() {
+            self.labels.insert(self.id(subject), value.clone());
+            ("name", escape(&value, false))
+        } else if ap == IAO_DEFINITION {
+            ("def", format!("\"{}\" {}", escape(&value, true), self.xrefs(axiom_ann)))
+        } else if ap == AnnotationBuiltIn::Comment.as_ref() {
+            ("comment", escape(&value, false))
+        } else if ap == OBO_IN_OWL_IN_SUBSET {
+            ("subset", value)
+        } else if let Some(scope) = synonym {
+            let synonym_type = axiom_ann
+                .iter()
+                .find(|a| a.ap.0.to_string() == OBO_IN_OWL_HAS_SYNONYM_TYPE)
+                .map(|a| format!(" {}", self.value(&a.av)))
+                .unwrap_or_default();
+            let xrefs = self.xrefs(axiom_ann);
+            ("synonym", format!("\"{}\" {}{} {}", escape(&value, true), scope, synonym_type, xrefs))
+        } else if ap == OBO_IN_OWL_HAS_DBXREF {
+            ("xref", escape(&value, false))
+        } else if ap == SHORTHAND_PROPERTY {
+            ("xref", self.ids.contract_without_shorthand(subject.as_ref()))
+        } else if ap == AnnotationBuiltIn::Deprecated.as_ref() {
+            match &ann.av {
+                AnnotationValue::Literal(l) if is_true_literal(l) => ("is_obsolete", "true".to_string()),
+                _ => return,
+            }
+        } else if ap == IAO_TERM_REPLACED_BY {
+            ("replaced_by", value)
+        } else if ap == OBO_IN_OWL_CONSIDER {
+            ("consider", value)
+        } else if let Some(tag) = literal_tag {
+            (tag, escape(&value, false))
+        } else {
+            ("property_value", self.property_value(ann))
+        };
+        self.stanza(subject).add(tag, value);
+    }
+
+    fn property_value(&self, ann: &Annotation<ArcStr>) -> String {
+        let property = self.id(&ann.ap.0);
+        match &ann.av {
+            AnnotationValue::Literal(Literal::Datatype { literal, datatype_iri }) => {
+                format!("{} \"{}\" {}", property, escape(literal, true), self.id(datatype_iri))
+            }
+            AnnotationValue::Literal(Literal::Simple { literal })
+            | AnnotationValue::Literal(Literal::Language { literal, .. }) => {
+                format!("{} \"{}\" xsd:string", property, escape(literal, true))
+            }
+            av => format!("{} {}", property, self.value(av)),
+        }
+    }
+
+    fn component(&mut self, ac: &AnnotatedComponent<ArcStr>) {
+        match &ac.component {
+            Component::DeclareAnnotationProperty(DeclareAnnotationProperty(AnnotationProperty(iri)))
+            | Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf { sub: AnnotationProperty(iri), .. })
+            | Component::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(iri),
+                ..
+            }) if is_builtin(iri.as_ref()) => {}
+            Component::DeclareClass(DeclareClass(c)) => self.stanza(&c.0).kind = Some("Term"),
+            Component::DeclareObjectProperty(DeclareObjectProperty(op)) => self.stanza(&op.0).kind = Some("Typedef"),
+            Component::DeclareNamedIndividual(DeclareNamedIndividual(i)) => self.stanza(&i.0).kind = Some("Instance"),
+            Component::DeclareAnnotationProperty(DeclareAnnotationProperty(ap)) => {
+                let stanza = self.stanza(&ap.0);
+                stanza.kind = Some("Typedef");
+                stanza.add("is_metadata_tag", "true".to_string());
+            }
+            Component::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(subject),
+                ann,
+            }) => self.annotation(subject, ann, &ac.ann),
+            Component::SubClassOf(SubClassOf { sub: ClassExpression::Class(c), sup }) => {
+                let (tag, value) = match sup {
+                    ClassExpression::Class(_) => ("is_a", self.class(sup)),
+                    _ => ("relationship", self.restriction(sup)),
+                };
+                if let Some(value) = value {
+                    let qualifiers = self.qualifiers(&ac.ann);
+                    let stanza = self.stanza(&c.0);
+                    stanza.default_kind("Term");
+                    stanza.add_qualified(tag, value, qualifiers);
+                }
+            }
+            Component::EquivalentClasses(EquivalentClasses(operands)) => {
+                let (c, other) = match operands.as_slice() {
+                    [ClassExpression::Class(c), other] => (c, other),
+                    _ => return,
+                };
+                let clauses: Option<Vec<(&str, String)>> = match other {
+                    ClassExpression::Class(_) => self.class(other).map(|id| vec![("equivalent_to", id)]),
+                    ClassExpression::ObjectIntersectionOf(parts) => parts
+                        .iter()
+                        .map(|part| self.restriction(part).map(|value| ("intersection_of", value)))
+                        .collect(),
+                    ClassExpression::ObjectUnionOf(parts) => parts
+                        .iter()
+                        .map(|part| self.class(part).map(|value| ("union_of", value)))
+                        .collect(),
+                    _ => None,
+                };
+                if let Some(clauses) = clauses {
+                    let stanza = self.stanza(&c.0);
+                    stanza.default_kind("Term");
+                    for (tag, value) in clauses {
+                        stanza.add(tag, value);
+                    }
+                }
+            }
+            Component::DisjointClasses(DisjointClasses(operands)) => {
+                if let [ClassExpression::Class(c), other @ ClassExpression::Class(_)] = operands.as_slice() {
+                    if let Some(value) = self.class(other) {
+                        self.stanza(&c.0).add("disjoint_from", value);
+                    }
+                }
+            }
+            Component::ClassAssertion(ClassAssertion { ce, i: Individual::Named(i) }) => {
+                if let Some(value) = self.class(ce) {
+                    let stanza = self.stanza(&i.0);
+                    stanza.default_kind("Instance");
+                    stanza.add("instance_of", value);
+                }
+            }
+            Component::ObjectPropertyAssertion(ObjectPropertyAssertion {
+                ope,
+                from: Individual::Named(from),
+                to: Individual::Named(to),
+            }) => {
+                if let Some(property) = self.property(ope) {
+                    let value = format!("{} {}", property, self.id(&to.0));
+                    let qualifiers = self.qualifiers(&ac.ann);
+                    let stanza = self.stanza(&from.0);
+                    stanza.default_kind("Instance");
+                    stanza.add_qualified("relationship", value, qualifiers);
+                }
+            }
+            Component::SubObjectPropertyOf(SubObjectPropertyOf { sub, sup: ObjectPropertyExpression::ObjectProperty(sup) }) => {
+                match sub {
+                    SubObjectPropertyExpression::ObjectPropertyExpression(ObjectPropertyExpression::ObjectProperty(sub)) => {
+                        let value = self.id(&sup.0);
+                        self.typedef(&sub.0, "is_a", value);
+                    }
+                    SubObjectPropertyExpression::ObjectPropertyChain(chain) => {
+                        let chain: Option<Vec<String>> = chain.iter().map(|ope| self.property(ope)).collect();
+                        match chain.as_deref() {
+                            Some([first, second]) if *first == self.id(&sup.0) => {
+                                let value = second.clone();
+                                self.typedef(&sup.0, "transitive_over", value);
+                            }
+                            Some(chain) if !chain.is_empty() => {
+                                let value = chain.join(" ");
+                                self.typedef(&sup.0, "holds_over_chain", value);
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf { sub, sup }) => {
+                let value = self.id(&sup.0);
+                self.typedef(&sub.0, "is_a", value);
+            }
+            Component::ObjectPropertyDomain(ObjectPropertyDomain { ope: ObjectPropertyExpression::ObjectProperty(op), ce }) => {
+                if let Some(value) = self.class(ce) {
+                    self.typedef(&op.0, "domain", value);
+                }
+            }
+            Component::ObjectPropertyRange(ObjectPropertyRange { ope: ObjectPropertyExpression::ObjectProperty(op), ce }) => {
+                if let Some(value) = self.class(ce) {
+                    self.typedef(&op.0, "range", value);
+                }
+            }
+            Component::InverseObjectProperties(InverseObjectProperties(a, b)) => {
+                let value = self.id(&b.0);
+                self.typedef(&a.0, "inverse_of", value);
+            }
+            Component::TransitiveObjectProperty(TransitiveObjectProperty(ope)) => self.characteristic(ope, "is_transitive"),
+            Component::SymmetricObjectProperty(SymmetricObjectProperty(ope)) => self.characteristic(ope, "is_symmetric"),
+            Component::AsymmetricObjectProperty(AsymmetricObjectProperty(ope)) => self.characteristic(ope, "is_asymmetric"),
+            Component::ReflexiveObjectProperty(ReflexiveObjectProperty(ope)) => self.characteristic(ope, "is_reflexive"),
+            Component::FunctionalObjectProperty(FunctionalObjectProperty(ope)) => self.characteristic(ope, "is_functional"),
+            Component::InverseFunctionalObjectProperty(InverseFunctionalObjectProperty(ope)) => {
+                self.characteristic(ope, "is_inverse_functional")
+            }
+            _ => {}
+        }
+    }
+
+    fn typedef(&mut self, iri: &IRI<ArcStr>, tag: &str, value: String) {
+        let stanza = self.stanza(iri);
+        stanza.default_kind("Typedef");
+        stanza.add(tag, value);
+    }
+
+    fn characteristic(&mut self, ope: &ObjectPropertyExpression<ArcStr>, tag: &str) {
+        if let ObjectPropertyExpression::ObjectProperty(op) = ope {
+            self.typedef(&op.0, tag, "true".to_string());
+        }
+    }
+
+    /// Writes a clause, with its qualifier block and `! name` for tag values ending with an
+    /// identifier that has a label.
+    fn clause(&self, tag: &str, value: &str, qualifiers: &str) -> String {
+        let mut clause = format!("{}: {}", tag, value);
+        if !qualifiers.is_empty() {
+            clause.push_str(&format!(" {{{}}}", qualifiers));
+        }
+        if REFERENCE_TAGS.contains(&tag) {
+            if let Some(name) = value.split_whitespace().last().and_then(|id| self.labels.get(id)) {
+                clause.push_str(&format!(" ! {}", name.replace('\n', " ")));
+            }
+        }
+        clause
+    }
+}
+
+/// Writes the components of an ontology as an OBO 1.4 document.
+///
+/// This is the inverse of `read`. Stanzas are sorted by identifier and their tags follow the
+/// order given by the specification. Axioms without an OBO representation, such as nested class
+/// expressions or data properties, are not written.
+pub fn write<'a, W: Write>(
+    components: impl Iterator<Item = &'a AnnotatedComponent<ArcStr>>,
+    mapping: &PrefixMapping,
+    mut out: W,
+) -> PyResult<()> {
+    let components: Vec<&AnnotatedComponent<ArcStr>> = components.collect();
+
+    let mut ontology_iri = None;
+    let mut version_iri = None;
+    let mut subset_properties = BTreeSet::new();
+    let mut synonym_type_properties = BTreeSet::new();
+    let mut shorthands = Vec::new();
+    for ac in &components {
+        match &ac.component {
+            Component::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(subject),
+                ann:
+                    Annotation {
+                        ap,
+                        av: AnnotationValue::Literal(Literal::Simple { literal }),
+                    },
+            }) if ap.0.to_string() == SHORTHAND_PROPERTY => {
+                shorthands.push((literal.clone(), subject.to_string()));
+            }
+            Component::OntologyID(OntologyID { iri, viri }) => {
+                ontology_iri = iri.as_ref().map(|iri| iri.to_string());
+                version_iri = viri.as_ref().map(|iri| iri.to_string());
+            }
+            Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf { sub, sup }) => {
+                if sup.0.to_string() == SUBSET_PROPERTY {
+                    subset_properties.insert(sub.0.to_string());
+                } else if sup.0.to_string() == SYNONYM_TYPE_PROPERTY {
+                    synonym_type_properties.insert(sub.0.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let ontology = match &ontology_iri {
+        Some(iri) => iri
+            .strip_prefix(OBO_NS)
+            .map(|name| name.trim_end_matches(".owl").to_string())
+            .unwrap_or_else(|| iri.clone()),
+        None => String::new(),
+    };
+    let mut ids = Ids {
+        ontology: ontology.clone(),
+        idspaces: mapping
+            .mappings()
+            .filter(|(prefix, _)| !prefix.is_empty() && prefix.as_str() != "obo")
+            .map(|(prefix, namespace)| (prefix.clone(), namespace.clone()))
+            .collect(),
+        ..Ids::default()
+    };
+    for (id, iri) in shorthands {
+        ids.add_shorthand(&id, iri);
+    }
+
+    let mut writer = Writer {
+        ids: &ids,
+        stanzas: BTreeMap::new(),
+        labels: HashMap::new(),
+    };
+
+    let mut header: Vec<String> = vec!["format-version: 1.4".to_string()];
+    if let Some(version) = &version_iri {
+        let data_version = version
+            .strip_prefix(&format!("{}{}/", OBO_NS, ontology))
+            .and_then(|v| v.strip_suffix(&format!("/{}.owl", ontology)))
+            .unwrap_or(version);
+        header.push(format!("data-version: {}", data_version));
+    }
+    let mut idspaces: Vec<_> = ids.idspaces.iter().collect();
+    idspaces.sort();
+    for (prefix, namespace) in idspaces {
+        header.push(format!("idspace: {} {}", prefix, namespace));
+    }
+
+    let mut subsetdefs = BTreeMap::new();
+    let mut synonymtypedefs = BTreeMap::new();
+    let mut synonym_type_scopes = BTreeMap::new();
+    let mut header_tail = BTreeSet::new();
+    for ac in &components {
+        match &ac.component {
+            Component::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(subject),
+                ann,
+            }) if subset_properties.contains(&subject.to_string())
+                || synonym_type_properties.contains(&subject.to_string()) =>
+            {
+                let description = format!("\"{}\"", escape(&writer.value(&ann.av), true));
+                if ann.ap.0.to_string() == HAS_SCOPE {
+                    synonym_type_scopes.insert(writer.id(subject), writer.value(&ann.av));
+                } else if subset_properties.contains(&subject.to_string()) {
+                    subsetdefs.insert(writer.id(subject), description);
+                } else {
+                    synonymtypedefs.insert(writer.id(subject), description);
+                }
+            }
+            Component::Import(Import(iri)) => {
+                header_tail.insert((0, format!("import: {}", iri)));
+            }
+            Component::OntologyAnnotation(OntologyAnnotation(ann)) => {
+                let ap = ann.ap.0.to_string();
+                let line = if ap == AnnotationBuiltIn::Comment.as_ref() {
+                    format!("remark: {}", escape(&writer.value(&ann.av), false))
+                } else {
+                    format!("property_value: {}", writer.property_value(ann))
+                };
+                header_tail.insert((1, line));
+            }
+            Component::DeclareAnnotationProperty(DeclareAnnotationProperty(ap))
+                if subset_properties.contains(&ap.0.to_string())
+                    || synonym_type_properties.contains(&ap.0.to_string()) => {}
+            Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf { sub, .. })
+                if subset_properties.contains(&sub.0.to_string())
+                    || synonym_type_properties.contains(&sub.0.to_string()) => {}
+            _ => writer.component(ac),
+        }
+    }
+    for id in subset_properties.iter().map(|iri| ids.contract(iri)) {
+        let description = subsetdefs.remove(&id).unwrap_or_else(|| "\"\"".to_string());
+        header.push(format!("subsetdef: {} {}", id, description));
+    }
+    for id in synonym_type_properties.iter().map(|iri| ids.contract(iri)) {
+        let description = synonymtypedefs.remove(&id).unwrap_or_else(|| "\"\"".to_string());
+        match synonym_type_scopes.remove(&id) {
+            Some(scope) => header.push(format!("synonymtypedef: {} {} {}", id, description, scope)),
+            None => header.push(format!("synonymtypedef: {} {}", id, description)),
+        }
+    }
+    header.extend(header_tail.into_iter().map(|(_, line)| line));
+    if ontology_iri.is_some() {
+        header.push(format!("ontology: {}", ontology));
+    }
+
+    for line in &header {
+        writeln!(out, "{}", line)?;
+    }
+    for kind in ["Term", "Typedef", "Instance"].iter() {
+        for (id, stanza) in &writer.stanzas {
+            if stanza.kind.unwrap_or("Term") != *kind {
+                continue;
+            }
+            write!(out, "\n[{}]\nid: {}\n", kind, id)?;
+            for (_, tag, value, qualifiers) in &stanza.clauses {
+                writeln!(out, "{}", writer.clause(tag, value, qualifiers))?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,696 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+
+use horned_owl::model::*;
+use horned_owl::vocab::AnnotationBuiltIn;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    is_true_literal, IAO_DEFINITION, OBO_IN_OWL_HAS_DBXREF as HAS_DBXREF,
+    OBO_IN_OWL_HAS_SYNONYM_TYPE as HAS_SYNONYM_TYPE, OBO_IN_OWL_IN_SUBSET as IN_SUBSET,
+    OBO_IN_OWL_NS as OBO_IN_OWL, OBO_NS as OBO,
+};
+
+const SYNONYM_PREDICATES: [&str; 4] = [
+    "hasExactSynonym",
+    "hasNarrowSynonym",
+    "hasBroadSynonym",
+    "hasRelatedSynonym",
+];
+
+/// The CURIE prefixes expanded when reading, besides OBO-style `PREFIX:LOCAL` identifiers.
+const PREFIXES: [(&str, &str); 5] = [
+    ("rdf:", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs:", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("owl:", "http://www.w3.org/2002/07/owl#"),
+    ("xsd:", "http://www.w3.org/2001/XMLSchema#"),
+    ("oboInOwl:", OBO_IN_OWL),
+];
+
+const IS_A: &str = "is_a";
+const SUB_PROPERTY_OF: &str = "subPropertyOf";
+const INVERSE_OF: &str = "inverseOf";
+const TYPE: &str = "type";
+
+#[derive(Default)]
+struct Synonym {
+    pred: String,
+    val: String,
+    xrefs: Vec<String>,
+    synonym_type: Option<String>,
+}
+
+#[derive(Default)]
+struct Node {
+    node_type: Option<&'static str>,
+    property_type: Option<&'static str>,
+    lbl: Option<String>,
+    definition: Option<(String, Vec<String>)>,
+    comments: Vec<String>,
+    subsets: Vec<String>,
+    xrefs: Vec<String>,
+    synonyms: Vec<Synonym>,
+    basic_property_values: Vec<(String, String)>,
+    deprecated: bool,
+}
+
+impl Node {
+    fn to_json(&self, id: &str) -> Value {
+        let mut node = Map::new();
+        node.insert("id".into(), json!(id));
+        if let Some(lbl) = &self.lbl {
+            node.insert("lbl".into(), json!(lbl));
+        }
+        if let Some(node_type) = self.node_type {
+            node.insert("type".into(), json!(node_type));
+        }
+        if let Some(property_type) = self.property_type {
+            node.insert("propertyType".into(), json!(property_type));
+        }
+
+        let mut meta = Map::new();
+        if let Some((val, xrefs)) = &self.definition {
+            meta.insert("definition".into(), json!({"val": val, "xrefs": xrefs}));
+        }
+        if !self.comments.is_empty() {
+            meta.insert("comments".into(), json!(self.comments));
+        }
+        if !self.subsets.is_empty() {
+            meta.insert("subsets".into(), json!(self.subsets));
+        }
+        if !self.xrefs.is_empty() {
+            let xrefs: Vec<Value> = self.xrefs.iter().map(|x| json!({ "val": x })).collect();
+            meta.insert("xrefs".into(), Value::Array(xrefs));
+        }
+        if !self.synonyms.is_empty() {
+            let synonyms = self
+                .synonyms
+                .iter()
+                .map(|s| {
+                    let mut synonym = json!({"pred": s.pred, "val": s.val, "xrefs": s.xrefs});
+                    if let Some(synonym_type) = &s.synonym_type {
+                        synonym["synonymType"] = json!(synonym_type);
+                    }
+                    synonym
+                })
+                .collect();
+            meta.insert("synonyms".into(), Value::Array(synonyms));
+        }
+        if !self.basic_property_values.is_empty() {
+            meta.insert(
+                "basicPropertyValues".into(),
+                basic_property_values(&self.basic_property_values),
+            );
+        }
+        if self.deprecated {
+            meta.insert("deprecated".into(), json!(true));
+        }
+        if !meta.is_empty() {
+            node.insert("meta".into(), Value::Object(meta));
+        }
+
+        Value::Object(node)
+    }
+}
+
+fn basic_property_values(values: &[(String, String)]) -> Value {
+    values
+        .iter()
+        .map(|(pred, val)| json!({"pred": pred, "val": val}))
+        .collect()
+}
+
+/// The lexical form of an annotation value.
+fn value_string(av: &AnnotationValue<ArcStr>) -> String {
+    match av {
+        AnnotationValue::Literal(Literal::Simple { literal })
+        | AnnotationValue::Literal(Literal::Language { literal, .. })
+        | AnnotationValue::Literal(Literal::Datatype { literal, .. }) => literal.clone(),
+        AnnotationValue::IRI(iri) => iri.to_string(),
+        AnnotationValue::AnonymousIndividual(ai) => ai.0.to_string(),
+    }
+}
+
+/// The values of the annotations on an axiom with the property `ap`.
+fn axiom_annotations(ann: &BTreeSet<Annotation<ArcStr>>, ap: &str) -> Vec<String> {
+    ann.iter()
+        .filter(|a| a.ap.0.to_string() == ap)
+        .map(|a| value_string(&a.av))
+        .collect()
+}
+
+fn named_class(ce: &ClassExpression<ArcStr>) -> Option<String> {
+    match ce {
+        ClassExpression::Class(c) => Some(c.0.to_string()),
+        _ => None,
+    }
+}
+
+fn named_property(ope: &ObjectPropertyExpression<ArcStr>) -> Option<String> {
+    match ope {
+        ObjectPropertyExpression::ObjectProperty(op) => Some(op.0.to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the property and filler of an existential restriction on named entities.
+fn existential(ce: &ClassExpression<ArcStr>) -> Option<(String, String)> {
+    match ce {
+        ClassExpression::ObjectSomeValuesFrom { ope, bce } => {
+            Some((named_property(ope)?, named_class(bce)?))
+        }
+        _ => None,
+    }
+}
+
+/// Returns a logical definition for a class defined as the intersection of named classes and
+/// existential restrictions.
+fn logical_definition(operands: &[ClassExpression<ArcStr>]) -> Option<Value> {
+    let (defined, definition) = match operands {
+        [ClassExpression::Class(c), definition] | [definition, ClassExpression::Class(c)] => {
+            (c.0.to_string(), definition)
+        }
+        _ => return None,
+    };
+
+    let parts = match definition {
+        ClassExpression::ObjectIntersectionOf(parts) => parts,
+        _ => return None,
+    };
+
+    let mut genus_ids = Vec::new();
+    let mut restrictions = Vec::new();
+    for part in parts {
+        if let Some(genus) = named_class(part) {
+            genus_ids.push(genus);
+        } else {
+            let (property, filler) = existential(part)?;
+            restrictions.push(json!({"propertyId": property, "fillerId": filler}));
+        }
+    }
+
+    Some(json!({
+        "definedClassId": defined,
+        "genusIds": genus_ids,
+        "restrictions": restrictions,
+    }))
+}
+
+/// Writes the components of an ontology as an OBO Graphs JSON document with a single graph.
+///
+/// Declarations become nodes, labels, definitions, synonyms, xrefs, comments, subsets and
+/// deprecation the node metadata and any other annotation assertion a basic property value.
+/// `SubClassOf` axioms between named classes become `is_a` edges and those with an existential
+/// restriction on the superclass side edges labelled with the property. Equivalences with an
+/// intersection of named classes and existential restrictions become logical definitions. Nodes
+/// and edges are sorted by IRI.
+pub fn write<'a, W: Write>(components: impl Iterator<Item = &'a AnnotatedComponent<ArcStr>>, out: W) -> PyResult<()> {
+    let mut id: Option<String> = None;
+    let mut version: Option<String> = None;
+    let mut graph_annotations = Vec::new();
+    let mut nodes: BTreeMap<String, Node> = BTreeMap::new();
+    let mut edges: BTreeSet<(String, String, String)> = BTreeSet::new();
+    let mut equivalent_nodes_sets = Vec::new();
+    let mut logical_definitions = Vec::new();
+
+    for ac in components {
+        match &ac.component {
+            Component::OntologyID(oid) => {
+                id = oid.iri.as_ref().map(|iri| iri.to_string());
+                version = oid.viri.as_ref().map(|iri| iri.to_string());
+            }
+            Component::OntologyAnnotation(OntologyAnnotation(ann)) => {
+                graph_annotations.push((ann.ap.0.to_string(), value_string(&ann.av)));
+            }
+            Component::DeclareClass(DeclareClass(c)) => {
+                nodes.entry(c.0.to_string()).or_default().node_type = Some("CLASS");
+            }
+            Component::DeclareNamedIndividual(DeclareNamedIndividual(i)) => {
+                nodes.entry(i.0.to_string()).or_default().node_type = Some("INDIVIDUAL");
+            }
+            Component::DeclareObjectProperty(DeclareObjectProperty(p)) => {
+                let node = nodes.entry(p.0.to_string()).or_default();
+                node.node_type = Some("PROPERTY");
+                node.property_type = Some("OBJECT");
+            }
+            Component::DeclareDataProperty(DeclareDataProperty(p)) => {
+                let node = nodes.entry(p.0.to_string()).or_default();
+                node.node_type = Some("PROPERTY");
+                node.property_type = Some("DATA");
+            }
+            Component::DeclareAnnotationProperty(DeclareAnnotationProperty(p)) => {
+                let node = nodes.entry(p.0.to_string()).or_default();
+                node.node_type = Some("PROPERTY");
+                node.property_type = Some("ANNOTATION");
+            }
+            Component::AnnotationAssertion(AnnotationAssertion {
+                subject: AnnotationSubject::IRI(subject),
+                ann,
+            }) => {
+                let node = nodes.entry(subject.to_string()).or_default();
+                let ap = ann.ap.0.to_string();
+                let val = value_string(&ann.av);
+                let synonym = ap
+                    .strip_prefix(OBO_IN_OWL)
+                    .filter(|pred| SYNONYM_PREDICATES.contains(pred));
+
+                if ap == AnnotationBuiltIn::Label.as_ref() {
+                    node.lbl = Some(val);
+                } else if ap == IAO_DEFINITION {
+                    node.definition = Some((val, axiom_annotations(&ac.ann, HAS_DBXREF)));
+                } else if let Some(pred) = synonym {
+                    node.synonyms.push(Synonym {
+                        pred: pred.to_string(),
+                        val,
+                        xrefs: axiom_annotations(&ac.ann, HAS_DBXREF),
+                        synonym_type: axiom_annotations(&ac.ann, HAS_SYNONYM_TYPE).into_iter().next(),
+                    });
+                } else if ap == HAS_DBXREF {
+                    node.xrefs.push(val);
+                } else if ap == AnnotationBuiltIn::Comment.as_ref() {
+                    node.comments.push(val);
+                } else if ap == IN_SUBSET {
+                    node.subsets.push(val);
+                } else if ap == AnnotationBuiltIn::Deprecated.as_ref()
+                    && matches!(&ann.av, AnnotationValue::Literal(l) if is_true_literal(l))
+                {
+                    node.deprecated = true;
+                } else {
+                    node.basic_property_values.push((ap, val));
+                }
+            }
+            Component::SubClassOf(SubClassOf { sub, sup }) => {
+                if let Some(sub) = named_class(sub) {
+                    if let Some(sup) = named_class(sup) {
+                        edges.insert((sub, IS_A.to_string(), sup));
+                    } else if let Some((property, filler)) = existential(sup) {
+                        edges.insert((sub, property, filler));
+                    }
+                }
+            }
+            Component::SubObjectPropertyOf(SubObjectPropertyOf {
+                sub: SubObjectPropertyExpression::ObjectPropertyExpression(sub),
+                sup,
+            }) => {
+                if let (Some(sub), Some(sup)) = (named_property(sub), named_property(sup)) {
+                    edges.insert((sub, SUB_PROPERTY_OF.to_string(), sup));
+                }
+            }
+            Component::SubDataPropertyOf(SubDataPropertyOf { sub, sup }) => {
+                edges.insert((sub.0.to_string(), SUB_PROPERTY_OF.to_string(), sup.0.to_string()));
+            }
+            Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf { sub, sup }) => {
+                edges.insert((sub.0.to_string(), SUB_PROPERTY_OF.to_string(), sup.0.to_string()));
+            }
+            Component::InverseObjectProperties(InverseObjectProperties(a, b)) => {
+                edges.insert((a.0.to_string(), INVERSE_OF.to_string(), b.0.to_string()));
+            }
+            Component::ClassAssertion(ClassAssertion {
+                ce,
+                i: Individual::Named(i),
+            }) => {
+                if let Some(c) = named_class(ce) {
+                    edges.insert((i.0.to_string(), TYPE.to_string(), c));
+                }
+            }
+            Component::ObjectPropertyAssertion(ObjectPropertyAssertion {
+                ope,
+                from: Individual::Named(from),
+                to: Individual::Named(to),
+            }) => {
+                if let Some(p) = named_property(ope) {
+                    edges.insert((from.0.to_string(), p, to.0.to_string()));
+                }
+            }
+            Component::EquivalentClasses(EquivalentClasses(operands)) => {
+                let named: Option<Vec<String>> = operands.iter().map(named_class).collect();
+                match named {
+                    Some(mut node_ids) => {
+                        node_ids.sort();
+                        equivalent_nodes_sets.push(json!({ "nodeIds": node_ids }));
+                    }
+                    None => logical_definitions.extend(logical_definition(operands)),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut graph = Map::new();
+    graph.insert("id".into(), json!(id.unwrap_or_default()));
+
+    let mut meta = Map::new();
+    if let Some(version) = version {
+        meta.insert("version".into(), json!(version));
+    }
+    if !graph_annotations.is_empty() {
+        graph_annotations.sort();
+        meta.insert("basicPropertyValues".into(), basic_property_values(&graph_annotations));
+    }
+    if !meta.is_empty() {
+        graph.insert("meta".into(), Value::Object(meta));
+    }
+
+    let nodes: Vec<Value> = nodes.iter().map(|(id, node)| node.to_json(id)).collect();
+    let edges: Vec<Value> = edges
+        .into_iter()
+        .map(|(sub, pred, obj)| json!({"sub": sub, "pred": pred, "obj": obj}))
+        .collect();
+    logical_definitions.sort_by_key(|d: &Value| d["definedClassId"].to_string());
+
+    graph.insert("nodes".into(), Value::Array(nodes));
+    graph.insert("edges".into(), Value::Array(edges));
+    graph.insert("equivalentNodesSets".into(), Value::Array(equivalent_nodes_sets));
+    graph.insert("logicalDefinitionAxioms".into(), Value::Array(logical_definitions));
+
+    serde_json::to_writer_pretty(out, &json!({ "graphs": [graph] })).map_err(std::io::Error::from)?;
+    Ok(())
+}
+
+/// Expands an OBO Graphs identifier to an IRI.
+///
+/// Full IRIs are kept, well-known CURIEs expanded and other `PREFIX:LOCAL` identifiers mapped to
+/// `http://purl.obolibrary.org/obo/PREFIX_LOCAL` following the OBO convention.
+fn expand(id: &str) -> String {
+    if id.contains("://") || id.starts_with("urn:") {
+        return id.to_string();
+    }
+    for (prefix, namespace) in PREFIXES.iter() {
+        if let Some(local) = id.strip_prefix(prefix) {
+            return format!("{}{}", namespace, local);
+        }
+    }
+    match id.split_once(':') {
+        Some((prefix, local)) if !prefix.is_empty() => format!("{}{}_{}", OBO, prefix, local),
+        _ => id.to_string(),
+    }
+}
+
+struct Reader<'a> {
+    build: &'a Build<ArcStr>,
+    components: Vec<AnnotatedComponent<ArcStr>>,
+    node_types: BTreeMap<String, (String, String)>,
+}
+
+impl<'a> Reader<'a> {
+    fn add(&mut self, component: Component<ArcStr>, ann: BTreeSet<Annotation<ArcStr>>) {
+        self.components.push(AnnotatedComponent { component, ann });
+    }
+
+    fn literal(&self, value: &str) -> AnnotationValue<ArcStr> {
+        AnnotationValue::Literal(Literal::Simple {
+            literal: value.to_string(),
+        })
+    }
+
+    fn annotation(&self, ap: &str, av: AnnotationValue<ArcStr>) -> Annotation<ArcStr> {
+        Annotation {
+            ap: self.build.annotation_property(ap),
+            av,
+        }
+    }
+
+    fn assert(&mut self, subject: &IRI<ArcStr>, ap: &str, av: AnnotationValue<ArcStr>, ann: BTreeSet<Annotation<ArcStr>>) {
+        let ann_assertion = AnnotationAssertion {
+            subject: AnnotationSubject::IRI(subject.clone()),
+            ann: self.annotation(ap, av),
+        };
+        self.add(Component::AnnotationAssertion(ann_assertion), ann);
+    }
+
+    fn xref_annotations(&self, value: &Value) -> BTreeSet<Annotation<ArcStr>> {
+        strings(&value["xrefs"])
+            .iter()
+            .map(|x| self.annotation(HAS_DBXREF, self.literal(x)))
+            .collect()
+    }
+
+    fn class(&self, id: &str) -> ClassExpression<ArcStr> {
+        ClassExpression::Class(self.build.class(expand(id)))
+    }
+
+    fn node_type(&self, id: &str) -> (&str, &str) {
+        self.node_types
+            .get(&expand(id))
+            .map(|(t, p)| (t.as_str(), p.as_str()))
+            .unwrap_or(("", ""))
+    }
+
+    fn graph(&mut self, graph: &Value) -> PyResult<()> {
+        let nodes = graph["nodes"].as_array().map(Vec::as_slice).unwrap_or(&[]);
+        for node in nodes {
+            if let Some(id) = node["id"].as_str() {
+                self.node_types.insert(
+                    expand(id),
+                    (
+                        node["type"].as_str().unwrap_or_default().to_string(),
+                        node["propertyType"].as_str().unwrap_or_default().to_string(),
+                    ),
+                );
+            }
+        }
+        for node in nodes {
+            self.node(node)?;
+        }
+
+        for edge in graph["edges"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            self.edge(edge)?;
+        }
+
+        for set in graph["equivalentNodesSets"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            let classes = strings(&set["nodeIds"]).iter().map(|id| self.class(id)).collect();
+            self.add(Component::EquivalentClasses(EquivalentClasses(classes)), BTreeSet::new());
+        }
+
+        for definition in graph["logicalDefinitionAxioms"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            self.logical_definition(definition)?;
+        }
+
+        Ok(())
+    }
+
+    fn node(&mut self, node: &Value) -> PyResult<()> {
+        let id = node["id"]
+            .as_str()
+            .ok_or_else(|| PyValueError::new_err(format!("OBO Graphs node without an id: {}", node)))?;
+        let iri = self.build.iri(expand(id));
+
+        let declaration = match (node["type"].as_str(), node["propertyType"].as_str()) {
+            (Some("CLASS"), _) => Some(Component::DeclareClass(DeclareClass(Class(iri.clone())))),
+            (Some("INDIVIDUAL"), _) => Some(Component::DeclareNamedIndividual(DeclareNamedIndividual(NamedIndividual(iri.clone())))),
+            (Some("PROPERTY"), Some("ANNOTATION")) => Some(Component::DeclareAnnotationProperty(DeclareAnnotationProperty(AnnotationProperty(iri.clone())))),
+            (Some("PROPERTY"), Some("DATA")) => Some(Component::DeclareDataProperty(DeclareDataProperty(DataProperty(iri.clone())))),
+            (Some("PROPERTY"), _) => Some(Component::DeclareObjectProperty(DeclareObjectProperty(ObjectProperty(iri.clone())))),
+            _ => None,
+        };
+        if let Some(declaration) = declaration {
+            self.add(declaration, BTreeSet::new());
+        }
+
+        if let Some(lbl) = node["lbl"].as_str() {
+            let label = self.literal(lbl);
+            self.assert(&iri, AnnotationBuiltIn::Label.as_ref(), label, BTreeSet::new());
+        }
+
+        let meta = &node["meta"];
+        if let Some(val) = meta["definition"]["val"].as_str() {
+            let ann = self.xref_annotations(&meta["definition"]);
+            let definition = self.literal(val);
+            self.assert(&iri, IAO_DEFINITION, definition, ann);
+        }
+        for comment in strings(&meta["comments"]) {
+            let comment = self.literal(&comment);
+            self.assert(&iri, AnnotationBuiltIn::Comment.as_ref(), comment, BTreeSet::new());
+        }
+        for subset in strings(&meta["subsets"]) {
+            let subset = AnnotationValue::IRI(self.build.iri(expand(&subset)));
+            self.assert(&iri, IN_SUBSET, subset, BTreeSet::new());
+        }
+        for xref in meta["xrefs"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            if let Some(val) = xref["val"].as_str() {
+                let xref = self.literal(val);
+                self.assert(&iri, HAS_DBXREF, xref, BTreeSet::new());
+            }
+        }
+        for synonym in meta["synonyms"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            let (pred, val) = match (synonym["pred"].as_str(), synonym["val"].as_str()) {
+                (Some(pred), Some(val)) => (pred, val),
+                _ => continue,
+            };
+            let mut ann = self.xref_annotations(synonym);
+            if let Some(synonym_type) = synonym["synonymType"].as_str() {
+                ann.insert(self.annotation(
+                    HAS_SYNONYM_TYPE,
+                    AnnotationValue::IRI(self.build.iri(expand(synonym_type))),
+                ));
+            }
+            let pred = if pred.contains(':') {
+                expand(pred)
+            } else {
+                format!("{}{}", OBO_IN_OWL, pred)
+            };
+            let synonym = self.literal(val);
+            self.assert(&iri, &pred, synonym, ann);
+        }
+        for bpv in meta["basicPropertyValues"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            if let (Some(pred), Some(val)) = (bpv["pred"].as_str(), bpv["val"].as_str()) {
+                let value = self.literal(val);
+                self.assert(&iri, &expand(pred), value, BTreeSet::new());
+            }
+        }
+        if meta["deprecated"].as_bool() == Some(true) {
+            let deprecated = AnnotationValue::Literal(Literal::Datatype {
+                literal: "true".to_string(),
+                datatype_iri: self.build.iri(crate::XSD_BOOLEAN),
+            });
+            self.assert(&iri, AnnotationBuiltIn::Deprecated.as_ref(), deprecated, BTreeSet::new());
+        }
+
+        Ok(())
+    }
+
+    fn edge(&mut self, edge: &Value) -> PyResult<()> {
+        let (sub, pred, obj) = match (edge["sub"].as_str(), edge["pred"].as_str(), edge["obj"].as_str()) {
+            (Some(sub), Some(pred), Some(obj)) => (sub, pred, obj),
+            _ => return Err(PyValueError::new_err(format!("Incomplete OBO Graphs edge: {}", edge))),
+        };
+        let (sub_type, property_type) = self.node_type(sub);
+        let is_individual = sub_type == "INDIVIDUAL";
+
+        let component = match pred {
+            //rdf:type makes the subject an individual even if it is not declared as a node
+            IS_A | TYPE if is_individual || pred == TYPE => Component::ClassAssertion(ClassAssertion {
+                ce: self.class(obj),
+                i: Individual::Named(self.build.named_individual(expand(sub))),
+            }),
+            IS_A => Component::SubClassOf(SubClassOf {
+                sub: self.class(sub),
+                sup: self.class(obj),
+            }),
+            SUB_PROPERTY_OF => match property_type {
+                "ANNOTATION" => Component::SubAnnotationPropertyOf(SubAnnotationPropertyOf {
+                    sub: self.build.annotation_property(expand(sub)),
+                    sup: self.build.annotation_property(expand(obj)),
+                }),
+                "DATA" => Component::SubDataPropertyOf(SubDataPropertyOf {
+                    sub: self.build.data_property(expand(sub)),
+                    sup: self.build.data_property(expand(obj)),
+                }),
+                _ => Component::SubObjectPropertyOf(SubObjectPropertyOf {
+                    sub: SubObjectPropertyExpression::ObjectPropertyExpression(
+                        ObjectPropertyExpression::ObjectProperty(self.build.object_property(expand(sub))),
+                    ),
+                    sup: ObjectPropertyExpression::ObjectProperty(self.build.object_property(expand(obj))),
+                }),
+            },
+            INVERSE_OF => Component::InverseObjectProperties(InverseObjectProperties(
+                self.build.object_property(expand(sub)),
+                self.build.object_property(expand(obj)),
+            )),
+            _ if is_individual => Component::ObjectPropertyAssertion(ObjectPropertyAssertion {
+                ope: ObjectPropertyExpression::ObjectProperty(self.build.object_property(expand(pred))),
+                from: Individual::Named(self.build.named_individual(expand(sub))),
+                to: Individual::Named(self.build.named_individual(expand(obj))),
+            }),
+            _ => Component::SubClassOf(SubClassOf {
+                sub: self.class(sub),
+                sup: ClassExpression::ObjectSomeValuesFrom {
+                    ope: ObjectPropertyExpression::ObjectProperty(self.build.object_property(expand(pred))),
+                    bce: Box::new(self.class(obj)),
+                },
+            }),
+        };
+        self.add(component, BTreeSet::new());
+
+        Ok(())
+    }
+
+    fn logical_definition(&mut self, definition: &Value) -> PyResult<()> {
+        let defined = definition["definedClassId"].as_str().ok_or_else(|| {
+            PyValueError::new_err(format!("OBO Graphs logical definition without a class: {}", definition))
+        })?;
+
+        let mut parts: Vec<ClassExpression<ArcStr>> = strings(&definition["genusIds"])
+            .iter()
+            .map(|id| self.class(id))
+            .collect();
+        for restriction in definition["restrictions"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            if let (Some(property), Some(filler)) =
+                (restriction["propertyId"].as_str(), restriction["fillerId"].as_str())
+            {
+                parts.push(ClassExpression::ObjectSomeValuesFrom {
+                    ope: ObjectPropertyExpression::ObjectProperty(self.build.object_property(expand(property))),
+                    bce: Box::new(self.class(filler)),
+                });
+            }
+        }
+
+        let definition = match parts.len() {
+            0 => return Ok(()),
+            1 => parts.remove(0),
+            _ => ClassExpression::ObjectIntersectionOf(parts),
+        };
+        let defined = self.class(defined);
+        self.add(
+            Component::EquivalentClasses(EquivalentClasses(vec![defined, definition])),
+            BTreeSet::new(),
+        );
+
+        Ok(())
+    }
+}
+
+fn strings(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Reads the components of an OBO Graphs JSON document, the inverse of `write`.
+///
+/// All graphs of the document are merged, the id and version of the first becoming the ontology
+/// ID. Values of basic property values are read as plain literals.
+pub fn read(text: &str, build: &Build<ArcStr>) -> PyResult<Vec<AnnotatedComponent<ArcStr>>> {
+    let document: Value = serde_json::from_str(text).map_err(to_py_err)?;
+    let graphs = document["graphs"]
+        .as_array()
+        .ok_or_else(|| PyValueError::new_err("Not an OBO Graphs document: missing 'graphs'."))?;
+
+    let mut reader = Reader {
+        build,
+        components: Vec::new(),
+        node_types: BTreeMap::new(),
+    };
+
+    if let Some(graph) = graphs.first() {
+        let iri = graph["id"].as_str().filter(|id| !id.is_empty()).map(|id| build.iri(id));
+        let viri = graph["meta"]["version"].as_str().map(|v| build.iri(v));
+        if iri.is_some() || viri.is_some() {
+            reader.add(Component::OntologyID(OntologyID { iri, viri }), BTreeSet::new());
+        }
+    }
+
+    for graph in graphs {
+        for bpv in graph["meta"]["basicPropertyValues"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            if let (Some(pred), Some(val)) = (bpv["pred"].as_str(), bpv["val"].as_str()) {
+                let ann = reader.annotation(&expand(pred), reader.literal(val));
+                reader.add(Component::OntologyAnnotation(OntologyAnnotation(ann)), BTreeSet::new());
+            }
+        }
+        reader.graph(graph)?;
+    }
+
+    Ok(reader.components)
+}
+
+fn to_py_err<E: std::fmt::Display>(error: E) -> PyErr {
+    PyValueError::new_err(format!("Invalid OBO Graphs JSON: {}", error))
+}
@@ -0,0 +1,563 @@
+use std::collections::HashMap;
+
+use horned_owl::model::*;
+use horned_owl::visitor::immutable::{Visit, Walk};
+use pyo3::prelude::*;
+
+use crate::model as py_model;
+
+/// The value a pattern variable is bound to.
+///
+/// Variables standing for a class, object property expression, data range, individual, literal
+/// or annotation value bind to the whole expression in that position, all other variables bind
+/// to an IRI.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binding {
+    Iri(IRI<ArcStr>),
+    ClassExpression(ClassExpression<ArcStr>),
+    ObjectPropertyExpression(ObjectPropertyExpression<ArcStr>),
+    DataRange(DataRange<ArcStr>),
+    Individual(Individual<ArcStr>),
+    Literal(Literal<ArcStr>),
+    AnnotationValue(AnnotationValue<ArcStr>),
+}
+
+impl IntoPy<PyObject> for Binding {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Binding::Iri(v) => py_model::IRI::from(v).into_py(py),
+            Binding::ClassExpression(v) => py_model::ClassExpression::from(v).into_py(py),
+            Binding::ObjectPropertyExpression(v) => py_model::ObjectPropertyExpression::from(v).into_py(py),
+            Binding::DataRange(v) => py_model::DataRange::from(v).into_py(py),
+            Binding::Individual(v) => py_model::Individual::from(v).into_py(py),
+            Binding::Literal(v) => py_model::Literal::from(v).into_py(py),
+            Binding::AnnotationValue(v) => py_model::AnnotationValue::from(v).into_py(py),
+        }
+    }
+}
+
+pub type Bindings = HashMap<String, Binding>;
+
+/// Returns the name of the variable if `iri` is a pattern variable, i.e. starts with `?`.
+///
+/// The anonymous variable `?` has the empty name.
+fn variable(iri: &IRI<ArcStr>) -> Option<String> {
+    iri.to_string().strip_prefix('?').map(str::to_string)
+}
+
+fn bind(name: String, value: Binding, bindings: &mut Bindings) -> bool {
+    if name.is_empty() {
+        return true;
+    }
+
+    match bindings.get(&name) {
+        Some(bound) => *bound == value,
+        None => {
+            bindings.insert(name, value);
+            true
+        }
+    }
+}
+
+/// Structural matching of a pattern against a value of the same type.
+///
+/// `self` is the pattern and may contain variables, `other` is the value from the ontology. If
+/// matching fails `bindings` may be left with some of the variables bound, so callers trying
+/// alternatives unify against a clone.
+pub trait Unify {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool;
+}
+
+/// Vectors hold the operands of n-ary constructs, which are sets in OWL, so they are matched
+/// regardless of order. Sequences use `unify_sequence`.
+impl<T: Unify> Unify for Vec<T> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let mut used = vec![false; other.len()];
+        match unify_unordered(self, other, &mut used, bindings) {
+            Some(matched) => {
+                *bindings = matched;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Matches each of `pattern` against a different, not yet `used`, element of `other`, trying
+/// every assignment until the bindings agree. Returns the bindings of the first that succeeds.
+fn unify_unordered<T: Unify>(pattern: &[T], other: &[T], used: &mut [bool], bindings: &Bindings) -> Option<Bindings> {
+    let (first, rest) = match pattern.split_first() {
+        Some(split) => split,
+        None => return Some(bindings.clone()),
+    };
+
+    for i in 0..other.len() {
+        if used[i] {
+            continue;
+        }
+
+        let mut attempt = bindings.clone();
+        if first.unify(&other[i], &mut attempt) {
+            used[i] = true;
+            if let Some(matched) = unify_unordered(rest, other, used, &attempt) {
+                return Some(matched);
+            }
+            used[i] = false;
+        }
+    }
+
+    None
+}
+
+/// Matches the elements of a sequence, such as a property chain, position by position.
+fn unify_sequence<T: Unify>(pattern: &[T], other: &[T], bindings: &mut Bindings) -> bool {
+    pattern.len() == other.len() && pattern.iter().zip(other.iter()).all(|(s, o)| s.unify(o, bindings))
+}
+
+impl<T: Unify> Unify for Box<T> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        (**self).unify(other, bindings)
+    }
+}
+
+impl<T: Unify> Unify for Option<T> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        match (self, other) {
+            (None, None) => true,
+            (Some(s), Some(o)) => s.unify(o, bindings),
+            _ => false,
+        }
+    }
+}
+
+macro_rules! unify_eq {
+    ($($type:ty),*) => {
+        $(
+            impl Unify for $type {
+                fn unify(&self, other: &Self, _: &mut Bindings) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+unify_eq!(
+    u32,
+    horned_owl::vocab::Facet,
+    AnonymousIndividual<ArcStr>,
+    Rule<ArcStr>
+);
+
+impl Unify for IRI<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        match variable(self) {
+            Some(name) => bind(name, Binding::Iri(other.clone()), bindings),
+            None => self == other,
+        }
+    }
+}
+
+macro_rules! unify_tuple {
+    ($($name:ident ( $($idx:tt),+ );)*) => {
+        $(
+            impl Unify for $name<ArcStr> {
+                fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+                    true $(&& self.$idx.unify(&other.$idx, bindings))+
+                }
+            }
+        )*
+    };
+}
+
+unify_tuple! {
+    Class(0);
+    ObjectProperty(0);
+    DataProperty(0);
+    AnnotationProperty(0);
+    NamedIndividual(0);
+    Datatype(0);
+    DocIRI(0);
+    Import(0);
+    OntologyAnnotation(0);
+    DeclareClass(0);
+    DeclareObjectProperty(0);
+    DeclareAnnotationProperty(0);
+    DeclareDataProperty(0);
+    DeclareNamedIndividual(0);
+    DeclareDatatype(0);
+    EquivalentClasses(0);
+    DisjointClasses(0);
+    DisjointUnion(0, 1);
+    EquivalentObjectProperties(0);
+    DisjointObjectProperties(0);
+    InverseObjectProperties(0, 1);
+    FunctionalObjectProperty(0);
+    InverseFunctionalObjectProperty(0);
+    ReflexiveObjectProperty(0);
+    IrreflexiveObjectProperty(0);
+    SymmetricObjectProperty(0);
+    AsymmetricObjectProperty(0);
+    TransitiveObjectProperty(0);
+    EquivalentDataProperties(0);
+    DisjointDataProperties(0);
+    FunctionalDataProperty(0);
+    SameIndividual(0);
+    DifferentIndividuals(0);
+}
+
+macro_rules! unify_struct {
+    ($($name:ident { $($field:ident),+ };)*) => {
+        $(
+            impl Unify for $name<ArcStr> {
+                fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+                    true $(&& self.$field.unify(&other.$field, bindings))+
+                }
+            }
+        )*
+    };
+}
+
+unify_struct! {
+    FacetRestriction { f, l };
+    Annotation { ap, av };
+    OntologyID { iri, viri };
+    SubClassOf { sup, sub };
+    SubObjectPropertyOf { sup, sub };
+    ObjectPropertyDomain { ope, ce };
+    ObjectPropertyRange { ope, ce };
+    SubDataPropertyOf { sup, sub };
+    DataPropertyDomain { dp, ce };
+    DataPropertyRange { dp, dr };
+    DatatypeDefinition { kind, range };
+    HasKey { ce, vpe };
+    ClassAssertion { ce, i };
+    ObjectPropertyAssertion { ope, from, to };
+    NegativeObjectPropertyAssertion { ope, from, to };
+    DataPropertyAssertion { dp, from, to };
+    NegativeDataPropertyAssertion { dp, from, to };
+    AnnotationAssertion { subject, ann };
+    SubAnnotationPropertyOf { sup, sub };
+    AnnotationPropertyDomain { ap, iri };
+    AnnotationPropertyRange { ap, iri };
+}
+
+impl Unify for Individual<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        if let Individual::Named(NamedIndividual(iri)) = self {
+            if let Some(name) = variable(iri) {
+                return bind(name, Binding::Individual(other.clone()), bindings);
+            }
+        }
+
+        match (self, other) {
+            (Individual::Named(s), Individual::Named(o)) => s.unify(o, bindings),
+            (Individual::Anonymous(s), Individual::Anonymous(o)) => s.unify(o, bindings),
+            _ => false,
+        }
+    }
+}
+
+impl Unify for ObjectPropertyExpression<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        if let ObjectPropertyExpression::ObjectProperty(ObjectProperty(iri)) = self {
+            if let Some(name) = variable(iri) {
+                return bind(name, Binding::ObjectPropertyExpression(other.clone()), bindings);
+            }
+        }
+
+        match (self, other) {
+            (ObjectPropertyExpression::ObjectProperty(s), ObjectPropertyExpression::ObjectProperty(o)) => {
+                s.unify(o, bindings)
+            }
+            (
+                ObjectPropertyExpression::InverseObjectProperty(s),
+                ObjectPropertyExpression::InverseObjectProperty(o),
+            ) => s.unify(o, bindings),
+            _ => false,
+        }
+    }
+}
+
+impl Unify for Literal<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        if let Literal::Simple { literal } = self {
+            if let Some(name) = literal.strip_prefix('?') {
+                return bind(name.to_string(), Binding::Literal(other.clone()), bindings);
+            }
+        }
+
+        match (self, other) {
+            (Literal::Simple { literal: s }, Literal::Simple { literal: o }) => s == o,
+            (
+                Literal::Language {
+                    literal: s,
+                    lang: s_lang,
+                },
+                Literal::Language {
+                    literal: o,
+                    lang: o_lang,
+                },
+            ) => s == o && s_lang == o_lang,
+            (
+                Literal::Datatype {
+                    literal: s,
+                    datatype_iri: s_dt,
+                },
+                Literal::Datatype {
+                    literal: o,
+                    datatype_iri: o_dt,
+                },
+            ) => s == o && s_dt.unify(o_dt, bindings),
+            _ => false,
+        }
+    }
+}
+
+impl Unify for DataRange<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        if let DataRange::Datatype(Datatype(iri)) = self {
+            if let Some(name) = variable(iri) {
+                return bind(name, Binding::DataRange(other.clone()), bindings);
+            }
+        }
+
+        match (self, other) {
+            (DataRange::Datatype(s), DataRange::Datatype(o)) => s.unify(o, bindings),
+            (DataRange::DataIntersectionOf(s), DataRange::DataIntersectionOf(o)) => s.unify(o, bindings),
+            (DataRange::DataUnionOf(s), DataRange::DataUnionOf(o)) => s.unify(o, bindings),
+            (DataRange::DataComplementOf(s), DataRange::DataComplementOf(o)) => s.unify(o, bindings),
+            (DataRange::DataOneOf(s), DataRange::DataOneOf(o)) => s.unify(o, bindings),
+            (DataRange::DatatypeRestriction(s_dt, s), DataRange::DatatypeRestriction(o_dt, o)) => {
+                s_dt.unify(o_dt, bindings) && s.unify(o, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Unify for ClassExpression<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        use ClassExpression::*;
+
+        if let Class(horned_owl::model::Class(iri)) = self {
+            if let Some(name) = variable(iri) {
+                return bind(name, Binding::ClassExpression(other.clone()), bindings);
+            }
+        }
+
+        match (self, other) {
+            (Class(s), Class(o)) => s.unify(o, bindings),
+            (ObjectIntersectionOf(s), ObjectIntersectionOf(o)) => s.unify(o, bindings),
+            (ObjectUnionOf(s), ObjectUnionOf(o)) => s.unify(o, bindings),
+            (ObjectComplementOf(s), ObjectComplementOf(o)) => s.unify(o, bindings),
+            (ObjectOneOf(s), ObjectOneOf(o)) => s.unify(o, bindings),
+            (ObjectSomeValuesFrom { ope: s_ope, bce: s }, ObjectSomeValuesFrom { ope: o_ope, bce: o })
+            | (ObjectAllValuesFrom { ope: s_ope, bce: s }, ObjectAllValuesFrom { ope: o_ope, bce: o }) => {
+                s_ope.unify(o_ope, bindings) && s.unify(o, bindings)
+            }
+            (ObjectHasValue { ope: s_ope, i: s }, ObjectHasValue { ope: o_ope, i: o }) => {
+                s_ope.unify(o_ope, bindings) && s.unify(o, bindings)
+            }
+            (ObjectHasSelf(s), ObjectHasSelf(o)) => s.unify(o, bindings),
+            (
+                ObjectMinCardinality { n: s_n, ope: s_ope, bce: s },
+                ObjectMinCardinality { n: o_n, ope: o_ope, bce: o },
+            )
+            | (
+                ObjectMaxCardinality { n: s_n, ope: s_ope, bce: s },
+                ObjectMaxCardinality { n: o_n, ope: o_ope, bce: o },
+            )
+            | (
+                ObjectExactCardinality { n: s_n, ope: s_ope, bce: s },
+                ObjectExactCardinality { n: o_n, ope: o_ope, bce: o },
+            ) => s_n.unify(o_n, bindings) && s_ope.unify(o_ope, bindings) && s.unify(o, bindings),
+            (DataSomeValuesFrom { dp: s_dp, dr: s }, DataSomeValuesFrom { dp: o_dp, dr: o })
+            | (DataAllValuesFrom { dp: s_dp, dr: s }, DataAllValuesFrom { dp: o_dp, dr: o }) => {
+                s_dp.unify(o_dp, bindings) && s.unify(o, bindings)
+            }
+            (DataHasValue { dp: s_dp, l: s }, DataHasValue { dp: o_dp, l: o }) => {
+                s_dp.unify(o_dp, bindings) && s.unify(o, bindings)
+            }
+            (
+                DataMinCardinality { n: s_n, dp: s_dp, dr: s },
+                DataMinCardinality { n: o_n, dp: o_dp, dr: o },
+            )
+            | (
+                DataMaxCardinality { n: s_n, dp: s_dp, dr: s },
+                DataMaxCardinality { n: o_n, dp: o_dp, dr: o },
+            )
+            | (
+                DataExactCardinality { n: s_n, dp: s_dp, dr: s },
+                DataExactCardinality { n: o_n, dp: o_dp, dr: o },
+            ) => s_n.unify(o_n, bindings) && s_dp.unify(o_dp, bindings) && s.unify(o, bindings),
+            _ => false,
+        }
+    }
+}
+
+impl Unify for PropertyExpression<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        match (self, other) {
+            (PropertyExpression::ObjectPropertyExpression(s), PropertyExpression::ObjectPropertyExpression(o)) => {
+                s.unify(o, bindings)
+            }
+            (PropertyExpression::DataProperty(s), PropertyExpression::DataProperty(o)) => s.unify(o, bindings),
+            (PropertyExpression::AnnotationProperty(s), PropertyExpression::AnnotationProperty(o)) => {
+                s.unify(o, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Unify for SubObjectPropertyExpression<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        match (self, other) {
+            (
+                SubObjectPropertyExpression::ObjectPropertyChain(s),
+                SubObjectPropertyExpression::ObjectPropertyChain(o),
+            ) => unify_sequence(s, o, bindings),
+            (
+                SubObjectPropertyExpression::ObjectPropertyExpression(s),
+                SubObjectPropertyExpression::ObjectPropertyExpression(o),
+            ) => s.unify(o, bindings),
+            _ => false,
+        }
+    }
+}
+
+impl Unify for AnnotationSubject<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        match (self, other) {
+            (AnnotationSubject::IRI(s), AnnotationSubject::IRI(o)) => s.unify(o, bindings),
+            (AnnotationSubject::AnonymousIndividual(s), AnnotationSubject::AnonymousIndividual(o)) => {
+                s.unify(o, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Unify for AnnotationValue<ArcStr> {
+    fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+        if let AnnotationValue::IRI(iri) = self {
+            if let Some(name) = variable(iri) {
+                return bind(name, Binding::AnnotationValue(other.clone()), bindings);
+            }
+        }
+
+        match (self, other) {
+            (AnnotationValue::Literal(s), AnnotationValue::Literal(o)) => s.unify(o, bindings),
+            (AnnotationValue::IRI(s), AnnotationValue::IRI(o)) => s.unify(o, bindings),
+            (AnnotationValue::AnonymousIndividual(s), AnnotationValue::AnonymousIndividual(o)) => {
+                s.unify(o, bindings)
+            }
+            _ => false,
+        }
+    }
+}
+
+macro_rules! unify_component {
+    ($($variant:ident),*) => {
+        impl Unify for Component<ArcStr> {
+            fn unify(&self, other: &Self, bindings: &mut Bindings) -> bool {
+                match (self, other) {
+                    $((Component::$variant(s), Component::$variant(o)) => s.unify(o, bindings),)*
+                    _ => false,
+                }
+            }
+        }
+    };
+}
+
+unify_component!(
+    Import,
+    OntologyAnnotation,
+    DocIRI,
+    OntologyID,
+    DeclareClass,
+    DeclareObjectProperty,
+    DeclareAnnotationProperty,
+    DeclareDataProperty,
+    DeclareNamedIndividual,
+    DeclareDatatype,
+    SubClassOf,
+    EquivalentClasses,
+    DisjointClasses,
+    DisjointUnion,
+    SubObjectPropertyOf,
+    EquivalentObjectProperties,
+    DisjointObjectProperties,
+    InverseObjectProperties,
+    ObjectPropertyDomain,
+    ObjectPropertyRange,
+    FunctionalObjectProperty,
+    InverseFunctionalObjectProperty,
+    ReflexiveObjectProperty,
+    IrreflexiveObjectProperty,
+    SymmetricObjectProperty,
+    AsymmetricObjectProperty,
+    TransitiveObjectProperty,
+    SubDataPropertyOf,
+    EquivalentDataProperties,
+    DisjointDataProperties,
+    DataPropertyDomain,
+    DataPropertyRange,
+    FunctionalDataProperty,
+    DatatypeDefinition,
+    HasKey,
+    SameIndividual,
+    DifferentIndividuals,
+    ClassAssertion,
+    ObjectPropertyAssertion,
+    NegativeObjectPropertyAssertion,
+    DataPropertyAssertion,
+    NegativeDataPropertyAssertion,
+    AnnotationAssertion,
+    SubAnnotationPropertyOf,
+    AnnotationPropertyDomain,
+    AnnotationPropertyRange,
+    Rule
+);
+
+struct ConstantIRIs(Vec<IRI<ArcStr>>);
+
+impl Visit<ArcStr> for ConstantIRIs {
+    fn visit_iri(&mut self, iri: &IRI<ArcStr>) {
+        if variable(iri).is_none() {
+            self.0.push(iri.clone());
+        }
+    }
+}
+
+/// Returns all IRIs of a pattern that are not variables.
+///
+/// Every component matching the pattern references all of them, so any can be used to look up
+/// candidates in the IRI index.
+pub fn constant_iris(pattern: &Component<ArcStr>) -> Vec<IRI<ArcStr>> {
+    let mut walk = Walk::new(ConstantIRIs(Vec::new()));
+    walk.component(pattern);
+    walk.into_visit().0
+}
+
+/// Matches `pattern` against each candidate and returns the matching components with their bindings.
+pub fn find_matches<'a, I>(pattern: &Component<ArcStr>, candidates: I) -> Vec<(AnnotatedComponent<ArcStr>, Bindings)>
+where
+    I: Iterator<Item = &'a AnnotatedComponent<ArcStr>>,
+{
+    candidates
+        .filter_map(|aax| {
+            let mut bindings = Bindings::new();
+            if pattern.unify(&aax.component, &mut bindings) {
+                Some((aax.clone(), bindings))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use curie::PrefixMapping;
+use oxigraph::io::{RdfFormat, RdfParser, RdfSerializer};
+use oxigraph::model::{BlankNode, Subject, Term, Triple};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Parses RDF in `format` into its triples.
+///
+/// Blank nodes are relabelled `b0`, `b1`, ... in the order they first occur so that documents
+/// written from the same ontology produce the same labels.
+pub fn triples(data: &[u8], format: RdfFormat) -> PyResult<Vec<Triple>> {
+    let mut labels: HashMap<BlankNode, BlankNode> = HashMap::new();
+    let mut relabel = |node: &BlankNode| {
+        let next = labels.len();
+        labels
+            .entry(node.clone())
+            .or_insert_with(|| BlankNode::new_unchecked(format!("b{}", next)))
+            .clone()
+    };
+
+    let mut triples = Vec::new();
+    for quad in RdfParser::from_format(format).for_reader(data) {
+        let quad = quad.map_err(|e| PyValueError::new_err(format!("Invalid RDF: {}", e)))?;
+        let subject = match quad.subject {
+            Subject::BlankNode(node) => Subject::BlankNode(relabel(&node)),
+            subject => subject,
+        };
+        let object = match quad.object {
+            Term::BlankNode(node) => Term::BlankNode(relabel(&node)),
+            object => object,
+        };
+        triples.push(Triple::new(subject, quad.predicate, object));
+    }
+    Ok(triples)
+}
+
+/// Writes triples in `format` to `out`, sorted by subject, predicate and object.
+///
+/// For Turtle and RDF/XML the prefixes of `mapping` are declared and used to abbreviate IRIs.
+pub fn write<W: Write>(mut triples: Vec<Triple>, format: RdfFormat, mapping: &PrefixMapping, out: W) -> PyResult<()> {
+    triples.sort_by_cached_key(|triple| triple.to_string());
+
+    let mut serializer = RdfSerializer::from_format(format);
+    if format != RdfFormat::NTriples {
+        let mut prefixes: Vec<_> = mapping.mappings().collect();
+        prefixes.sort();
+        for (prefix, namespace) in prefixes {
+            serializer = serializer
+                .with_prefix(prefix.as_str(), namespace.as_str())
+                .map_err(|e| PyValueError::new_err(format!("Invalid prefix '{}': {}", prefix, e)))?;
+        }
+    }
+
+    let mut writer = serializer.for_writer(out);
+    for triple in &triples {
+        writer.serialize_triple(triple)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Converts RDF in `format` to RDF/XML for the horned-owl RDF reader, returning it together with
+/// the prefixes declared in the document.
+pub fn to_rdf_xml(data: &[u8], format: RdfFormat) -> PyResult<(Vec<u8>, PrefixMapping)> {
+    let mut mapping = PrefixMapping::default();
+    let mut parser = RdfParser::from_format(format).for_reader(data);
+    let mut writer = RdfSerializer::from_format(RdfFormat::RdfXml).for_writer(Vec::new());
+    for quad in parser.by_ref() {
+        let quad = quad.map_err(|e| PyValueError::new_err(format!("Invalid RDF: {}", e)))?;
+        writer.serialize_triple(quad.as_ref())?;
+    }
+    for (prefix, namespace) in parser.prefixes() {
+        let _ = mapping.add_prefix(prefix, namespace);
+    }
+    Ok((writer.finish()?, mapping))
+}
+
+/// Returns the prefixes declared on the root element of an RDF/XML document, which is where
+/// they are declared by OWL tools.
+pub fn rdf_xml_prefixes(data: &[u8]) -> PyResult<PrefixMapping> {
+    let mut mapping = PrefixMapping::default();
+    let mut parser = RdfParser::from_format(RdfFormat::RdfXml).for_reader(data);
+    if let Some(quad) = parser.next() {
+        quad.map_err(|e| PyValueError::new_err(format!("Invalid RDF: {}", e)))?;
+    }
+    for (prefix, namespace) in parser.prefixes() {
+        let _ = mapping.add_prefix(prefix, namespace);
+    }
+    Ok(mapping)
+}
+
+/// Writes triples as RDF/XML for the horned-owl RDF reader.
+pub fn rdf_xml(triples: &[Triple]) -> PyResult<Vec<u8>> {
+    let mut writer = RdfSerializer::from_format(RdfFormat::RdfXml).for_writer(Vec::new());
+    for triple in triples {
+        writer.serialize_triple(triple)?;
+    }
+    Ok(writer.finish()?)
+}
@@ -0,0 +1,90 @@
+use horned_owl::model::{ArcStr, Build, Literal};
+use oxigraph::model::{GraphNameRef, Term, Triple};
+use oxigraph::sparql::QueryResults;
+use oxigraph::store::Store;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::literal_to_py;
+
+/// Loads the triples of an ontology into a store for querying.
+pub fn store(triples: &[Triple]) -> PyResult<Store> {
+    let store = Store::new().map_err(to_py_err)?;
+    for triple in triples {
+        store
+            .insert(triple.as_ref().in_graph(GraphNameRef::DefaultGraph))
+            .map_err(to_py_err)?;
+    }
+    Ok(store)
+}
+
+/// Evaluates a SPARQL query over the triples of an ontology loaded by `store`.
+///
+/// SELECT queries return a list of dicts mapping the variable names to the bound values, with
+/// `None` for variables left unbound by an OPTIONAL. ASK queries return a bool and CONSTRUCT or
+/// DESCRIBE queries a list of `(subject, predicate, object)` tuples.
+pub fn query(py: Python, store: &Store, sparql: &str, build: &Build<ArcStr>) -> PyResult<PyObject> {
+    match store.query(sparql).map_err(to_py_err)? {
+        QueryResults::Boolean(b) => Ok(b.to_object(py)),
+        QueryResults::Solutions(solutions) => {
+            let variables = solutions.variables().to_vec();
+            let mut rows = Vec::new();
+            for solution in solutions {
+                let solution = solution.map_err(to_py_err)?;
+                let row = PyDict::new_bound(py);
+                for variable in &variables {
+                    let value = match solution.get(variable) {
+                        Some(term) => term_to_py(py, term, build)?,
+                        None => py.None(),
+                    };
+                    row.set_item(variable.as_str(), value)?;
+                }
+                rows.push(row.to_object(py));
+            }
+            Ok(rows.to_object(py))
+        }
+        QueryResults::Graph(triples) => {
+            let mut result = Vec::new();
+            for triple in triples {
+                let triple = triple.map_err(to_py_err)?;
+                result.push((
+                    term_to_py(py, &triple.subject.into(), build)?,
+                    triple.predicate.as_str().to_object(py),
+                    term_to_py(py, &triple.object, build)?,
+                ));
+            }
+            Ok(result.to_object(py))
+        }
+    }
+}
+
+/// Converts an RDF term to a Python value.
+///
+/// IRIs become strings, blank nodes strings of the form `_:id` and literals native Python values
+/// as returned by `PyIndexedOntology.get_annotation_values`.
+fn term_to_py(py: Python, term: &Term, build: &Build<ArcStr>) -> PyResult<PyObject> {
+    match term {
+        Term::NamedNode(node) => Ok(node.as_str().to_object(py)),
+        Term::BlankNode(node) => Ok(node.to_string().to_object(py)),
+        Term::Literal(literal) => {
+            let literal: Literal<ArcStr> = match literal.language() {
+                Some(lang) => Literal::Language {
+                    literal: literal.value().to_string(),
+                    lang: lang.to_string(),
+                },
+                None => Literal::Datatype {
+                    literal: literal.value().to_string(),
+                    datatype_iri: build.iri(literal.datatype().as_str()),
+                },
+            };
+            literal_to_py(py, &literal)
+        }
+        #[allow(unreachable_patterns)]
+        _ => Ok(term.to_string().to_object(py)),
+    }
+}
+
+fn to_py_err<E: std::fmt::Display>(error: E) -> PyErr {
+    PyValueError::new_err(format!("SPARQL query failed: {}", error))
+}
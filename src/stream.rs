@@ -0,0 +1,83 @@
+use std::io::{self, Write};
+
+use curie::PrefixMapping;
+use oxigraph::io::RdfFormat;
+use oxigraph::model::Triple;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::iri_mapped::IRIMappedOntology;
+use crate::rdf_syntax;
+
+/// Writes `ontology` as OWX to `out` with the horned-owl writer.
+pub fn write_owx<W: Write>(out: W, ontology: &mut IRIMappedOntology, mapping: &PrefixMapping) -> PyResult<()> {
+    ontology
+        .with_component_mapped(|cmo| horned_owl::io::owx::writer::write(out, cmo, Some(mapping)))
+        .map_err(|e| PyValueError::new_err(format!("Problem writing the ontology as OWX: {:?}", e)))
+}
+
+/// Writes `ontology` as RDF/XML to `out` with the horned-owl writer.
+pub fn write_rdf_xml<W: Write>(mut out: W, ontology: &mut IRIMappedOntology) -> PyResult<()> {
+    ontology
+        .with_component_mapped(|cmo| horned_owl::io::rdf::writer::write(&mut out, cmo))
+        .map_err(|e| PyValueError::new_err(format!("Problem writing the ontology as RDF: {:?}", e)))
+}
+
+/// The triples of the RDF mapping of `ontology`, as written by the horned-owl RDF/XML writer.
+///
+/// horned-owl only writes RDF as RDF/XML, so the whole document and all of its triples are held in
+/// memory.
+pub fn triples(ontology: &mut IRIMappedOntology) -> PyResult<Vec<Triple>> {
+    let mut rdf = Vec::new();
+    write_rdf_xml(&mut rdf, ontology)?;
+    rdf_syntax::triples(&rdf, RdfFormat::RdfXml)
+}
+
+/// Counts the bytes written through it and passes the count to a Python callable at most once
+/// per `PROGRESS_INTERVAL` bytes and once when flushed.
+pub struct Progress<W> {
+    inner: W,
+    callback: Option<PyObject>,
+    written: usize,
+    reported: usize,
+}
+
+const PROGRESS_INTERVAL: usize = 1 << 20;
+
+impl<W: Write> Progress<W> {
+    pub fn new(inner: W, callback: Option<PyObject>) -> Self {
+        Progress {
+            inner,
+            callback,
+            written: 0,
+            reported: 0,
+        }
+    }
+
+    fn report(&mut self) -> io::Result<()> {
+        if let Some(callback) = &self.callback {
+            if self.written != self.reported {
+                self.reported = self.written;
+                Python::with_gil(|py| callback.call1(py, (self.written,)))
+                    .map_err(io::Error::other)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for Progress<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.written += n;
+        if self.written - self.reported >= PROGRESS_INTERVAL {
+            self.report()?;
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()?;
+        self.report()
+    }
+}
@@ -0,0 +1,89 @@
+import datetime
+import decimal
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+XSD = "http://www.w3.org/2001/XMLSchema#"
+
+
+def assertion(value):
+    return f"""
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://example.com/p"/>
+        <IRI>http://example.com/A</IRI>
+        {value}
+    </AnnotationAssertion>"""
+
+
+def literal(text, datatype):
+    return assertion(f'<Literal datatypeIRI="{XSD}{datatype}">{text}</Literal>')
+
+
+ONTOLOGY = f"""<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    {literal("42", "integer")}
+    {literal("7", "nonNegativeInteger")}
+    {literal("true", "boolean")}
+    {literal("1.50", "decimal")}
+    {literal("2.5E0", "double")}
+    {literal("2020-01-02T03:04:05", "dateTime")}
+    {literal("2020-01-02", "date")}
+    {assertion('<Literal xml:lang="en">text</Literal>')}
+    {assertion('<Literal>plain</Literal>')}
+    {assertion('<IRI>http://example.com/B</IRI>')}
+</Ontology>"""
+
+A = "http://example.com/A"
+P = "http://example.com/p"
+
+
+def value(v):
+    if isinstance(v, LanguageLiteral):
+        return v.literal, v.lang
+    if isinstance(v, DatatypeLiteral):
+        return v.literal, str(v.datatype_iri)
+    if isinstance(v, SimpleLiteral):
+        return v.literal
+    return str(v)
+
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+
+# By default the model values are returned, keeping language tags, datatypes and IRIs.
+values = onto.get_annotation_values(A, P)
+assert len(values) == 10
+assert all(isinstance(v, (SimpleLiteral, LanguageLiteral, DatatypeLiteral, IRI)) for v in values)
+values = [value(v) for v in values]
+assert ("text", "en") in values
+assert "plain" in values
+assert ("42", XSD + "integer") in values
+assert "http://example.com/B" in values
+
+# With native=True literals become Python values.
+native = onto.get_annotation_values(A, P, True)
+assert len(native) == 10
+for value in [42, 7, True, decimal.Decimal("1.50"), 2.5,
+              datetime.datetime(2020, 1, 2, 3, 4, 5), datetime.date(2020, 1, 2), "text", "plain"]:
+    assert any(type(v) is type(value) and v == value for v in native), value
+assert "http://example.com/B" in [str(v) for v in native if isinstance(v, IRI)]
+
+# Timezones are accepted, and invalid or unconverted literals keep their lexical form.
+UTC = datetime.timezone.utc
+for text, datatype, expected in [
+        ("2020-01-02Z", "date", datetime.date(2020, 1, 2)),
+        ("2020-01-02+02:00", "date", datetime.date(2020, 1, 2)),
+        ("2020-01-02T03:04:05Z", "dateTime", datetime.datetime(2020, 1, 2, 3, 4, 5, tzinfo=UTC)),
+        (" 42 ", "integer", 42),
+        ("maybe", "boolean", "maybe"),
+        ("12abc", "integer", "12abc"),
+        ("2020-13-01", "date", "2020-13-01"),
+        ("  padded  ", "string", "  padded  ")]:
+    onto = pyhornedowl.open_ontology(f"""<?xml version="1.0"?>
+    <Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+        {literal(text, datatype)}
+    </Ontology>""")
+    [v] = onto.get_annotation_values(A, P, True)
+    assert type(v) is type(expected) and v == expected, (text, v)
+
+print("annotation value tests passed")
@@ -0,0 +1,78 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <AnnotationAssertion>
+        <Annotation>
+            <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#seeAlso"/>
+            <IRI>http://example.com/source</IRI>
+        </Annotation>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#comment"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal>first</Literal>
+    </AnnotationAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#comment"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal xml:lang="en">second</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+A = "http://example.com/A"
+COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+SEE_ALSO = "http://www.w3.org/2000/01/rdf-schema#seeAlso"
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+
+
+def value(av):
+    if isinstance(av, LanguageLiteral):
+        return av.literal, av.lang
+    if isinstance(av, DatatypeLiteral):
+        return av.literal, str(av.datatype_iri)
+    if isinstance(av, SimpleLiteral):
+        return av.literal
+    return str(av)
+
+
+def values(onto, prop):
+    return sorted((value(ac.component.ann.av) for ac in onto.get_axioms()
+                   if isinstance(ac.component, AnnotationAssertion) and str(ac.component.subject) == A
+                   and str(ac.component.ann.ap.first) == prop), key=str)
+
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+
+# add_annotation keeps the existing values.
+onto.add_annotation(A, COMMENT, "third", lang="de")
+assert sorted(onto.get_annotations(A, COMMENT)) == ["first", "second", "third"]
+assert ("third", "de") in values(onto, COMMENT)
+
+onto.add_annotation(A, "http://example.com/count", "3", datatype=XSD_INTEGER)
+assert values(onto, "http://example.com/count") == [("3", XSD_INTEGER)]
+
+onto.add_annotation(A, SEE_ALSO, onto.iri("http://example.com/B"))
+assert values(onto, SEE_ALSO) == ["http://example.com/B"]
+
+# set_annotation replaces all values and keeps the axiom annotations of the replaced assertions.
+onto.set_annotation(A, COMMENT, "only")
+assert values(onto, COMMENT) == ["only"]
+[comment] = [ac for ac in onto.get_axioms()
+             if isinstance(ac.component, AnnotationAssertion) and str(ac.component.ann.ap.first) == COMMENT]
+assert [str(a.ap.first) for a in comment.ann] == [SEE_ALSO]
+
+# Unless replace is false.
+onto.set_annotation(A, COMMENT, "another", replace=False)
+assert values(onto, COMMENT) == ["another", "only"]
+
+# remove_annotations removes the given value regardless of language or datatype, or all values.
+onto.add_annotation(A, COMMENT, "only", lang="en")
+assert onto.remove_annotations(A, COMMENT, "only") == 2
+assert values(onto, COMMENT) == ["another"]
+assert onto.remove_annotations(A, COMMENT, "missing") == 0
+assert onto.remove_annotations(A, SEE_ALSO, onto.iri("http://example.com/B")) == 1
+assert onto.remove_annotations(A, COMMENT) == 1
+assert values(onto, COMMENT) == []
+
+print("annotation setter tests passed")
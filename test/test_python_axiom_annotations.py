@@ -0,0 +1,81 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <SubClassOf>
+        <Annotation>
+            <AnnotationProperty IRI="http://www.geneontology.org/formats/oboInOwl#hasDbXref"/>
+            <Literal>PMID:1</Literal>
+        </Annotation>
+        <Class IRI="http://example.com/A"/>
+        <Class IRI="http://example.com/B"/>
+    </SubClassOf>
+    <SubClassOf>
+        <Annotation>
+            <AnnotationProperty IRI="http://www.geneontology.org/formats/oboInOwl#hasDbXref"/>
+            <Literal xml:lang="en">PMID:2</Literal>
+        </Annotation>
+        <Class IRI="http://example.com/B"/>
+        <Class IRI="http://example.com/C"/>
+    </SubClassOf>
+    <SubClassOf><Class IRI="http://example.com/C"/><Class IRI="http://example.com/D"/></SubClassOf>
+</Ontology>"""
+
+XREF = "http://www.geneontology.org/formats/oboInOwl#hasDbXref"
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+
+
+def sub_class_of(sub, sup):
+    return SubClassOf(Class(onto.iri(f"http://example.com/{sup}")), Class(onto.iri(f"http://example.com/{sub}")))
+
+
+def annotations(anns):
+    return sorted((str(a.ap.first), a.av.literal) for a in anns)
+
+
+def classes(sub_class_of):
+    return str(sub_class_of.sub.first), str(sub_class_of.sup.first)
+
+
+a_b = sub_class_of("A", "B")
+c_d = sub_class_of("C", "D")
+xref = Annotation(AnnotationProperty(onto.iri(XREF)), SimpleLiteral("PMID:1"))
+source = Annotation(AnnotationProperty(onto.iri("http://purl.org/dc/terms/source")), SimpleLiteral("curator"))
+
+assert annotations(onto.get_axiom_annotations(a_b)) == [(XREF, "PMID:1")]
+assert onto.get_axiom_annotations(c_d) == []
+
+# Annotations are added and removed without changing the axiom itself.
+onto.add_axiom_annotation(c_d, source)
+onto.add_axiom_annotation(a_b, source)
+assert annotations(onto.get_axiom_annotations(a_b)) == annotations([source, xref])
+assert annotations(onto.get_axiom_annotations(c_d)) == annotations([source])
+
+onto.remove_axiom_annotation(a_b, xref)
+assert annotations(onto.get_axiom_annotations(a_b)) == annotations([source])
+assert len(onto.get_axioms()) == 4
+assert onto.get_superclasses("http://example.com/A") == {"http://example.com/B"}
+
+# Axioms are found by their annotations.
+found = onto.get_axioms_with_annotation("http://purl.org/dc/terms/source")
+assert sorted(classes(ac.component) for ac in found) == sorted(map(classes, [a_b, c_d]))
+
+found = onto.get_axioms_with_annotation(XREF, "PMID:2")
+assert [classes(ac.component) for ac in found] == [classes(sub_class_of("B", "C"))]
+assert onto.get_axioms_with_annotation(XREF, "PMID:1") == []
+assert onto.get_axioms_with_annotation(XREF, SimpleLiteral("PMID:2")) == []
+
+# Axioms not in the ontology are an error.
+missing = sub_class_of("A", "D")
+for call in (lambda: onto.get_axiom_annotations(missing),
+             lambda: onto.add_axiom_annotation(missing, source),
+             lambda: onto.remove_axiom_annotation(missing, source)):
+    try:
+        call()
+        assert False, "an axiom that is not in the ontology raises"
+    except ValueError:
+        pass
+
+print("axiom annotation tests passed")
@@ -0,0 +1,85 @@
+import os
+import pickle
+import random
+import tempfile
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+EMPTY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="http://example.com/"/>
+</Ontology>"""
+
+
+def read_turtle(text):
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "onto.ttl")
+        with open(path, "w") as f:
+            f.write(text)
+        return pyhornedowl.open_ontology(path)
+
+
+def build(order):
+    onto = pyhornedowl.open_ontology(EMPTY)
+    p = ObjectProperty(onto.iri("http://example.com/p"))
+    label = AnnotationProperty(onto.iri("http://www.w3.org/2000/01/rdf-schema#label"))
+    classes = [Class(onto.iri(f"http://example.com/C{i}")) for i in range(10)]
+    components = [DeclareObjectProperty(p)]
+    for i, cls in enumerate(classes):
+        components.append(DeclareClass(cls))
+        components.append(SubClassOf(ObjectSomeValuesFrom(p, classes[(i + 1) % 10]), cls))
+        components.append(SubClassOf(ObjectSomeValuesFrom(p, classes[(i + 2) % 10]), cls))
+        components.append(AnnotationAssertion(cls.first, Annotation(label, SimpleLiteral(f"class {i}"))))
+    random.Random(order).shuffle(components)
+    for component in components:
+        onto.add_axiom(component)
+    return onto
+
+
+first = build(1)
+second = build(2)
+assert set(first.get_axioms()) == set(second.get_axioms())
+
+# With canonical ordering, the same components are always written the same way.
+first.set_canonical_ordering(True)
+second.set_canonical_ordering(True)
+for write in ["write_to_rdf_string", "write_to_turtle_string", "write_to_ntriples_string",
+              "write_to_jsonld_string", "write_to_owx_string"]:
+    assert getattr(first, write)() == getattr(second, write)(), write
+
+# Blank nodes are labelled from the triples around them.
+ntriples = first.write_to_ntriples_string()
+assert ntriples.splitlines() == sorted(ntriples.splitlines())
+assert "_:" in ntriples
+
+# The output reads back to the same ontology, in canonical order or not.
+axioms = set(first.get_axioms())
+for canonical in [True, False]:
+    first.set_canonical_ordering(canonical)
+    assert set(pyhornedowl.open_ontology(first.write_to_rdf_string()).get_axioms()) == axioms
+    assert set(read_turtle(first.write_to_turtle_string()).get_axioms()) == axioms
+
+# OWX output does not depend on the setting.
+first.set_canonical_ordering(False)
+owx = first.write_to_owx_string()
+first.set_canonical_ordering(True)
+assert first.write_to_owx_string() == owx
+
+# Subsumptions are grouped by their subclass and assertions by their individual.
+onto = pyhornedowl.open_ontology(EMPTY)
+onto.set_canonical_ordering(True)
+for i in range(3):
+    onto.add_axiom(SubClassOf(Class(onto.iri(f"http://example.com/T{2 - i}")), Class(onto.iri(f"http://example.com/S{i}"))))
+    onto.add_axiom(ClassAssertion(Class(onto.iri(f"http://example.com/C{2 - i}")),
+                                  NamedIndividual(onto.iri(f"http://example.com/i{i}"))))
+rdf = onto.write_to_rdf_string()
+for subjects in [["S0", "S1", "S2"], ["i0", "i1", "i2"]]:
+    positions = [rdf.index(f'rdf:about="http://example.com/{s}"') for s in subjects]
+    assert positions == sorted(positions), subjects
+
+# Pickled and copied ontologies keep the setting.
+for duplicate in [pickle.loads(pickle.dumps(first)), first.clone()]:
+    assert duplicate.write_to_rdf_string() == second.write_to_rdf_string()
+
+print("canonical ordering tests passed")
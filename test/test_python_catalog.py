@@ -0,0 +1,83 @@
+import os
+import tempfile
+
+import pyhornedowl
+
+MAIN = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/main">
+    <Import>http://example.com/a</Import>
+    <Import>http://example.com/rw/b.owx</Import>
+    <SubClassOf><Class IRI="http://example.com/M"/><Class IRI="http://example.com/A"/></SubClassOf>
+</Ontology>"""
+
+A = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/a">
+    <Import>http://example.com/rw/b.owx</Import>
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+</Ontology>"""
+
+B = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/rw/b.owx">
+    <Declaration><Class IRI="http://example.com/B"/></Declaration>
+</Ontology>"""
+
+CATALOG = """<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<catalog prefer="public" xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+    <group id="imports" xml:base="sub/">
+        <uri name="http://example.com/a" uri="a.owx"/>
+    </group>
+    <rewriteURI uriStartString="http://example.com/rw/" rewritePrefix="rw/"/>
+</catalog>"""
+
+
+def write(path, text):
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "w") as f:
+        f.write(text)
+
+
+with tempfile.TemporaryDirectory() as directory:
+    main = os.path.join(directory, "main.owx")
+    write(main, MAIN)
+    write(os.path.join(directory, "sub", "a.owx"), A)
+    write(os.path.join(directory, "rw", "b.owx"), B)
+    write(os.path.join(directory, "catalog-v001.xml"), CATALOG)
+
+    # Without resolve_imports only the ontology itself is loaded.
+    onto = pyhornedowl.open_ontology(main)
+    assert onto.get_classes() == set()
+
+    # The catalog next to the file resolves uri entries within a group with xml:base and
+    # rewriteURI prefixes, and the closure is merged into the ontology.
+    onto = pyhornedowl.open_ontology(main, resolve_imports=True)
+    assert onto.get_iri() == "http://example.com/main"
+    assert onto.get_classes() == {"http://example.com/B"}
+    assert pyhornedowl.get_ancestors(onto, "http://example.com/M") == {
+        "http://example.com/M", "http://example.com/A", "http://example.com/B"}
+
+    # A directory catalog resolves imports by the last segment of their IRI.
+    flat = os.path.join(directory, "flat")
+    write(os.path.join(flat, "a"), A.replace("http://example.com/rw/b.owx", "http://example.com/b.owx"))
+    write(os.path.join(flat, "b.owx"), B)
+    path = os.path.join(directory, "flat_main.owx")
+    write(path, MAIN.replace("http://example.com/rw/b.owx", "http://example.com/b.owx"))
+    onto = pyhornedowl.open_ontology(path, resolve_imports=True, catalog=flat)
+    assert onto.get_classes() == {"http://example.com/B"}
+
+    # Imports that cannot be resolved are an error rather than fetched from the network.
+    try:
+        pyhornedowl.open_ontology(main, resolve_imports=True, catalog=os.path.join(directory, "sub"))
+        assert False, "an unresolved import raises"
+    except ValueError:
+        pass
+
+    # A malformed catalog is reported.
+    bad = os.path.join(directory, "bad.xml")
+    write(bad, "<catalog><uri name=\"x\" uri=\"y\"></catalog>")
+    try:
+        pyhornedowl.open_ontology(main, resolve_imports=True, catalog=bad)
+        assert False, "a malformed catalog raises"
+    except ValueError as e:
+        assert "Unable to read catalog" in str(e)
+
+print("catalog tests passed")
@@ -0,0 +1,70 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <Declaration><Class IRI="http://example.com/B"/></Declaration>
+    <Declaration><ObjectProperty IRI="http://example.com/p"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+    <SubClassOf>
+        <Class IRI="http://example.com/B"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/p"/><Class IRI="http://example.com/C"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <EquivalentClasses><Class IRI="http://example.com/C"/><Class IRI="http://example.com/D"/></EquivalentClasses>
+    <TransitiveObjectProperty><ObjectProperty IRI="http://example.com/p"/></TransitiveObjectProperty>
+</Ontology>"""
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+
+
+def key(obj):
+    if isinstance(obj, (str, int, float, type(None))):
+        return obj
+    if isinstance(obj, IRI):
+        return str(obj)
+    if isinstance(obj, (list, tuple, set)):
+        return tuple(sorted(map(key, obj), key=repr))
+    attributes = [a for a in dir(obj) if not a.startswith("_") and not callable(getattr(obj, a))]
+    return (type(obj).__name__,) + tuple(key(getattr(obj, a)) for a in sorted(attributes))
+
+
+def components(*args):
+    return sorted((key(ac.component) for ac in onto.get_components(*args)), key=repr)
+
+
+def sub_class_of(sup, sub):
+    return key(SubClassOf(sup, sub))
+
+
+A = Class(onto.iri("http://example.com/A"))
+B = Class(onto.iri("http://example.com/B"))
+C = Class(onto.iri("http://example.com/C"))
+p = ObjectProperty(onto.iri("http://example.com/p"))
+
+assert components(ComponentKind.SubClassOf) == sorted([
+    sub_class_of(B, A), sub_class_of(ObjectSomeValuesFrom(p, C), B)], key=repr)
+assert components(ComponentKind.DeclareClass) == sorted([key(DeclareClass(A)), key(DeclareClass(B))], key=repr)
+assert len(onto.get_components(ComponentKind.OntologyID)) == 1
+assert onto.get_components(ComponentKind.DisjointClasses) == []
+
+# Several kinds at once
+assert len(onto.get_components([ComponentKind.SubClassOf, ComponentKind.EquivalentClasses])) == 3
+assert len(onto.get_components([ComponentKind.DeclareClass, ComponentKind.DeclareObjectProperty])) == 3
+
+# Restricted to the components referencing an IRI
+assert components(ComponentKind.SubClassOf, "http://example.com/p") == [
+    sub_class_of(ObjectSomeValuesFrom(p, C), B)]
+assert components(ComponentKind.SubClassOf, "http://example.com/A") == [sub_class_of(B, A)]
+assert len(onto.get_components([ComponentKind.SubClassOf, ComponentKind.EquivalentClasses],
+                               "http://example.com/C")) == 2
+assert onto.get_components(ComponentKind.SubClassOf, "http://example.com/D") == []
+assert len(onto.get_components(ComponentKind.TransitiveObjectProperty, "http://example.com/p")) == 1
+
+try:
+    onto.get_components("SubClassOf")
+    assert False, "kinds are given as ComponentKind"
+except TypeError:
+    pass
+
+print("component kind tests passed")
@@ -0,0 +1,72 @@
+import pyhornedowl
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="http://example.com/"/>
+    <Declaration><Class IRI="http://example.com/limb"/></Declaration>
+    <Declaration><Class IRI="http://example.com/arm"/></Declaration>
+    <Declaration><Class IRI="http://example.com/hand"/></Declaration>
+    <Declaration><Class IRI="http://example.com/finger"/></Declaration>
+    <Declaration><Class IRI="http://example.com/thumb"/></Declaration>
+    <Declaration><Class IRI="http://example.com/forelimb"/></Declaration>
+    <Declaration><ObjectProperty IRI="http://example.com/part_of"/></Declaration>
+    <Declaration><NamedIndividual IRI="http://example.com/my_arm"/></Declaration>
+    <Declaration><NamedIndividual IRI="http://example.com/my_hand"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/arm"/><Class IRI="http://example.com/limb"/></SubClassOf>
+    <SubClassOf>
+        <Class IRI="http://example.com/hand"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/part_of"/><Class IRI="http://example.com/arm"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <SubClassOf>
+        <Class IRI="http://example.com/finger"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/part_of"/><Class IRI="http://example.com/hand"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <SubClassOf><Class IRI="http://example.com/thumb"/><Class IRI="http://example.com/finger"/></SubClassOf>
+    <EquivalentClasses><Class IRI="http://example.com/forelimb"/><Class IRI="http://example.com/arm"/></EquivalentClasses>
+    <ClassAssertion><Class IRI="http://example.com/arm"/><NamedIndividual IRI="http://example.com/my_arm"/></ClassAssertion>
+    <ObjectPropertyAssertion>
+        <ObjectProperty IRI="http://example.com/part_of"/>
+        <NamedIndividual IRI="http://example.com/my_hand"/>
+        <NamedIndividual IRI="http://example.com/my_arm"/>
+    </ObjectPropertyAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/part_of</IRI>
+        <Literal>part of</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+EX = "http://example.com/"
+
+
+def iris(*names):
+    return {EX + name for name in names}
+
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+
+# Subclasses follow named superclasses and existential restrictions.
+assert onto.dl_query("ex:limb") == iris("arm", "forelimb")
+assert onto.dl_query("'part of' some ex:arm") == iris("hand")
+assert onto.dl_query("'part of' some ex:hand") == iris("finger", "thumb")
+assert onto.dl_query("ex:finger or ex:arm") == iris("finger", "thumb", "arm", "forelimb")
+assert onto.dl_query("ex:finger and ('part of' some ex:hand)") == iris("thumb")
+assert onto.dl_query("<http://example.com/finger>", "subclasses") == iris("thumb")
+
+# Superclasses, equivalents and instances
+assert onto.dl_query("ex:thumb", "superclasses") == iris("finger")
+assert onto.dl_query("ex:arm", "equivalents") == iris("forelimb")
+assert onto.dl_query("ex:limb", "instances") == iris("my_arm")
+assert onto.dl_query("'part of' value ex:my_arm", "instances") == iris("my_hand")
+
+# Unknown CURIEs are entities without axioms.
+assert onto.dl_query("ex:unknown") == set()
+
+for query, kind in [("'no such label'", None), ("ex:limb and", None), ("ex:limb", "children")]:
+    try:
+        onto.dl_query(query, kind)
+        assert False, f"{query!r} with kind {kind!r} raises"
+    except ValueError:
+        pass
+
+print("dl query tests passed")
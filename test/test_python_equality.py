@@ -0,0 +1,59 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+EX = "http://example.com/"
+
+
+def cls(name):
+    return Class(IRI.parse(EX + name))
+
+
+A, B, C = cls("A"), cls("B"), cls("C")
+p = ObjectProperty(IRI.parse(EX + "p"))
+
+# Objects built separately are equal and hash alike.
+assert cls("A") == A
+assert hash(cls("A")) == hash(A)
+assert A != B
+assert SubClassOf(B, ObjectSomeValuesFrom(p, A)) == SubClassOf(B, ObjectSomeValuesFrom(p, cls("A")))
+assert SubClassOf(A, B) != SubClassOf(B, A)
+assert ObjectIntersectionOf([A, B]) != ObjectIntersectionOf([B, A])
+assert SimpleLiteral("a") == SimpleLiteral("a")
+assert LanguageLiteral("a", "en") != LanguageLiteral("a", "de")
+assert A != p
+assert A != "http://example.com/A"
+
+# Model objects can be put in sets and used as dict keys.
+assert len({A, cls("A"), B}) == 2
+assert len({SubClassOf(A, B), SubClassOf(cls("A"), cls("B")), DeclareClass(A)}) == 2
+axioms = {SubClassOf(A, B): "first"}
+assert axioms[SubClassOf(cls("A"), cls("B"))] == "first"
+annotation = Annotation(AnnotationProperty(IRI.parse(EX + "note")), SimpleLiteral("x"))
+assert len({AnnotatedComponent(SubClassOf(A, B), {annotation}),
+            AnnotatedComponent(SubClassOf(A, B), {annotation}),
+            AnnotatedComponent(SubClassOf(A, B), set())}) == 2
+
+# Objects of the same class are ordered like their horned-owl counterparts.
+assert A < B < C
+assert sorted([C, A, B]) == [A, B, C]
+assert max([A, C, B]) == C
+assert IRI.parse(EX + "a") < IRI.parse(EX + "b")
+assert SubClassOf(A, B) <= SubClassOf(A, B)
+assert sorted([SubClassOf(C, A), SubClassOf(A, B), SubClassOf(B, A)]) == \
+    [SubClassOf(A, B), SubClassOf(B, A), SubClassOf(C, A)]
+
+try:
+    A < p
+    assert False, "objects of different classes are not ordered"
+except TypeError:
+    pass
+
+# Components from an ontology compare equal to ones built in Python.
+onto = pyhornedowl.open_ontology("""<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+</Ontology>""")
+assert SubClassOf(B, A) in {ac.component for ac in onto.get_axioms()}
+assert AnnotatedComponent(SubClassOf(B, A), set()) in set(onto.get_axioms())
+
+print("equality and ordering tests passed")
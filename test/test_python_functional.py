@@ -0,0 +1,53 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+EX = "http://example.com/"
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+
+A = Class(IRI.parse(EX + "A"))
+B = Class(IRI.parse(EX + "B"))
+p = ObjectProperty(IRI.parse(EX + "p"))
+label = AnnotationProperty(IRI.parse("http://www.w3.org/2000/01/rdf-schema#label"))
+
+# repr and str of model objects are OWL Functional Syntax.
+axiom = SubClassOf(B, ObjectSomeValuesFrom(p, A))
+assert repr(axiom) == "SubClassOf(ObjectSomeValuesFrom(<http://example.com/p> <http://example.com/A>) <http://example.com/B>)"
+assert str(axiom) == repr(axiom)
+
+assert repr(A) == "<http://example.com/A>"
+assert repr(ObjectIntersectionOf([A, ObjectComplementOf(B)])) == \
+    "ObjectIntersectionOf(<http://example.com/A> ObjectComplementOf(<http://example.com/B>))"
+assert repr(DeclareClass(A)) == "Declaration(Class(<http://example.com/A>))"
+assert repr(TransitiveObjectProperty(p)) == "TransitiveObjectProperty(<http://example.com/p>)"
+assert repr(ClassAssertion(A, NamedIndividual(IRI.parse(EX + "i")))) == \
+    "ClassAssertion(<http://example.com/A> <http://example.com/i>)"
+
+# Literals are quoted and escaped.
+assert repr(SimpleLiteral("a")) == '"a"'
+assert repr(LanguageLiteral('say "hi"', "en")) == '"say \\"hi\\""@en'
+assert repr(DatatypeLiteral("1", IRI.parse(XSD_INTEGER))) == '"1"^^<http://www.w3.org/2001/XMLSchema#integer>'
+assert repr(AnnotationAssertion(IRI.parse(EX + "A"), Annotation(label, SimpleLiteral("a")))) == \
+    'AnnotationAssertion(<http://www.w3.org/2000/01/rdf-schema#label> <http://example.com/A> "a")'
+
+# Axiom annotations are written inside the axiom.
+annotated = AnnotatedComponent(axiom, {Annotation(label, SimpleLiteral("c"))})
+assert repr(annotated) == ('SubClassOf(Annotation(<http://www.w3.org/2000/01/rdf-schema#label> "c") '
+                           'ObjectSomeValuesFrom(<http://example.com/p> <http://example.com/A>) <http://example.com/B>)')
+
+# IRIs keep a repr that recreates them and a str that is the IRI itself.
+assert repr(IRI.parse(EX + "A")) == 'IRI.parse("http://example.com/A")'
+assert str(IRI.parse(EX + "A")) == EX + "A"
+
+# Prefixes abbreviate IRIs.
+assert axiom.to_functional({"ex": EX}) == "SubClassOf(ObjectSomeValuesFrom(ex:p ex:A) ex:B)"
+assert axiom.to_functional() == repr(axiom)
+
+onto = pyhornedowl.open_ontology(f"""<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="{EX}"/>
+</Ontology>""")
+assert onto.render_functional(axiom) == "SubClassOf(ObjectSomeValuesFrom(ex:p ex:A) ex:B)"
+assert onto.render_functional(annotated) == \
+    'SubClassOf(Annotation(<http://www.w3.org/2000/01/rdf-schema#label> "c") ObjectSomeValuesFrom(ex:p ex:A) ex:B)'
+
+print("functional syntax tests passed")
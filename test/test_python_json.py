@@ -0,0 +1,82 @@
+import json
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <SubClassOf>
+        <Annotation>
+            <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#comment"/>
+            <Literal xml:lang="en">asserted</Literal>
+        </Annotation>
+        <Class IRI="http://example.com/A"/>
+        <ObjectIntersectionOf>
+            <Class IRI="http://example.com/B"/>
+            <ObjectMinCardinality cardinality="2"><ObjectProperty IRI="http://example.com/p"/><Class IRI="http://example.com/C"/></ObjectMinCardinality>
+        </ObjectIntersectionOf>
+    </SubClassOf>
+    <DataPropertyRange>
+        <DataProperty IRI="http://example.com/d"/>
+        <DatatypeRestriction>
+            <Datatype IRI="http://www.w3.org/2001/XMLSchema#integer"/>
+            <FacetRestriction facet="http://www.w3.org/2001/XMLSchema#minInclusive">
+                <Literal datatypeIRI="http://www.w3.org/2001/XMLSchema#integer">0</Literal>
+            </FacetRestriction>
+        </DatatypeRestriction>
+    </DataPropertyRange>
+    <ClassAssertion><Class IRI="http://example.com/A"/><AnonymousIndividual nodeID="x"/></ClassAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#seeAlso"/>
+        <IRI>http://example.com/A</IRI>
+        <IRI>http://example.com/B</IRI>
+    </AnnotationAssertion>
+</Ontology>"""
+
+A = Class(IRI.parse("http://example.com/A"))
+p = ObjectProperty(IRI.parse("http://example.com/p"))
+
+# The documented schema: the class name under "type" and fields under their attribute names.
+assert A.to_dict() == {"type": "Class", "first": "http://example.com/A"}
+assert SubClassOf(A, ObjectSomeValuesFrom(p, A)).to_dict() == {
+    "type": "SubClassOf",
+    "sup": {"type": "Class", "first": "http://example.com/A"},
+    "sub": {
+        "type": "ObjectSomeValuesFrom",
+        "ope": {"type": "ObjectProperty", "first": "http://example.com/p"},
+        "bce": {"type": "Class", "first": "http://example.com/A"},
+    },
+}
+assert LanguageLiteral("a", "en").to_dict() == {"type": "LanguageLiteral", "literal": "a", "lang": "en"}
+assert ObjectMinCardinality(2, p, A).to_dict()["n"] == 2
+assert OntologyID(IRI.parse("http://example.com/onto"), None).to_dict() == {
+    "type": "OntologyID", "iri": "http://example.com/onto", "viri": None}
+
+# Every component of an ontology round trips through dicts and JSON.
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+for ac in onto.get_axioms():
+    assert AnnotatedComponent.from_dict(ac.to_dict()) == ac
+    assert AnnotatedComponent.from_json(ac.to_json()) == ac
+    assert type(ac.component).from_json(ac.component.to_json()) == ac.component
+    assert json.loads(ac.to_json(indent=2)) == ac.to_dict()
+
+[annotated] = [ac for ac in onto.get_axioms() if isinstance(ac.component, SubClassOf)]
+assert annotated.to_dict()["ann"] == [{
+    "type": "Annotation",
+    "ap": {"type": "AnnotationProperty", "first": "http://www.w3.org/2000/01/rdf-schema#comment"},
+    "av": {"type": "LanguageLiteral", "literal": "asserted", "lang": "en"},
+}]
+
+# Decoding checks the type of the encoded object.
+for call in [lambda: SubClassOf.from_dict({"type": "Class", "first": "http://example.com/A"}),
+             lambda: Class.from_dict({"first": "http://example.com/A"}),
+             lambda: Class.from_dict({"type": "Class"}),
+             lambda: SubClassOf.from_json("{")]:
+    try:
+        call()
+        assert False, "invalid input is rejected"
+    except ValueError:
+        pass
+
+print("json tests passed")
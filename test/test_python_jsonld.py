@@ -0,0 +1,75 @@
+import json
+import os
+import tempfile
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="http://example.com/"/>
+    <Prefix name="owl" IRI="http://www.w3.org/2002/07/owl#"/>
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <Declaration><Class IRI="http://example.com/B"/></Declaration>
+    <Declaration><ObjectProperty IRI="http://example.com/p"/></Declaration>
+    <SubClassOf>
+        <Class IRI="http://example.com/A"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/p"/><Class IRI="http://example.com/B"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal xml:lang="en">a</Literal>
+    </AnnotationAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://example.com/count"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal datatypeIRI="http://www.w3.org/2001/XMLSchema#integer">3</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+RDFS_LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+RDFS_SUBCLASS_OF = "http://www.w3.org/2000/01/rdf-schema#subClassOf"
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+text = onto.write_to_jsonld_string()
+document = json.loads(text)
+
+# The context declares the prefixes of the ontology, which compact IRIs.
+assert document["@context"] == {"ex": "http://example.com/", "owl": "http://www.w3.org/2002/07/owl#"}
+
+# Each subject is a node object in the flattened, sorted graph.
+graph = document["@graph"]
+ids = [node["@id"] for node in graph]
+assert ids == sorted(ids)
+assert set(ids) == {"_:b0", "ex:A", "ex:B", "ex:onto", "ex:p"}
+nodes = {node["@id"]: node for node in graph}
+assert nodes["ex:A"]["@type"] == ["owl:Class"]
+assert nodes["ex:p"]["@type"] == ["owl:ObjectProperty"]
+assert nodes["ex:onto"]["@type"] == ["owl:Ontology"]
+assert nodes["ex:A"][RDFS_LABEL] == [{"@value": "a", "@language": "en"}]
+assert nodes["ex:A"]["ex:count"] == [{"@value": "3", "@type": "http://www.w3.org/2001/XMLSchema#integer"}]
+assert nodes["ex:A"][RDFS_SUBCLASS_OF] == [{"@id": "_:b0"}]
+assert nodes["_:b0"]["owl:someValuesFrom"] == [{"@id": "ex:B"}]
+
+# The document reads back to the same ontology, except for annotations with undeclared properties.
+axioms = {ac for ac in onto.get_axioms()
+          if not (isinstance(ac.component, AnnotationAssertion)
+                  and str(ac.component.ann.ap.first) == "http://example.com/count")}
+assert set(pyhornedowl.open_ontology(text).get_axioms()) == axioms
+assert onto.write_to_jsonld_string() == text
+
+with tempfile.TemporaryDirectory() as directory:
+    path = os.path.join(directory, "onto.jsonld")
+    onto.save_to_file(path)
+    with open(path) as f:
+        assert f.read() == text
+    assert set(pyhornedowl.open_ontology(path).get_axioms()) == axioms
+
+try:
+    pyhornedowl.open_ontology('{"@graph": [')
+    assert False, "malformed JSON-LD is rejected"
+except ValueError:
+    pass
+
+print("json-ld tests passed")
@@ -0,0 +1,144 @@
+import bz2
+import gzip
+import io
+import os
+import shutil
+import subprocess
+import tempfile
+import zipfile
+
+import pyhornedowl
+
+OWX = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="http://example.com/"/>
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <Declaration><Class IRI="http://example.com/B"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+</Ontology>"""
+
+RDF = """<?xml version="1.0"?>
+<rdf:RDF xmlns:ex="http://example.com/"
+         xmlns:owl="http://www.w3.org/2002/07/owl#"
+         xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <owl:Ontology rdf:about="http://example.com/onto"/>
+    <owl:Class rdf:about="http://example.com/A"/>
+</rdf:RDF>"""
+
+axioms = set(pyhornedowl.open_ontology_from_string(OWX, "owx").get_axioms())
+assert len(axioms) == 4
+
+
+def zstd(data):
+    try:
+        import zstandard
+        return zstandard.ZstdCompressor().compress(data)
+    except ImportError:
+        if shutil.which("zstd") is None:
+            return None
+        return subprocess.run(["zstd", "-c", "-q"], input=data, capture_output=True, check=True).stdout
+
+
+def zipped(files):
+    data = io.BytesIO()
+    with zipfile.ZipFile(data, "w", zipfile.ZIP_DEFLATED) as archive:
+        for name, content in files:
+            archive.writestr(name, content)
+    return data.getvalue()
+
+
+# Compressed data is recognised by its magic number, and the format from the decompressed content.
+data = OWX.encode()
+documents = [data, gzip.compress(data), bz2.compress(data), zipped([("onto.owx", OWX)]),
+             zipped([("onto/", ""), ("onto/onto.owx", OWX)])]
+if zstd(data) is not None:
+    documents.append(zstd(data))
+for document in documents:
+    assert set(pyhornedowl.open_ontology_from_bytes(document).get_axioms()) == axioms
+    assert set(pyhornedowl.open_ontology_from_bytes(document, "owx").get_axioms()) == axioms
+    assert set(pyhornedowl.open_ontology_from_reader(io.BytesIO(document)).get_axioms()) == axioms
+
+try:
+    pyhornedowl.open_ontology_from_bytes(zipped([("a.owx", OWX), ("b.owx", OWX)]))
+    assert False, "a zip archive with several files is rejected"
+except ValueError as e:
+    assert "Unable to read zip archive" in str(e)
+
+# Text readers are read as they are.
+assert set(pyhornedowl.open_ontology_from_reader(io.StringIO(OWX)).get_axioms()) == axioms
+assert set(pyhornedowl.open_ontology_from_reader(io.StringIO(OWX), "owx").get_axioms()) == axioms
+
+# An OWX document is not guessed to be OBO because a literal contains a stanza header.
+commented = OWX.replace("</Ontology>", """<AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#comment"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal>format-version: 1.2
+[Term]
+id: X:1</Literal>
+    </AnnotationAssertion>
+</Ontology>""")
+assert len(pyhornedowl.open_ontology_from_string(commented).get_axioms()) == 5
+
+# OBO documents without a header start with a stanza rather than a JSON array.
+obo = pyhornedowl.open_ontology_from_string("[Term]\nid: GO:0000001\nname: mitochondrion inheritance\n")
+assert obo.get_classes() == {"http://purl.obolibrary.org/obo/GO_0000001"}
+jsonld = pyhornedowl.open_ontology_from_string(
+    '[{"@id": "http://example.com/A", "@type": "http://www.w3.org/2002/07/owl#Class"}]')
+assert jsonld.get_classes() == {"http://example.com/A"}
+
+# The prefixes of RDF/XML documents are kept.
+for onto in [pyhornedowl.open_ontology_from_string(RDF, "rdfxml"),
+             pyhornedowl.open_ontology_from_bytes(gzip.compress(RDF.encode()))]:
+    assert onto.get_iri_for_id("ex:A") == "http://example.com/A"
+
+# Text is never taken for a path, and a format that cannot be guessed is an error.
+try:
+    pyhornedowl.open_ontology_from_string("hello")
+    assert False, "text in an unknown format is rejected"
+except ValueError as e:
+    assert "Unknown format" in str(e)
+
+with tempfile.TemporaryDirectory() as directory:
+    path = os.path.join(directory, "onto.owx")
+    with open(path, "w") as f:
+        f.write(OWX)
+    try:
+        pyhornedowl.open_ontology_from_string(path)
+        assert False, "a path is not read as a file"
+    except ValueError:
+        pass
+
+    # Compressed files are decompressed, and the format is guessed from the inner extension.
+    for name, content in [("onto.owx.gz", gzip.compress(data)), ("onto.owl.bz2", bz2.compress(RDF.encode())),
+                          ("onto.zip", zipped([("onto.owx", OWX)]))]:
+        path = os.path.join(directory, name)
+        with open(path, "wb") as f:
+            f.write(content)
+        onto = pyhornedowl.open_ontology_from_file(path)
+        assert onto.get_iri_for_id("ex:A") == "http://example.com/A"
+    assert set(pyhornedowl.open_ontology_from_file(os.path.join(directory, "onto.owx.gz")).get_axioms()) == axioms
+
+    # open_ontology guesses formats as the explicit loaders do, and files are saved in the format
+    # their extension is read as.
+    for name, content in [("onto.owx.gz", None), ("onto.txt", OWX), ("onto.owl", RDF)]:
+        path = os.path.join(directory, name)
+        if content is not None:
+            with open(path, "w") as f:
+                f.write(content)
+        assert set(pyhornedowl.open_ontology(path).get_axioms()) == \
+            set(pyhornedowl.open_ontology_from_file(path).get_axioms())
+    for name in ["saved.owl", "saved.ttl", "saved.obo", "saved.json", "saved.jsonld"]:
+        path = os.path.join(directory, name)
+        pyhornedowl.open_ontology(OWX).save_to_file(path)
+        assert set(pyhornedowl.open_ontology(path).get_axioms()) == axioms, name
+
+    try:
+        pyhornedowl.open_ontology(os.path.join(directory, "missing.owx"))
+        assert False, "a missing file is reported"
+    except ValueError as e:
+        assert "no such file" in str(e)
+
+assert pyhornedowl.open_ontology("[Term]\nid: GO:0000001\n").get_classes() == {"http://purl.obolibrary.org/obo/GO_0000001"}
+assert set(pyhornedowl.open_ontology(RDF).get_axioms()) == set(pyhornedowl.open_ontology_from_string(RDF).get_axioms())
+
+print("loader tests passed")
@@ -0,0 +1,95 @@
+import os
+import tempfile
+
+import pyhornedowl
+from pyhornedowl.model import Class, DeclareClass
+
+CORE = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/core" versionIRI="http://example.com/core/1.0">
+    <Declaration><Class IRI="http://example.com/Thing"/></Declaration>
+    <Declaration><Class IRI="http://example.com/Part"/></Declaration>
+    <Declaration><ObjectProperty IRI="http://example.com/part_of"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/Part"/><Class IRI="http://example.com/Thing"/></SubClassOf>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/Part</IRI>
+        <Literal>part</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+DOMAIN = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/domain">
+    <Import>http://example.com/core</Import>
+    <Declaration><Class IRI="http://example.com/Wheel"/></Declaration>
+    <Declaration><DataProperty IRI="http://example.com/radius"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/Wheel"/><Class IRI="http://example.com/Part"/></SubClassOf>
+</Ontology>"""
+
+THING = "http://example.com/Thing"
+PART = "http://example.com/Part"
+WHEEL = "http://example.com/Wheel"
+
+with tempfile.TemporaryDirectory() as directory:
+    with open(os.path.join(directory, "core"), "w") as f:
+        f.write(CORE)
+    with open(os.path.join(directory, "domain.owx"), "w") as f:
+        f.write(DOMAIN)
+
+    manager = pyhornedowl.OntologyManager()
+    key = manager.load(os.path.join(directory, "domain.owx"), resolve_imports=True, catalog=directory)
+    assert key == "http://example.com/domain"
+    assert manager.get_ontology_iris() == {"http://example.com/domain", "http://example.com/core"}
+
+    # Members are found by ontology, version or import IRI.
+    assert manager.get("http://example.com/core/1.0").get_iri() == "http://example.com/core"
+    assert manager.get_imports("http://example.com/domain") == {"http://example.com/core"}
+    assert manager.get_imports_closure("http://example.com/domain") == [
+        "http://example.com/domain", "http://example.com/core"]
+
+    # Queries cover the imports closure of a member.
+    assert manager.get_classes("http://example.com/domain") == {THING, PART, WHEEL}
+    assert manager.get_classes("http://example.com/core") == {THING, PART}
+    assert manager.get_object_properties("http://example.com/domain") == {"http://example.com/part_of"}
+    assert manager.get_data_properties("http://example.com/domain") == {"http://example.com/radius"}
+    assert len(manager.get_axioms("http://example.com/domain")) == len(
+        manager.get_axioms("http://example.com/core")) + 5
+    assert len(manager.get_axioms_for_iri("http://example.com/domain", PART)) == 4
+    assert manager.get_iri_for_label("http://example.com/domain", "part") == PART
+    assert manager.get_iri_for_label("http://example.com/domain", "wheel") is None
+    assert manager.get_subclasses("http://example.com/domain", PART) == {WHEEL}
+    assert manager.get_superclasses("http://example.com/domain", WHEEL) == {PART}
+    assert manager.get_ancestors("http://example.com/domain", WHEEL) == {WHEEL, PART, THING}
+    assert manager.get_descendants("http://example.com/domain", THING) == {THING, PART, WHEEL}
+    assert manager.get_descendants("http://example.com/core", THING) == {THING, PART}
+
+    # Loading an ontology whose imports are already members does not load them again.
+    core = manager.get("http://example.com/core")
+    manager.load(os.path.join(directory, "domain.owx"), resolve_imports=True, catalog=directory)
+    assert manager.get("http://example.com/core") is core
+
+    manager.remove("http://example.com/core")
+    assert manager.get_ontology_iris() == {"http://example.com/domain"}
+    assert manager.get_imports_closure("http://example.com/domain") == ["http://example.com/domain"]
+    assert manager.get_classes("http://example.com/domain") == {WHEEL}
+
+    try:
+        manager.get("http://example.com/core")
+        assert False, "a removed member cannot be retrieved"
+    except KeyError:
+        pass
+
+# Added ontologies are shared with the caller and may be added under several keys.
+manager = pyhornedowl.OntologyManager()
+core = pyhornedowl.open_ontology(CORE)
+assert manager.add(core) == "http://example.com/core"
+assert manager.add(core, "http://example.com/alias") == "http://example.com/alias"
+core.remove_entity(PART)
+assert manager.get_classes("http://example.com/alias") == {THING}
+assert manager.get_descendants("http://example.com/core", THING) == {THING}
+
+# IRIs created through an added ontology come from the manager's shared cache.
+EXTRA = "http://example.com/Extra"
+core.add_axiom(DeclareClass(Class(core.iri(EXTRA))))
+assert manager.get_classes("http://example.com/core") == {THING, EXTRA}
+
+print("manager tests passed")
@@ -0,0 +1,94 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="http://example.com/"/>
+    <Declaration><Class IRI="http://example.com/atom"/></Declaration>
+    <Declaration><Class IRI="http://example.com/carbon_atom"/></Declaration>
+    <Declaration><ObjectProperty IRI="http://example.com/part_of"/></Declaration>
+    <Declaration><DataProperty IRI="http://example.com/mass"/></Declaration>
+    <Declaration><NamedIndividual IRI="http://example.com/c1"/></Declaration>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/carbon_atom</IRI>
+        <Literal>carbon atom</Literal>
+    </AnnotationAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/part_of</IRI>
+        <Literal>part of</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+
+def key(obj):
+    if isinstance(obj, (str, int, float, type(None))):
+        return obj
+    if isinstance(obj, IRI):
+        return str(obj)
+    if isinstance(obj, (list, tuple, set)):
+        return tuple(sorted(map(key, obj), key=repr))
+    attributes = [a for a in dir(obj) if not a.startswith("_") and not callable(getattr(obj, a))]
+    return (type(obj).__name__,) + tuple(key(getattr(obj, a)) for a in sorted(attributes))
+
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+
+atom = Class(onto.iri("http://example.com/atom"))
+carbon = Class(onto.iri("http://example.com/carbon_atom"))
+part_of = ObjectProperty(onto.iri("http://example.com/part_of"))
+mass = DataProperty(onto.iri("http://example.com/mass"))
+
+# Names are resolved as labels, CURIEs or full IRIs.
+assert key(onto.parse_class_expression("'carbon atom'")) == key(carbon)
+assert key(onto.parse_class_expression("ex:atom")) == key(atom)
+assert key(onto.parse_class_expression("<http://example.com/atom>")) == key(atom)
+
+ce = onto.parse_class_expression("ex:atom and ('part of' some 'carbon atom') and not ex:atom")
+assert key(ce) == key(ObjectIntersectionOf([atom, ObjectSomeValuesFrom(part_of, carbon), ObjectComplementOf(atom)]))
+assert key(onto.parse_class_expression("'part of' only (ex:atom or 'carbon atom')")) == \
+    key(ObjectAllValuesFrom(part_of, ObjectUnionOf([atom, carbon])))
+assert key(onto.parse_class_expression("inverse ('part of') min 2 ex:atom")) == \
+    key(ObjectMinCardinality(2, InverseObjectProperty(part_of), atom))
+assert key(onto.parse_class_expression("{ex:c1}")) == \
+    key(ObjectOneOf([NamedIndividual(onto.iri("http://example.com/c1"))]))
+
+# Axioms and declarations
+assert key(onto.parse_component("'carbon atom' SubClassOf: ex:atom")) == key(SubClassOf(atom, carbon))
+assert key(onto.parse_component("'part of' Characteristics: Transitive")) == key(TransitiveObjectProperty(part_of))
+assert key(onto.parse_component("Class: ex:new")) == \
+    key(DeclareClass(Class(onto.iri("http://example.com/new"))))
+assert key(onto.parse_component("ex:c1 Types: ex:atom")) == \
+    key(ClassAssertion(atom, NamedIndividual(onto.iri("http://example.com/c1"))))
+
+# Rendering uses labels where available and reads back.
+assert onto.render_manchester(carbon) == "'carbon atom'"
+assert onto.render_manchester(atom) == "ex:atom"
+for text in ["'part of' some 'carbon atom'",
+             "ex:atom and (not 'carbon atom')",
+             "'part of' only (ex:atom or 'carbon atom')"]:
+    expression = onto.parse_class_expression(text)
+    assert key(onto.parse_class_expression(onto.render_manchester(expression))) == key(expression)
+
+axiom = onto.parse_component("'carbon atom' SubClassOf: 'part of' some ex:atom")
+assert key(onto.parse_component(onto.render_manchester(axiom))) == key(axiom)
+
+# An entity with several labels is always rendered with the same one.
+LABELLED = ONTOLOGY.replace("</Ontology>", "".join(f"""
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/atom</IRI>
+        <Literal>{label}</Literal>
+    </AnnotationAssertion>""" for label in ["zinc atom", "atom of zinc", "metal atom"]) + "</Ontology>")
+for _ in range(20):
+    assert pyhornedowl.open_ontology(LABELLED).render_manchester(atom) == "'atom of zinc'"
+
+for text in ["ex:atom and", "'unknown label'", "(ex:atom"]:
+    try:
+        onto.parse_class_expression(text)
+        assert False, f"{text!r} is rejected"
+    except ValueError:
+        pass
+
+print("manchester syntax tests passed")
@@ -0,0 +1,95 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <SubClassOf>
+        <Class IRI="http://example.com/hand"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/part_of"/><Class IRI="http://example.com/arm"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <SubClassOf>
+        <Class IRI="http://example.com/finger"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/part_of"/><Class IRI="http://example.com/hand"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <SubClassOf>
+        <Class IRI="http://example.com/hand"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/has_part"/><Class IRI="http://example.com/finger"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <SubClassOf><Class IRI="http://example.com/loop"/><Class IRI="http://example.com/loop"/></SubClassOf>
+    <SubClassOf><Class IRI="http://example.com/hand"/><Class IRI="http://example.com/limb_part"/></SubClassOf>
+    <EquivalentClasses><Class IRI="http://example.com/finger"/><Class IRI="http://example.com/digit"/></EquivalentClasses>
+    <SubClassOf>
+        <Class IRI="http://example.com/thumb"/>
+        <ObjectIntersectionOf>
+            <Class IRI="http://example.com/digit"/>
+            <Class IRI="http://example.com/hand_part"/>
+            <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/part_of"/><Class IRI="http://example.com/hand_part"/></ObjectSomeValuesFrom>
+        </ObjectIntersectionOf>
+    </SubClassOf>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/hand</IRI>
+        <Literal xml:lang="en">hand</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+
+
+def var(name):
+    return Class(IRI.parse(f"?{name}"))
+
+
+def bindings(pattern):
+    return sorted(sorted((k, str(v.first)) for k, v in b.items()) for _, b in onto.match_pattern(pattern))
+
+
+part_of = ObjectProperty(onto.iri("http://example.com/part_of"))
+
+# Variables bind to the expressions in their position.
+assert bindings(SubClassOf(ObjectSomeValuesFrom(part_of, var("y")), var("x"))) == [
+    [("x", "http://example.com/finger"), ("y", "http://example.com/hand")],
+    [("x", "http://example.com/hand"), ("y", "http://example.com/arm")],
+]
+
+# Variables may stand for properties and for nested class expressions.
+matches = bindings(SubClassOf(ObjectSomeValuesFrom(ObjectProperty(IRI.parse("?p")), var("y")),
+                              Class(onto.iri("http://example.com/hand"))))
+assert matches == [
+    [("p", "http://example.com/has_part"), ("y", "http://example.com/finger")],
+    [("p", "http://example.com/part_of"), ("y", "http://example.com/arm")],
+]
+assert len(onto.match_pattern(SubClassOf(var("sup"), Class(onto.iri("http://example.com/hand"))))) == 3
+
+# A variable used twice must bind to the same value.
+assert bindings(SubClassOf(var("x"), var("x"))) == [[("x", "http://example.com/loop")]]
+
+# The anonymous variable matches anything without being bound.
+assert bindings(SubClassOf(ObjectSomeValuesFrom(part_of, var("")), var("x"))) == [
+    [("x", "http://example.com/finger")], [("x", "http://example.com/hand")]]
+
+# A simple literal variable matches any literal.
+label = AnnotationProperty(onto.iri("http://www.w3.org/2000/01/rdf-schema#label"))
+[(component, binding)] = onto.match_pattern(AnnotationAssertion(IRI.parse("?s"), Annotation(label, SimpleLiteral("?l"))))
+assert isinstance(component.component, AnnotationAssertion)
+assert str(binding["s"]) == "http://example.com/hand"
+assert isinstance(binding["l"], LanguageLiteral)
+assert (binding["l"].literal, binding["l"].lang) == ("hand", "en")
+
+# The operands of n-ary constructs match in any order.
+finger, digit = Class(onto.iri("http://example.com/finger")), Class(onto.iri("http://example.com/digit"))
+assert len(onto.match_pattern(EquivalentClasses([finger, digit]))) == 1
+assert len(onto.match_pattern(EquivalentClasses([digit, finger]))) == 1
+assert bindings(EquivalentClasses([var("x"), finger])) == [[("x", "http://example.com/digit")]]
+
+# Operands are tried against each other until the bindings of all agree, and a failed attempt
+# leaves no bindings behind.
+assert bindings(SubClassOf(ObjectIntersectionOf([var("x"), var("y"), ObjectSomeValuesFrom(part_of, var("x"))]),
+                           var("s"))) == [
+    [("s", "http://example.com/thumb"), ("x", "http://example.com/hand_part"), ("y", "http://example.com/digit")]]
+assert onto.match_pattern(SubClassOf(ObjectIntersectionOf([var("x"), var("x"), var("y")]), var("s"))) == []
+
+# Patterns without matches return nothing.
+assert onto.match_pattern(SubClassOf(var("x"), Class(onto.iri("http://example.com/arm")))) == []
+
+print("pattern matching tests passed")
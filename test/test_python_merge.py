@@ -0,0 +1,84 @@
+import warnings
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+FIRST = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/first">
+    <Prefix name="ex" IRI="http://example.com/first#"/>
+    <Prefix name="a" IRI="http://example.com/a#"/>
+    <Import>http://example.com/second</Import>
+    <Import>http://example.com/other</Import>
+    <Declaration><Class IRI="http://example.com/first#A"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/first#A"/><Class IRI="http://example.com/second#B"/></SubClassOf>
+</Ontology>"""
+
+SECOND = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/second">
+    <Prefix name="ex" IRI="http://example.com/second#"/>
+    <Prefix name="b" IRI="http://example.com/b#"/>
+    <Declaration><Class IRI="http://example.com/second#B"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/second#B"/><Class IRI="http://example.com/second#C"/></SubClassOf>
+</Ontology>"""
+
+A = "http://example.com/first#A"
+B = "http://example.com/second#B"
+C = "http://example.com/second#C"
+
+
+def open_test_ontology(text):
+    return pyhornedowl.open_ontology(text)
+
+
+def imports(onto):
+    return sorted(str(ac.component.first) for ac in onto.get_axioms() if isinstance(ac.component, Import))
+
+
+def prefix_warnings(record):
+    return [str(w.message) for w in record if issubclass(w.category, UserWarning)]
+
+
+# merge adds components and prefixes, keeping the first binding of a conflicting prefix.
+first = open_test_ontology(FIRST)
+assert first.merge(open_test_ontology(SECOND)) == ["ex"]
+
+assert first.get_iri() == "http://example.com/first"
+assert first.get_classes() == {A, B}
+assert pyhornedowl.get_ancestors(first, A) == {A, B, C}
+assert first.get_iri_for_id("ex:A") == A
+assert first.get_iri_for_id("b:X") == "http://example.com/b#X"
+assert imports(first) == ["http://example.com/other", "http://example.com/second"]
+
+# Imports of merged ontologies are dropped on request and the ontology ID can be replaced.
+first = open_test_ontology(FIRST)
+first.merge(open_test_ontology(SECOND),
+            OntologyID(IRI.parse("http://example.com/merged"), None),
+            drop_merged_imports=True)
+assert first.get_iri() == "http://example.com/merged"
+assert imports(first) == ["http://example.com/other"]
+
+# merge_ontologies combines several ontologies into a new one and reports conflicts the same way.
+first = open_test_ontology(FIRST)
+second = open_test_ontology(SECOND)
+with warnings.catch_warnings(record=True) as record:
+    warnings.simplefilter("always")
+    merged = pyhornedowl.merge_ontologies([first, second], drop_merged_imports=True)
+assert prefix_warnings(record) == ["Prefix 'ex' is bound to different IRIs, keeping the first binding."]
+
+assert merged.get_iri() == "http://example.com/first"
+assert merged.get_classes() == {A, B}
+assert imports(merged) == ["http://example.com/other"]
+assert pyhornedowl.get_ancestors(merged, A) == {A, B, C}
+
+# The merged ontologies are left unchanged.
+assert first.get_classes() == {A}
+assert second.get_classes() == {B}
+
+# Ontologies without conflicting prefixes merge without warnings.
+with warnings.catch_warnings(record=True) as record:
+    warnings.simplefilter("always")
+    merged = pyhornedowl.merge_ontologies([second, open_test_ontology(SECOND)])
+assert prefix_warnings(record) == []
+assert merged.get_classes() == {B}
+
+print("merge tests passed")
@@ -0,0 +1,136 @@
+import os
+import tempfile
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+OBO_DOCUMENT = """format-version: 1.4
+ontology: test
+
+[Term]
+id: TEST:0000001
+name: hand
+def: "A hand." [PMID:1]
+synonym: "manus" EXACT []
+xref: UBERON:0002398
+is_a: TEST:0000003 ! limb part
+relationship: part_of TEST:0000002 ! arm
+
+[Term]
+id: TEST:0000002
+name: arm
+
+[Term]
+id: TEST:0000003
+name: limb part
+intersection_of: TEST:0000002 ! arm
+intersection_of: part_of TEST:0000001 ! hand
+
+[Term]
+id: TEST:0000004
+name: old term
+is_obsolete: true
+replaced_by: TEST:0000001
+
+[Typedef]
+id: part_of
+name: part of
+xref: BFO:0000050
+is_transitive: true
+"""
+
+OBO = "http://purl.obolibrary.org/obo/"
+HAND = OBO + "TEST_0000001"
+ARM = OBO + "TEST_0000002"
+LIMB_PART = OBO + "TEST_0000003"
+OLD = OBO + "TEST_0000004"
+PART_OF = OBO + "BFO_0000050"
+
+
+def read_obo(text):
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "test.obo")
+        with open(path, "w") as f:
+            f.write(text)
+        return pyhornedowl.open_ontology(path)
+
+
+onto = read_obo(OBO_DOCUMENT)
+assert onto.get_iri() == OBO + "test.owl"
+
+# Tags follow the OBO to OWL mapping.
+assert onto.get_classes() == {HAND, ARM, LIMB_PART, OLD}
+assert onto.get_object_properties() == {PART_OF}
+assert onto.get_iri_for_label("hand") == HAND
+assert onto.get_annotations(HAND, OBO + "IAO_0000115") == ["A hand."]
+assert onto.get_annotations(HAND, "http://www.geneontology.org/formats/oboInOwl#hasExactSynonym") == ["manus"]
+assert onto.get_annotations(HAND, "http://www.geneontology.org/formats/oboInOwl#hasDbXref") == ["UBERON:0002398"]
+assert onto.get_superclasses(HAND) == {LIMB_PART}
+assert onto.get_obsolete_entities() == {OLD}
+
+hand, arm, limb_part = (Class(onto.iri(iri)) for iri in (HAND, ARM, LIMB_PART))
+part_of = ObjectProperty(onto.iri(PART_OF))
+axioms = {ac.component for ac in onto.get_axioms()}
+assert SubClassOf(ObjectSomeValuesFrom(part_of, arm), hand) in axioms
+assert EquivalentClasses([limb_part, ObjectIntersectionOf([arm, ObjectSomeValuesFrom(part_of, hand)])]) in axioms
+assert TransitiveObjectProperty(part_of) in axioms
+
+# The definition keeps its xrefs as axiom annotations.
+[definition] = [ac for ac in onto.get_axioms_for_iri(HAND)
+                if isinstance(ac.component, AnnotationAssertion)
+                and str(ac.component.ann.ap.first) == OBO + "IAO_0000115"]
+assert [str(a.av) for a in definition.ann] == ['"PMID:1"']
+
+# Writing produces the same document, which reads back to the same ontology.
+assert onto.write_to_obo_string() == OBO_DOCUMENT
+restored = read_obo(onto.write_to_obo_string())
+assert set(restored.get_axioms()) == set(onto.get_axioms())
+
+# Trailing qualifiers become axiom annotations, and synonym types keep their scope.
+QUALIFIED_DOCUMENT = """format-version: 1.4
+synonymtypedef: UK_SPELLING "British spelling" EXACT
+ontology: test
+
+[Term]
+id: TEST:0000001
+name: hand
+synonym: "handd" EXACT UK_SPELLING []
+relationship: part_of TEST:0000002 {source="PMID:2"} ! arm
+
+[Term]
+id: TEST:0000002
+name: arm
+
+[Typedef]
+id: part_of
+name: part of
+xref: BFO:0000050
+"""
+onto = read_obo(QUALIFIED_DOCUMENT)
+[relationship] = [ac for ac in onto.get_axioms_for_iri(HAND) if isinstance(ac.component, SubClassOf)]
+assert [(str(a.ap.first), str(a.av)) for a in relationship.ann] == \
+    [("http://www.geneontology.org/formats/oboInOwl#source", '"PMID:2"')]
+assert onto.get_annotations(OBO + "test#UK_SPELLING", "http://www.geneontology.org/formats/oboInOwl#hasScope") == ["EXACT"]
+assert onto.write_to_obo_string() == QUALIFIED_DOCUMENT
+
+# An OWX document is not mistaken for OBO because a literal contains a stanza header.
+owx = pyhornedowl.open_ontology("""<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#comment"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal>format-version: 1.2
+[Term]
+id: X:1</Literal>
+    </AnnotationAssertion>
+</Ontology>""")
+assert owx.get_iri() == "http://example.com/onto"
+
+for text in ["[Term]\nname: no id\n", "format-version: 1.4\n\n[Term]\nid: X:1\nis_a\n"]:
+    try:
+        read_obo(text)
+        assert False, f"{text!r} is rejected"
+    except ValueError:
+        pass
+
+print("obo tests passed")
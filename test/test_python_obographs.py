@@ -0,0 +1,93 @@
+import json
+import os
+import tempfile
+
+import pyhornedowl
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://purl.obolibrary.org/obo/test.owl">
+    <Declaration><Class IRI="http://purl.obolibrary.org/obo/TEST_1"/></Declaration>
+    <Declaration><Class IRI="http://purl.obolibrary.org/obo/TEST_2"/></Declaration>
+    <Declaration><Class IRI="http://purl.obolibrary.org/obo/TEST_3"/></Declaration>
+    <Declaration><ObjectProperty IRI="http://purl.obolibrary.org/obo/BFO_0000050"/></Declaration>
+    <SubClassOf><Class IRI="http://purl.obolibrary.org/obo/TEST_1"/><Class IRI="http://purl.obolibrary.org/obo/TEST_2"/></SubClassOf>
+    <SubClassOf><Class IRI="http://purl.obolibrary.org/obo/TEST_1"/>
+      <ObjectSomeValuesFrom><ObjectProperty IRI="http://purl.obolibrary.org/obo/BFO_0000050"/><Class IRI="http://purl.obolibrary.org/obo/TEST_3"/></ObjectSomeValuesFrom></SubClassOf>
+    <EquivalentClasses><Class IRI="http://purl.obolibrary.org/obo/TEST_3"/>
+      <ObjectIntersectionOf><Class IRI="http://purl.obolibrary.org/obo/TEST_2"/>
+      <ObjectSomeValuesFrom><ObjectProperty IRI="http://purl.obolibrary.org/obo/BFO_0000050"/><Class IRI="http://purl.obolibrary.org/obo/TEST_1"/></ObjectSomeValuesFrom></ObjectIntersectionOf></EquivalentClasses>
+    <AnnotationAssertion><AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/><IRI>http://purl.obolibrary.org/obo/TEST_1</IRI><Literal>hand</Literal></AnnotationAssertion>
+    <AnnotationAssertion><AnnotationProperty IRI="http://purl.obolibrary.org/obo/IAO_0000115"/><IRI>http://purl.obolibrary.org/obo/TEST_1</IRI><Literal>A hand.</Literal></AnnotationAssertion>
+    <AnnotationAssertion><AnnotationProperty IRI="http://www.geneontology.org/formats/oboInOwl#hasExactSynonym"/><IRI>http://purl.obolibrary.org/obo/TEST_1</IRI><Literal>manus</Literal></AnnotationAssertion>
+    <AnnotationAssertion><AnnotationProperty IRI="http://www.w3.org/2002/07/owl#deprecated"/><IRI>http://purl.obolibrary.org/obo/TEST_2</IRI><Literal datatypeIRI="http://www.w3.org/2001/XMLSchema#boolean">true</Literal></AnnotationAssertion>
+</Ontology>"""
+
+OBO = "http://purl.obolibrary.org/obo/"
+PART_OF = OBO + "BFO_0000050"
+
+
+def read_obographs(text, format=None):
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "test.json")
+        with open(path, "w") as f:
+            f.write(text)
+        return pyhornedowl.open_ontology_from_file(path, format) if format else pyhornedowl.open_ontology(path)
+
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+[graph] = json.loads(onto.write_to_obographs_string())["graphs"]
+assert graph["id"] == OBO + "test.owl"
+
+# Declared entities become nodes with their metadata.
+nodes = {node["id"]: node for node in graph["nodes"]}
+assert set(nodes) == {OBO + "TEST_1", OBO + "TEST_2", OBO + "TEST_3", PART_OF}
+assert nodes[PART_OF]["type"] == "PROPERTY"
+hand = nodes[OBO + "TEST_1"]
+assert hand["type"] == "CLASS"
+assert hand["lbl"] == "hand"
+assert hand["meta"]["definition"]["val"] == "A hand."
+assert [(s["pred"], s["val"]) for s in hand["meta"]["synonyms"]] == [("hasExactSynonym", "manus")]
+assert nodes[OBO + "TEST_2"]["meta"]["deprecated"] is True
+
+# Subclass axioms and existential restrictions become edges, equivalences logical definitions.
+assert sorted((e["sub"], e["pred"], e["obj"]) for e in graph["edges"]) == [
+    (OBO + "TEST_1", PART_OF, OBO + "TEST_3"),
+    (OBO + "TEST_1", "is_a", OBO + "TEST_2"),
+]
+[definition] = graph["logicalDefinitionAxioms"]
+assert definition["definedClassId"] == OBO + "TEST_3"
+assert definition["genusIds"] == [OBO + "TEST_2"]
+assert definition["restrictions"] == [{"propertyId": PART_OF, "fillerId": OBO + "TEST_1"}]
+
+# Reading the graph back restores the ontology.
+restored = read_obographs(onto.write_to_obographs_string())
+assert set(restored.get_axioms()) == set(onto.get_axioms())
+
+with tempfile.TemporaryDirectory() as directory:
+    path = os.path.join(directory, "test.json")
+    onto.save_to_file(path, "obographs")
+    assert set(pyhornedowl.open_ontology(path).get_axioms()) == set(onto.get_axioms())
+
+# Class assertions read back as such even if the individual is not declared.
+UNDECLARED = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://purl.obolibrary.org/obo/test.owl">
+    <Declaration><Class IRI="http://purl.obolibrary.org/obo/TEST_1"/></Declaration>
+    <ClassAssertion><Class IRI="http://purl.obolibrary.org/obo/TEST_1"/><NamedIndividual IRI="http://example.com/i"/></ClassAssertion>
+</Ontology>"""
+onto = pyhornedowl.open_ontology(UNDECLARED)
+[graph] = json.loads(onto.write_to_obographs_string())["graphs"]
+assert [(e["sub"], e["pred"], e["obj"]) for e in graph["edges"]] == [("http://example.com/i", "type", OBO + "TEST_1")]
+assert set(read_obographs(onto.write_to_obographs_string()).get_axioms()) == set(onto.get_axioms())
+
+# JSON-LD documents also use .json and are recognised by their content.
+jsonld = read_obographs('{"@id": "http://example.com/A", "@type": "http://www.w3.org/2002/07/owl#Class"}')
+assert jsonld.get_classes() == {"http://example.com/A"}
+
+for text in ["{", "[]", '{"graphs": [{"nodes": [{"type": "CLASS"}]}]}']:
+    try:
+        read_obographs(text, "obographs")
+        assert False, f"{text!r} is rejected"
+    except ValueError:
+        pass
+
+print("obographs tests passed")
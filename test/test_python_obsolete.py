@@ -0,0 +1,82 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <Declaration><Class IRI="http://example.com/B"/></Declaration>
+    <Declaration><Class IRI="http://example.com/C"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+    <SubClassOf><Class IRI="http://example.com/C"/><Class IRI="http://example.com/A"/></SubClassOf>
+    <AnnotationAssertion>
+        <Annotation>
+            <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#comment"/>
+            <Literal>curated</Literal>
+        </Annotation>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal xml:lang="en">thing</Literal>
+    </AnnotationAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/B</IRI>
+        <Literal>other thing</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+A = "http://example.com/A"
+B = "http://example.com/B"
+C = "http://example.com/C"
+LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+REPLACED_BY = "http://purl.obolibrary.org/obo/IAO_0100001"
+CONSIDER = "http://www.geneontology.org/formats/oboInOwl#consider"
+REASON = "http://purl.obolibrary.org/obo/IAO_0000231"
+
+
+def values(onto, subject, prop):
+    return [ac.component.ann.av for ac in onto.get_axioms()
+            if isinstance(ac.component, AnnotationAssertion) and str(ac.component.subject) == subject
+            and str(ac.component.ann.ap.first) == prop]
+
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+assert onto.get_obsolete_entities() == set()
+
+onto.obsolete_entity(A, replaced_by=B, consider=[C], reason="http://example.com/reason")
+
+assert onto.get_obsolete_entities() == {A}
+
+# The asserted label is replaced, keeping its language tag and axiom annotations.
+[label] = values(onto, A, LABEL)
+assert (label.literal, label.lang) == ("obsolete thing", "en")
+[label_axiom] = [ac for ac in onto.get_axioms()
+                 if isinstance(ac.component, AnnotationAssertion) and str(ac.component.subject) == A
+                 and str(ac.component.ann.ap.first) == LABEL]
+assert [a.av.literal for a in label_axiom.ann] == ["curated"]
+
+assert [str(v) for v in values(onto, A, REPLACED_BY)] == [B]
+assert [str(v) for v in values(onto, A, CONSIDER)] == [C]
+assert [str(v) for v in values(onto, A, REASON)] == ["http://example.com/reason"]
+
+# Logical axioms about the entity are removed, references from other entities are kept.
+assert onto.get_superclasses(A) == set()
+assert onto.get_subclasses(A) == {C}
+assert A in onto.get_classes()
+
+# The label index follows the replaced label.
+assert onto.get_iri_for_label("other thing") == B
+onto.obsolete_entity(B)
+assert onto.get_obsolete_entities() == {A, B}
+assert onto.get_iri_for_label("obsolete other thing") == B
+assert onto.get_iri_for_label("other thing") is None
+
+# The reason must be an IRI, and a rejected call leaves the entity untouched.
+try:
+    onto.obsolete_entity(C, reason="out of scope")
+    assert False, "expected ValueError"
+except ValueError:
+    pass
+assert onto.get_obsolete_entities() == {A, B}
+assert onto.get_superclasses(C) == {A}
+
+print("obsolete_entity tests passed")
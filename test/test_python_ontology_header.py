@@ -0,0 +1,79 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto" versionIRI="http://example.com/onto/1.0">
+    <Import>http://example.com/imported</Import>
+    <Annotation>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#comment"/>
+        <Literal>An ontology</Literal>
+    </Annotation>
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+</Ontology>"""
+
+COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+
+
+def annotations(onto):
+    return [(str(a.ap.first), a.av.literal) for a in onto.get_ontology_annotations()]
+
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+assert onto.get_iri() == "http://example.com/onto"
+assert onto.get_version_iri() == "http://example.com/onto/1.0"
+
+# The ontology and version IRIs are set independently of each other.
+onto.set_iri("http://example.com/renamed")
+assert onto.get_iri() == "http://example.com/renamed"
+assert onto.get_version_iri() == "http://example.com/onto/1.0"
+
+onto.set_version_iri("http://example.com/renamed/2.0")
+assert onto.get_iri() == "http://example.com/renamed"
+assert onto.get_version_iri() == "http://example.com/renamed/2.0"
+
+onto.set_version_iri(None)
+assert onto.get_iri() == "http://example.com/renamed"
+assert onto.get_version_iri() is None
+
+onto.set_iri(None)
+assert onto.get_iri() is None
+
+onto.set_iri("http://example.com/onto")
+
+# The changed header is written out.
+written = pyhornedowl.open_ontology(onto.write_to_owx_string())
+assert written.get_iri() == "http://example.com/onto"
+assert written.get_version_iri() is None
+
+# Ontology annotations
+[annotation] = onto.get_ontology_annotations()
+assert str(annotation.ap.first) == COMMENT
+assert annotation.av.literal == "An ontology"
+
+creator = Annotation(AnnotationProperty(onto.iri("http://purl.org/dc/terms/creator")), SimpleLiteral("someone"))
+onto.add_ontology_annotation(creator)
+assert sorted(annotations(onto)) == [("http://purl.org/dc/terms/creator", "someone"), (COMMENT, "An ontology")]
+
+onto.remove_ontology_annotation(annotation)
+assert annotations(onto) == [("http://purl.org/dc/terms/creator", "someone")]
+
+try:
+    onto.remove_ontology_annotation(annotation)
+    assert False, "removing a missing annotation raises"
+except ValueError:
+    pass
+
+# Imports
+assert onto.get_imports() == ["http://example.com/imported"]
+onto.add_import("http://example.com/other")
+assert sorted(onto.get_imports()) == ["http://example.com/imported", "http://example.com/other"]
+onto.remove_import("http://example.com/imported")
+assert onto.get_imports() == ["http://example.com/other"]
+
+try:
+    onto.remove_import("http://example.com/imported")
+    assert False, "removing a missing import raises"
+except ValueError:
+    pass
+
+print("ontology header tests passed")
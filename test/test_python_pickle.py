@@ -0,0 +1,75 @@
+import copy
+import pickle
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="http://example.com/"/>
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal>a</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+A = "http://example.com/A"
+B = "http://example.com/B"
+
+# Model objects
+a = Class(IRI.parse(A))
+annotation = Annotation(AnnotationProperty(IRI.parse("http://www.w3.org/2000/01/rdf-schema#comment")),
+                        LanguageLiteral("note", "en"))
+restriction = ObjectSomeValuesFrom(ObjectProperty(IRI.parse("http://example.com/p")), a)
+for value in [IRI.parse(A), a, restriction, SubClassOf(Class(IRI.parse(B)), restriction),
+              DatatypeLiteral("1", IRI.parse("http://www.w3.org/2001/XMLSchema#integer")), annotation,
+              AnnotatedComponent(DeclareClass(a), {annotation}), ComponentKind.SubClassOf]:
+    assert pickle.loads(pickle.dumps(value)) == value
+    assert copy.copy(value) == value
+    assert copy.deepcopy(value) == value
+
+# Copies of model objects are independent of the original.
+component = AnnotatedComponent(DeclareClass(a), set())
+duplicate = copy.deepcopy(component)
+duplicate.ann = {annotation}
+assert component.ann == set()
+
+# Ontologies round trip through pickle with their prefixes and indexes.
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+restored = pickle.loads(pickle.dumps(onto))
+assert set(restored.get_axioms()) == set(onto.get_axioms())
+assert restored.get_iri() == "http://example.com/onto"
+assert restored.get_iri_for_id("ex:A") == A
+assert restored.get_iri_for_label("a") == A
+assert restored.get_superclasses(A) == {B}
+
+# The pickle payload is read as OWX even when a literal looks like another format.
+onto = pyhornedowl.open_ontology("""<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#comment"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal>format-version: 1.2
+[Term]
+id: X:1</Literal>
+    </AnnotationAssertion>
+</Ontology>""")
+restored = pickle.loads(pickle.dumps(onto))
+assert set(restored.get_axioms()) == set(onto.get_axioms())
+assert isinstance(pickle.loads(pickle.dumps(onto, protocol=0)), pyhornedowl.PyIndexedOntology)
+
+# Copies of ontologies are independent of the original.
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+for duplicate in [copy.copy(onto), copy.deepcopy(onto), onto.clone()]:
+    duplicate.remove_entity(A)
+    duplicate.add_prefix_mapping("other", "http://example.com/other#")
+    assert duplicate.get_axioms_for_iri(A) == []
+    assert len(onto.get_axioms_for_iri(A)) == 3
+    assert onto.get_iri_for_label("a") == A
+    assert onto.get_superclasses(A) == {B}
+    assert onto.get_iri_for_id("other:x") is None
+
+print("pickle and copy tests passed")
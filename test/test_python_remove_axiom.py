@@ -0,0 +1,16 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+CLASSES = ["http://example.com/E%d" % i for i in range(80)]
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <EquivalentClasses>%s</EquivalentClasses>
+</Ontology>""" % "".join('<Class IRI="%s"/>' % c for c in CLASSES)
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+[ax] = onto.get_axioms_for_iri(CLASSES[0])
+
+# A removed axiom is no longer found through any of the IRIs it mentions.
+onto.remove_axiom(ax.component)
+assert all(onto.get_axioms_for_iri(c) == [] for c in CLASSES)
@@ -0,0 +1,92 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <Declaration><Class IRI="http://example.com/B"/></Declaration>
+    <Declaration><Class IRI="http://example.com/C"/></Declaration>
+    <Declaration><ObjectProperty IRI="http://example.com/p"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+    <SubClassOf><Class IRI="http://example.com/B"/><Class IRI="http://example.com/C"/></SubClassOf>
+    <SubClassOf>
+        <Class IRI="http://example.com/C"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/p"/><Class IRI="http://example.com/B"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/B</IRI>
+        <Literal>b</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+A = "http://example.com/A"
+B = "http://example.com/B"
+C = "http://example.com/C"
+
+
+def open_test_ontology():
+    return pyhornedowl.open_ontology(ONTOLOGY)
+
+
+# mode="all" removes every component mentioning the entity.
+onto = open_test_ontology()
+assert onto.remove_entity(B) == 5
+assert onto.get_axioms_for_iri(B) == []
+assert B not in onto.get_classes()
+assert onto.get_iri_for_label("b") is None
+assert onto.get_superclasses(A) == set()
+assert onto.get_subclasses(C) == set()
+
+# mode="subject" keeps axioms that only refer to the entity.
+onto = open_test_ontology()
+assert onto.remove_entity(B, "subject") == 3
+assert B not in onto.get_classes()
+assert onto.get_superclasses(A) == {B}
+assert len(onto.get_axioms_for_iri(B)) == 2
+
+# mode="rewire" connects the subclasses of the entity to its superclasses.
+onto = open_test_ontology()
+assert onto.remove_entity(B, "rewire") == 5
+assert onto.get_superclasses(A) == {C}
+assert onto.get_subclasses(C) == {A}
+
+# Removing an entity that is not referenced removes nothing.
+onto = open_test_ontology()
+assert onto.remove_entity("http://example.com/unknown") == 0
+assert len(onto.get_axioms()) == 9
+
+try:
+    onto.remove_entity(B, "unknown")
+    assert False, "an unknown mode is rejected"
+except ValueError:
+    pass
+
+# A hierarchy edge asserted by another, annotated axiom survives removing an annotation value.
+onto = pyhornedowl.open_ontology("""<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+    <SubClassOf>
+        <Annotation>
+            <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#seeAlso"/>
+            <IRI>http://example.com/X</IRI>
+        </Annotation>
+        <Class IRI="http://example.com/A"/>
+        <Class IRI="http://example.com/B"/>
+    </SubClassOf>
+</Ontology>""")
+assert onto.remove_entity("http://example.com/X") == 1
+assert len(onto.get_axioms()) == 2
+assert onto.get_superclasses(A) == {B}
+assert onto.get_subclasses(B) == {A}
+
+# A removed axiom is no longer found through any of the IRIs it mentions.
+classes = ["http://example.com/E%d" % i for i in range(80)]
+onto = pyhornedowl.open_ontology("""<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <EquivalentClasses>%s</EquivalentClasses>
+</Ontology>""" % "".join('<Class IRI="%s"/>' % c for c in classes))
+assert onto.remove_entity(classes[0]) == 1
+assert all(onto.get_axioms_for_iri(c) == [] for c in classes)
+
+print("remove_entity tests passed")
@@ -0,0 +1,68 @@
+import datetime
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <Declaration><Class IRI="http://example.com/B"/></Declaration>
+    <SubClassOf><Class IRI="http://example.com/A"/><Class IRI="http://example.com/B"/></SubClassOf>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal>a</Literal>
+    </AnnotationAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://example.com/count"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal datatypeIRI="http://www.w3.org/2001/XMLSchema#integer">3</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+PREFIXES = """
+PREFIX owl: <http://www.w3.org/2002/07/owl#>
+PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+"""
+CLASSES = PREFIXES + "SELECT ?c WHERE { ?c a owl:Class } ORDER BY ?c"
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+
+# SELECT returns one dict per solution, with None for unbound variables.
+assert onto.sparql_query(CLASSES) == [{"c": "http://example.com/A"}, {"c": "http://example.com/B"}]
+assert onto.sparql_query(PREFIXES + """
+SELECT ?c ?label WHERE { ?c a owl:Class OPTIONAL { ?c rdfs:label ?label } } ORDER BY ?c
+""") == [{"c": "http://example.com/A", "label": "a"}, {"c": "http://example.com/B", "label": None}]
+
+# Literals with a known datatype are converted to Python values.
+assert onto.sparql_query("SELECT ?n WHERE { ?s <http://example.com/count> ?n }") == [{"n": 3}]
+assert onto.sparql_query("""
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+SELECT ?v WHERE { VALUES ?v { "2020-01-02Z"^^xsd:date "maybe"^^xsd:boolean " x "^^xsd:string } }
+""") == [{"v": datetime.date(2020, 1, 2)}, {"v": "maybe"}, {"v": " x "}]
+
+# ASK returns a bool.
+assert onto.sparql_query(PREFIXES + "ASK { <http://example.com/A> rdfs:subClassOf <http://example.com/B> }") is True
+assert onto.sparql_query(PREFIXES + "ASK { <http://example.com/B> rdfs:subClassOf <http://example.com/A> }") is False
+
+# CONSTRUCT returns triples.
+assert onto.sparql_query(PREFIXES + """
+CONSTRUCT { ?sup <http://example.com/superOf> ?sub } WHERE { ?sub rdfs:subClassOf ?sup }
+""") == [("http://example.com/B", "http://example.com/superOf", "http://example.com/A")]
+
+# Results follow changes to the ontology.
+onto.add_axiom(DeclareClass(Class(onto.iri("http://example.com/C"))))
+assert [r["c"] for r in onto.sparql_query(CLASSES)] == [
+    "http://example.com/A", "http://example.com/B", "http://example.com/C"]
+onto.remove_axiom(DeclareClass(Class(onto.iri("http://example.com/A"))))
+assert [r["c"] for r in onto.sparql_query(CLASSES)] == ["http://example.com/B", "http://example.com/C"]
+onto.remove_entity("http://example.com/B")
+assert [r["c"] for r in onto.sparql_query(CLASSES)] == ["http://example.com/C"]
+
+try:
+    onto.sparql_query("SELECT WHERE")
+    assert False, "a malformed query raises"
+except ValueError:
+    pass
+
+print("sparql tests passed")
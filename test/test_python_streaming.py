@@ -0,0 +1,62 @@
+import os
+import tempfile
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+onto = pyhornedowl.open_ontology("""<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="http://example.com/"/>
+    <Declaration><ObjectProperty IRI="http://example.com/p"/></Declaration>
+</Ontology>""")
+
+# Enough components for several MiB of output, including blank nodes in RDF.
+p = ObjectProperty(onto.iri("http://example.com/p"))
+for i in range(4000):
+    cls = Class(onto.iri(f"http://example.com/C{i}"))
+    onto.add_axiom(DeclareClass(cls))
+    onto.add_axiom(SubClassOf(ObjectSomeValuesFrom(p, Class(onto.iri(f"http://example.com/C{i + 1}"))), cls))
+axioms = set(onto.get_axioms())
+assert len(axioms) == 8002
+
+with tempfile.TemporaryDirectory() as directory:
+    for name, serialization, write in [("onto.owx", "owx", onto.write_to_owx_string),
+                                       ("onto.owl", "rdfxml", onto.write_to_rdf_string),
+                                       ("onto.ttl", "turtle", onto.write_to_turtle_string)]:
+        path = os.path.join(directory, name)
+        reported = []
+        onto.save_to_file(path, serialization, progress=reported.append)
+
+        # Progress is reported at most once per MiB and once at the end with the file size.
+        size = os.path.getsize(path)
+        assert reported and reported[-1] == size
+        assert reported == sorted(set(reported))
+        assert len(reported) <= size // (1 << 20) + 1
+        if size > 1 << 20:
+            assert len(reported) > 1 and reported[0] >= 1 << 20
+
+        # The file holds the document written to a string, and the ontology is unchanged.
+        with open(path) as f:
+            assert f.read() == write()
+        assert set(onto.get_axioms()) == axioms
+        assert len(onto.get_axioms_for_iri("http://example.com/C1")) == 3
+        assert set(pyhornedowl.open_ontology(path).get_axioms()) == axioms
+
+    restored = pyhornedowl.open_ontology(onto.write_to_owx_string())
+    assert restored.get_iri_for_id("ex:C0") == "http://example.com/C0"
+
+    # Every restriction has its own blank node.
+    ntriples = onto.write_to_ntriples_string()
+    assert len({line.split()[0] for line in ntriples.splitlines() if line.startswith("_:")}) == 4000
+
+    # An exception raised by the callback stops writing.
+    def fail(written):
+        raise RuntimeError("stop")
+
+    try:
+        onto.save_to_file(os.path.join(directory, "failed.owx"), progress=fail)
+        assert False, "the exception of the callback is raised"
+    except Exception as e:
+        assert "stop" in str(e)
+
+print("streaming tests passed")
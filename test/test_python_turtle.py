@@ -0,0 +1,75 @@
+import os
+import tempfile
+
+import pyhornedowl
+
+ONTOLOGY = """<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.com/onto">
+    <Prefix name="ex" IRI="http://example.com/"/>
+    <Declaration><Class IRI="http://example.com/A"/></Declaration>
+    <Declaration><Class IRI="http://example.com/B"/></Declaration>
+    <Declaration><ObjectProperty IRI="http://example.com/p"/></Declaration>
+    <SubClassOf>
+        <Class IRI="http://example.com/A"/>
+        <ObjectSomeValuesFrom><ObjectProperty IRI="http://example.com/p"/><Class IRI="http://example.com/B"/></ObjectSomeValuesFrom>
+    </SubClassOf>
+    <AnnotationAssertion>
+        <AnnotationProperty IRI="http://www.w3.org/2000/01/rdf-schema#label"/>
+        <IRI>http://example.com/A</IRI>
+        <Literal xml:lang="en">a "quoted" label</Literal>
+    </AnnotationAssertion>
+</Ontology>"""
+
+
+
+def read(text, extension):
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "onto." + extension)
+        with open(path, "w") as f:
+            f.write(text)
+        return pyhornedowl.open_ontology(path)
+
+
+onto = pyhornedowl.open_ontology(ONTOLOGY)
+axioms = set(onto.get_axioms())
+
+# Turtle declares the prefixes of the ontology and uses them to abbreviate IRIs.
+turtle = onto.write_to_turtle_string()
+assert "@prefix ex: <http://example.com/> ." in turtle
+assert "ex:A a <http://www.w3.org/2002/07/owl#Class>" in turtle
+assert '<http://www.w3.org/2000/01/rdf-schema#label> "a \\"quoted\\" label"@en' in turtle
+assert "<http://www.w3.org/2002/07/owl#someValuesFrom> ex:B" in turtle
+
+# N-Triples writes one sorted triple per line.
+ntriples = onto.write_to_ntriples_string()
+lines = ntriples.splitlines()
+assert lines == sorted(lines)
+assert len(lines) == 9
+assert all(line.endswith(" .") for line in lines)
+assert "<http://example.com/onto> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> " \
+       "<http://www.w3.org/2002/07/owl#Ontology> ." in lines
+
+# Both read back to the same ontology.
+for text, extension in [(turtle, "ttl"), (ntriples, "nt")]:
+    assert set(read(text, extension).get_axioms()) == axioms
+assert read(turtle, "ttl").get_iri_for_id("ex:A") == "http://example.com/A"
+
+# Writing the same ontology again, or its read-back copy, gives the same document.
+assert onto.write_to_turtle_string() == turtle
+assert read(turtle, "ttl").write_to_turtle_string() == turtle
+
+with tempfile.TemporaryDirectory() as directory:
+    for name, text in [("onto.ttl", turtle), ("onto.nt", ntriples)]:
+        path = os.path.join(directory, name)
+        onto.save_to_file(path)
+        with open(path) as f:
+            assert f.read() == text
+        assert set(pyhornedowl.open_ontology(path).get_axioms()) == axioms
+
+try:
+    read("@prefix ex: <http://example.com/> . ex:a ex:b", "ttl")
+    assert False, "malformed Turtle is rejected"
+except ValueError:
+    pass
+
+print("turtle and n-triples tests passed")